@@ -516,7 +516,7 @@ mod tests {
         fn balances(&self) -> HashMap<Currency, AccountBalance> {
             todo!()
         }
-        fn apply(&mut self, _: AccountState) {
+        fn apply(&mut self, _: AccountState) -> anyhow::Result<()> {
             todo!()
         }
         fn calculate_balance_locked(
@@ -633,8 +633,7 @@ impl OrderMatchingEngine {
             if order.order_side() == OrderSide::Sell
                 && self.account_type != AccountType::Margin
                 && matches!(self.instrument, InstrumentAny::Equity(_))
-                && (position.is_none()
-                    || !order.would_reduce_only(position.unwrap().side, position.unwrap().quantity))
+                && order.would_increase_exposure(position)
             {
                 let position_string = position.map_or("None".to_string(), |pos| pos.id.to_string());
                 self.generate_order_rejected(
@@ -651,11 +650,7 @@ impl OrderMatchingEngine {
             if self.config.use_reduce_only
                 && order.is_reduce_only()
                 && !order.is_closed()
-                && position.is_none_or(|pos| {
-                    pos.is_closed()
-                        || (order.is_buy() && pos.is_long())
-                        || (order.is_sell() && pos.is_short())
-                })
+                && order.would_increase_exposure(position)
             {
                 self.generate_order_rejected(
                     order,
@@ -1055,6 +1050,8 @@ impl OrderMatchingEngine {
             order.account_id(),
             Some(price),
             Some(trigger_price),
+            None,
+            None,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
@@ -1073,6 +1070,7 @@ impl OrderMatchingEngine {
             false,
             Some(venue_order_id),
             order.account_id(),
+            None,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
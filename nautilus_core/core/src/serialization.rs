@@ -22,7 +22,46 @@ use serde::{
 };
 
 struct BoolVisitor;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Represents a wire format for persisted objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WireFormat {
+    /// JSON, human readable.
+    Json,
+    /// `MsgPack`, compact and self-describing.
+    MsgPack,
+    /// Bincode, most compact but not self-describing.
+    Bincode,
+}
+
+/// Serialize `value` to bytes in the given wire `format`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` cannot be serialized in `format`.
+pub fn to_bytes<T: Serialize>(value: &T, format: WireFormat) -> anyhow::Result<Vec<u8>> {
+    let bytes = match format {
+        WireFormat::Json => serde_json::to_vec(value)?,
+        WireFormat::MsgPack => rmp_serde::to_vec_named(value)?,
+        WireFormat::Bincode => bincode::serialize(value)?,
+    };
+    Ok(bytes)
+}
+
+/// Deserialize a value from `bytes` encoded in the given wire `format`.
+///
+/// # Errors
+///
+/// This function returns an error if `bytes` is not a valid `format` encoding of `T`.
+pub fn from_bytes<T: DeserializeOwned>(format: WireFormat, bytes: &[u8]) -> anyhow::Result<T> {
+    let value = match format {
+        WireFormat::Json => serde_json::from_slice(bytes)?,
+        WireFormat::MsgPack => rmp_serde::from_slice(bytes)?,
+        WireFormat::Bincode => bincode::deserialize(bytes)?,
+    };
+    Ok(value)
+}
 
 /// Represents types which are serializable for JSON and `MsgPack` specifications.
 pub trait Serializable: Serialize + for<'de> Deserialize<'de> {
@@ -88,7 +127,7 @@ mod tests {
     use rstest::*;
     use serde::Deserialize;
 
-    use super::from_bool_as_u8;
+    use super::{from_bool_as_u8, from_bytes, to_bytes, WireFormat};
 
     #[derive(Deserialize)]
     pub struct TestStruct {
@@ -96,6 +135,45 @@ mod tests {
         pub value: u8,
     }
 
+    #[derive(Debug, PartialEq, serde::Serialize, Deserialize)]
+    pub struct TestRecord {
+        pub id: u64,
+        pub name: String,
+        pub values: Vec<f64>,
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    #[case(WireFormat::Bincode)]
+    fn test_wire_format_round_trip(#[case] format: WireFormat) {
+        let record = TestRecord {
+            id: 1,
+            name: "test".to_string(),
+            values: vec![1.0, 2.5],
+        };
+
+        let bytes = to_bytes(&record, format).unwrap();
+        let result: TestRecord = from_bytes(format, &bytes).unwrap();
+
+        assert_eq!(result, record);
+    }
+
+    #[rstest]
+    fn test_wire_format_bincode_smaller_than_json() {
+        // Large integers such as UNIX nanosecond timestamps are fixed width in bincode
+        let record = TestRecord {
+            id: 1_700_000_000_000_000_000,
+            name: "test".to_string(),
+            values: vec![1.0, 2.5],
+        };
+
+        let json = to_bytes(&record, WireFormat::Json).unwrap();
+        let bincode = to_bytes(&record, WireFormat::Bincode).unwrap();
+
+        assert!(bincode.len() < json.len());
+    }
+
     #[rstest]
     #[case(r#"{"value": true}"#, 1)]
     #[case(r#"{"value": false}"#, 0)]
@@ -13,12 +13,167 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use rustls::{self, ClientConfig};
-use rustls_platform_verifier::ConfigVerifierExt;
+use anyhow::Context;
+use rustls::{
+    self,
+    pki_types::{
+        CertificateDer, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+    },
+    ClientConfig,
+};
+use rustls_platform_verifier::{ConfigVerifierExt, Verifier};
+use x509_parser::certificate::X509Certificate;
 
 pub fn create_tls_config() -> Arc<ClientConfig> {
     tracing::info!("Loading certificates");
     Arc::new(ClientConfig::with_platform_verifier())
 }
+
+/// A client certificate chain and private key loaded from PEM files.
+#[derive(Debug)]
+pub struct ClientCert {
+    /// The client certificate followed by any CA certificates.
+    pub chain: Vec<CertificateDer<'static>>,
+    pub key: PrivateKeyDer<'static>,
+    /// The CA certificates, trusted as extra roots when verifying the server.
+    pub roots: Vec<CertificateDer<'static>>,
+    /// The earliest expiry time of any certificate in the chain.
+    pub not_after: SystemTime,
+}
+
+/// Loads a client certificate chain and private key from the PEM files in `certs_dir`.
+///
+/// Files are read in name order, with `CERTIFICATE` blocks forming the chain and the
+/// first private key block used as the key. CA certificates are placed after the client
+/// certificate in the chain and also returned as roots, so a venue's private CA can be
+/// placed alongside the client certificate. If every certificate is a CA, the first is
+/// taken as the (self-signed) client certificate.
+pub fn load_client_cert(certs_dir: &Path) -> anyhow::Result<ClientCert> {
+    let mut paths = std::fs::read_dir(certs_dir)
+        .with_context(|| format!("Failed to read certs dir {}", certs_dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    let mut chain = Vec::new();
+    let mut roots = Vec::new();
+    let mut key = None;
+    for path in paths.iter().filter(|path| path.is_file()) {
+        let contents = std::fs::read(path)?;
+        for block in pem::parse_many(contents)? {
+            match block.tag() {
+                "CERTIFICATE" => {
+                    let cert = CertificateDer::from(block.into_contents());
+                    if parse_cert(&cert)?.is_ca() {
+                        roots.push(cert);
+                    } else {
+                        chain.push(cert);
+                    }
+                }
+                "PRIVATE KEY" if key.is_none() => {
+                    key = Some(PrivatePkcs8KeyDer::from(block.into_contents()).into());
+                }
+                "RSA PRIVATE KEY" if key.is_none() => {
+                    key = Some(PrivatePkcs1KeyDer::from(block.into_contents()).into());
+                }
+                "EC PRIVATE KEY" if key.is_none() => {
+                    key = Some(PrivateSec1KeyDer::from(block.into_contents()).into());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let key = key.context("No private key found in certs dir")?;
+    // A self-signed client certificate may itself be marked as a CA
+    if chain.is_empty() && !roots.is_empty() {
+        chain.push(roots.remove(0));
+    }
+    chain.extend(roots.iter().cloned());
+    let not_after = chain
+        .iter()
+        .map(|cert| cert_not_after(cert))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .min()
+        .context("No certificate found in certs dir")?;
+
+    Ok(ClientCert {
+        chain,
+        key,
+        roots,
+        not_after,
+    })
+}
+
+/// Creates a TLS config which presents the given client certificate.
+///
+/// The server is verified against the platform roots and the CA certificates loaded
+/// with the client certificate.
+pub fn create_tls_config_with_client_cert(cert: ClientCert) -> anyhow::Result<Arc<ClientConfig>> {
+    tracing::info!("Loading certificates with client certificate");
+    let builder = ClientConfig::builder();
+    let verifier = Verifier::new_with_extra_roots(cert.roots)?
+        .with_provider(builder.crypto_provider().clone());
+    let config = builder
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_client_auth_cert(cert.chain, cert.key)?;
+    Ok(Arc::new(config))
+}
+
+/// Returns the `notAfter` expiry time of a DER encoded X.509 certificate.
+pub fn cert_not_after(der: &[u8]) -> anyhow::Result<SystemTime> {
+    let cert = parse_cert(der)?;
+    let secs = u64::try_from(cert.validity().not_after.timestamp())
+        .context("Certificate expiry is before the Unix epoch")?;
+    Ok(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+fn parse_cert(der: &[u8]) -> anyhow::Result<X509Certificate<'_>> {
+    let (_, cert) = x509_parser::parse_x509_certificate(der)
+        .map_err(|e| anyhow::anyhow!("Invalid X.509 certificate: {e}"))?;
+    Ok(cert)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use nautilus_core::paths::get_test_data_path;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_load_client_cert() {
+        let cert = load_client_cert(&get_test_data_path().join("certs")).unwrap();
+
+        assert_eq!(cert.chain.len(), 1);
+        assert!(cert.roots.is_empty());
+        assert_eq!(
+            cert.not_after,
+            UNIX_EPOCH + Duration::from_secs(2_107_510_989)
+        );
+    }
+
+    #[rstest]
+    fn test_cert_not_after_invalid() {
+        assert!(cert_not_after(b"not a certificate").is_err());
+    }
+
+    #[rstest]
+    fn test_load_client_cert_missing_dir() {
+        let result = load_client_cert(&get_test_data_path().join("no-such-certs"));
+        assert!(result.is_err());
+    }
+}
@@ -269,6 +269,8 @@ impl ExecutionClient {
             Some(self.account_id),
             Some(price),
             trigger_price,
+            None,
+            None,
         );
 
         self.send_order_event(OrderEventAny::Updated(event));
@@ -293,6 +295,7 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            None,
         );
 
         self.send_order_event(OrderEventAny::Canceled(event));
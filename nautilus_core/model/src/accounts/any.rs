@@ -15,21 +15,31 @@
 
 use std::collections::HashMap;
 
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
-    enums::AccountType,
+    accounts::{
+        base::Account,
+        betting::{liability, BettingAccount},
+        cash::CashAccount,
+        margin::MarginAccount,
+    },
+    enums::{AccountType, OrderSide},
     events::{AccountState, OrderFilled},
-    identifiers::AccountId,
+    identifiers::{AccountId, InstrumentId},
     instruments::InstrumentAny,
+    orders::OrderAny,
     position::Position,
-    types::{AccountBalance, Currency, Money},
+    types::{AccountBalance, Currency, MarginBalance, Money, Price},
 };
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AccountAny {
     Margin(MarginAccount),
     Cash(CashAccount),
+    Betting(BettingAccount),
 }
 
 impl AccountAny {
@@ -38,6 +48,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.id,
             AccountAny::Cash(cash) => cash.id,
+            AccountAny::Betting(betting) => betting.id,
         }
     }
 
@@ -45,6 +56,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.last_event(),
             AccountAny::Cash(cash) => cash.last_event(),
+            AccountAny::Betting(betting) => betting.last_event(),
         }
     }
 
@@ -52,13 +64,15 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.events(),
             AccountAny::Cash(cash) => cash.events(),
+            AccountAny::Betting(betting) => betting.events(),
         }
     }
 
-    pub fn apply(&mut self, event: AccountState) {
+    pub fn apply(&mut self, event: AccountState) -> anyhow::Result<()> {
         match self {
             AccountAny::Margin(margin) => margin.apply(event),
             AccountAny::Cash(cash) => cash.apply(event),
+            AccountAny::Betting(betting) => betting.apply(event),
         }
     }
 
@@ -66,6 +80,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances(),
             AccountAny::Cash(cash) => cash.balances(),
+            AccountAny::Betting(betting) => betting.balances(),
         }
     }
 
@@ -73,6 +88,29 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances_locked(),
             AccountAny::Cash(cash) => cash.balances_locked(),
+            AccountAny::Betting(betting) => betting.balances_locked(),
+        }
+    }
+
+    /// Returns the free balance (total minus locked) for the given `currency`, or `None`
+    /// if the account holds no balance in that currency.
+    #[must_use]
+    pub fn available(&self, currency: Currency) -> Option<Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balance_free(Some(currency)),
+            AccountAny::Cash(cash) => cash.balance_free(Some(currency)),
+            AccountAny::Betting(betting) => betting.balance_free(Some(currency)),
+        }
+    }
+
+    /// Returns the total balance for the given `currency`, or `None` if the account holds
+    /// no balance in that currency.
+    #[must_use]
+    pub fn total(&self, currency: Currency) -> Option<Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balance_total(Some(currency)),
+            AccountAny::Cash(cash) => cash.balance_total(Some(currency)),
+            AccountAny::Betting(betting) => betting.balance_total(Some(currency)),
         }
     }
 
@@ -80,22 +118,210 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.base_currency(),
             AccountAny::Cash(cash) => cash.base_currency(),
+            AccountAny::Betting(betting) => betting.base_currency(),
         }
     }
 
+    /// Returns the commissions charged to the account as a time series sorted by timestamp.
+    ///
+    /// Each commission is stamped with the `ts_event` of the fill which charged it, and only
+    /// the most recent [`COMMISSION_HISTORY_CAPACITY`](super::base::COMMISSION_HISTORY_CAPACITY)
+    /// commissions are retained.
+    #[must_use]
+    pub fn commission_history(&self) -> Vec<(UnixNanos, Currency, Money)> {
+        let history = match self {
+            AccountAny::Margin(margin) => &margin.commission_history,
+            AccountAny::Cash(cash) => &cash.commission_history,
+            AccountAny::Betting(betting) => &betting.commission_history,
+        };
+        let mut series: Vec<(UnixNanos, Currency, Money)> = history
+            .iter()
+            .map(|(ts_event, commission)| (*ts_event, commission.currency, *commission))
+            .collect();
+        series.sort_by_key(|(ts_event, _, _)| *ts_event);
+        series
+    }
+
+    /// Records the notional value of the `fill` on the account, for [`Self::turnover`].
+    ///
+    /// Only the most recent [`FILL_NOTIONALS_CAPACITY`](super::base::FILL_NOTIONALS_CAPACITY)
+    /// fills are retained.
+    pub fn update_fills(&mut self, instrument: &InstrumentAny, fill: &OrderFilled) {
+        match self {
+            AccountAny::Margin(margin) => margin.update_fills(instrument, fill),
+            AccountAny::Cash(cash) => cash.update_fills(instrument, fill),
+            AccountAny::Betting(betting) => betting.update_fills(instrument, fill),
+        }
+    }
+
+    /// Returns the total absolute notional value of the fills with a `ts_event` within the
+    /// inclusive window `from` to `to`, in the given `currency`.
+    ///
+    /// Fill notionals are converted between the base and quote currencies of the instrument
+    /// at the fill price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fill within the window cannot be converted to `currency`.
+    pub fn turnover(
+        &self,
+        from: UnixNanos,
+        to: UnixNanos,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        let fill_notionals = match self {
+            AccountAny::Margin(margin) => &margin.fill_notionals,
+            AccountAny::Cash(cash) => &cash.fill_notionals,
+            AccountAny::Betting(betting) => &betting.fill_notionals,
+        };
+        fill_notionals
+            .iter()
+            .filter(|(ts_event, _, _)| *ts_event >= from && *ts_event <= to)
+            .try_fold(
+                Money::new(0.0, currency),
+                |total, (ts_event, notional_quote, notional_base)| {
+                    let notional = if notional_quote.currency == currency {
+                        *notional_quote
+                    } else {
+                        notional_base
+                            .filter(|notional| notional.currency == currency)
+                            .ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "Cannot convert fill notional {notional_quote} at {ts_event} to {currency}"
+                                )
+                            })?
+                    };
+                    Ok(total + Money::new(notional.as_f64().abs(), currency))
+                },
+            )
+    }
+
+    /// Creates an account from the `events`, applying each in turn after the first.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `events` is empty.
+    /// - If the events do not all refer to the account ID of the first event.
     pub fn from_events(events: Vec<AccountState>) -> anyhow::Result<Self> {
         if events.is_empty() {
             anyhow::bail!("No order events provided to create `AccountAny`");
         }
 
         let init_event = events.first().unwrap();
+        if let Some(event) = events
+            .iter()
+            .find(|event| event.account_id != init_event.account_id)
+        {
+            anyhow::bail!(
+                "Account ID mismatch: expected {}, was {}",
+                init_event.account_id,
+                event.account_id
+            );
+        }
+
         let mut account = Self::from(init_event.clone());
         for event in events.iter().skip(1) {
-            account.apply(event.clone());
+            account.apply(event.clone())?;
         }
         Ok(account)
     }
 
+    /// Merges the `accounts` (e.g. the sub-accounts at a venue) into a synthetic aggregate
+    /// account, summing the balances per currency, and for margin accounts the margins
+    /// per instrument.
+    ///
+    /// The aggregate account ID is `{issuer}-AGGREGATE` for the issuer of the first account.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `accounts` is empty.
+    /// - If the accounts are not all of the same account type and base currency.
+    pub fn merge(accounts: &[&AccountAny]) -> anyhow::Result<AccountAny> {
+        let Some(first) = accounts.first() else {
+            anyhow::bail!("No accounts provided to merge");
+        };
+        let account_type = first.account_type();
+        let base_currency = first.base_currency();
+
+        let mut balances: HashMap<Currency, AccountBalance> = HashMap::new();
+        let mut margins: HashMap<InstrumentId, MarginBalance> = HashMap::new();
+        let mut ts_event = UnixNanos::default();
+        for account in accounts {
+            if account.account_type() != account_type {
+                anyhow::bail!(
+                    "Cannot merge account {} of type {:?} with type {:?}",
+                    account.id(),
+                    account.account_type(),
+                    account_type
+                );
+            }
+            if account.base_currency() != base_currency {
+                anyhow::bail!(
+                    "Cannot merge account {} with base currency {:?} with base currency {:?}",
+                    account.id(),
+                    account.base_currency(),
+                    base_currency
+                );
+            }
+
+            for (currency, balance) in account.balances() {
+                balances
+                    .entry(currency)
+                    .and_modify(|total| {
+                        *total = AccountBalance::new(
+                            total.total + balance.total,
+                            total.locked + balance.locked,
+                            total.free + balance.free,
+                        );
+                    })
+                    .or_insert(balance);
+            }
+            if let AccountAny::Margin(margin) = account {
+                for (instrument_id, balance) in &margin.margins {
+                    margins
+                        .entry(*instrument_id)
+                        .and_modify(|total| {
+                            *total = MarginBalance::new(
+                                total.initial + balance.initial,
+                                total.maintenance + balance.maintenance,
+                                *instrument_id,
+                            );
+                        })
+                        .or_insert(*balance);
+                }
+            }
+            if let Some(last_event) = account.last_event() {
+                ts_event = ts_event.max(last_event.ts_event);
+            }
+        }
+
+        let account_id = AccountId::new(format!("{}-AGGREGATE", first.id().get_issuer()));
+        let state = AccountState::new(
+            account_id,
+            account_type,
+            balances.into_values().collect(),
+            margins.into_values().collect(),
+            false,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            base_currency,
+        );
+
+        Ok(Self::from(state))
+    }
+
+    #[must_use]
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            AccountAny::Margin(margin) => margin.account_type,
+            AccountAny::Cash(cash) => cash.account_type,
+            AccountAny::Betting(betting) => betting.account_type,
+        }
+    }
+
     pub fn calculate_pnls(
         &self,
         instrument: InstrumentAny,
@@ -105,8 +331,159 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.calculate_pnls(instrument, fill, position),
             AccountAny::Cash(cash) => cash.calculate_pnls(instrument, fill, position),
+            AccountAny::Betting(betting) => betting.calculate_pnls(instrument, fill, position),
         }
     }
+
+    /// Calculates the PnLs for the `fill` and returns their sum converted to the account
+    /// base currency.
+    ///
+    /// The `fx_rates` map is keyed by `(from, to)` currency pairs. Where a direct rate is
+    /// not available the inverse of the `(to, from)` rate is used.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The account has no base currency.
+    /// - No FX rate is available to convert one of the PnL currencies.
+    /// - The underlying PnL calculation fails.
+    pub fn calculate_pnls_in_base(
+        &self,
+        instrument: InstrumentAny,
+        fill: OrderFilled,
+        position: Option<Position>,
+        fx_rates: &HashMap<(Currency, Currency), Decimal>,
+    ) -> anyhow::Result<Money> {
+        let base_currency = self
+            .base_currency()
+            .ok_or_else(|| anyhow::anyhow!("Account {} has no base currency", self.id()))?;
+
+        let mut total = Decimal::ZERO;
+        for pnl in self.calculate_pnls(instrument, fill, position)? {
+            let rate = fx_rate(fx_rates, pnl.currency, base_currency).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No FX rate available to convert {} to {}",
+                    pnl.currency,
+                    base_currency
+                )
+            })?;
+            total += pnl.as_decimal() * rate;
+        }
+
+        let amount = total
+            .to_f64()
+            .ok_or_else(|| anyhow::anyhow!("Cannot convert {total} to f64"))?;
+        Ok(Money::new(amount, base_currency))
+    }
+
+    /// Returns the balances as they would be if `order` fully filled at `fill_price`.
+    ///
+    /// The account state is not mutated. For cash accounts the filled quantity is
+    /// swapped between the base and quote currencies, for margin accounts the
+    /// initial margin is locked, and for betting accounts the bet liability is
+    /// locked. Commissions are not included.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order side is `NoOrderSide`.
+    /// - If the leverage of a margin account for the instrument is not positive, see
+    ///   [`MarginAccount::projected_initial_margin`].
+    /// - If a projected amount is not a valid [`Money`] amount.
+    pub fn projected_balance_after(
+        &self,
+        order: &OrderAny,
+        fill_price: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<HashMap<Currency, AccountBalance>> {
+        let mut balances = self.balances();
+        let quantity = order.leaves_qty();
+
+        match self {
+            AccountAny::Cash(cash) => {
+                let fill_qty = quantity.as_f64();
+                let fill_px = fill_price.as_f64();
+                let (base_delta, quote_delta) = match order.order_side() {
+                    OrderSide::Buy => (fill_qty, -(fill_qty * fill_px)),
+                    OrderSide::Sell => (-fill_qty, fill_qty * fill_px),
+                    OrderSide::NoOrderSide => {
+                        anyhow::bail!("Invalid `OrderSide`, was {}", order.order_side())
+                    }
+                };
+                if let (Some(base_currency), None) =
+                    (instrument.base_currency(), cash.base_currency)
+                {
+                    apply_projected_delta(
+                        &mut balances,
+                        Money::new_checked(base_delta, base_currency)?,
+                    );
+                }
+                apply_projected_delta(
+                    &mut balances,
+                    Money::new_checked(quote_delta, instrument.quote_currency())?,
+                );
+            }
+            AccountAny::Margin(margin) => {
+                let locked = margin.projected_initial_margin(instrument, quantity, fill_price)?;
+                apply_projected_lock(&mut balances, locked);
+            }
+            AccountAny::Betting(_) => {
+                let locked = liability(quantity, fill_price, order.order_side())?;
+                let amount = locked
+                    .to_f64()
+                    .ok_or_else(|| anyhow::anyhow!("Cannot convert {locked} to f64"))?;
+                apply_projected_lock(
+                    &mut balances,
+                    Money::new_checked(amount, instrument.quote_currency())?,
+                );
+            }
+        }
+
+        Ok(balances)
+    }
+}
+
+fn fx_rate(
+    fx_rates: &HashMap<(Currency, Currency), Decimal>,
+    from: Currency,
+    to: Currency,
+) -> Option<Decimal> {
+    if from == to {
+        return Some(Decimal::ONE);
+    }
+    if let Some(rate) = fx_rates.get(&(from, to)) {
+        return Some(*rate);
+    }
+    fx_rates
+        .get(&(to, from))
+        .filter(|rate| !rate.is_zero())
+        .map(|rate| Decimal::ONE / rate)
+}
+
+fn projected_balance_or_zero(
+    balances: &HashMap<Currency, AccountBalance>,
+    currency: Currency,
+) -> AccountBalance {
+    balances.get(&currency).copied().unwrap_or_else(|| {
+        let zero = Money::new(0.0, currency);
+        AccountBalance::new(zero, zero, zero)
+    })
+}
+
+fn apply_projected_delta(balances: &mut HashMap<Currency, AccountBalance>, delta: Money) {
+    let current = projected_balance_or_zero(balances, delta.currency);
+    let balance = AccountBalance::new(current.total + delta, current.locked, current.free + delta);
+    balances.insert(delta.currency, balance);
+}
+
+fn apply_projected_lock(balances: &mut HashMap<Currency, AccountBalance>, locked: Money) {
+    let current = projected_balance_or_zero(balances, locked.currency);
+    let balance = AccountBalance::new(
+        current.total,
+        current.locked + locked,
+        current.free - locked,
+    );
+    balances.insert(locked.currency, balance);
 }
 
 impl From<AccountState> for AccountAny {
@@ -114,7 +491,7 @@ impl From<AccountState> for AccountAny {
         match event.account_type {
             AccountType::Margin => AccountAny::Margin(MarginAccount::new(event, false)),
             AccountType::Cash => AccountAny::Cash(CashAccount::new(event, false)),
-            AccountType::Betting => todo!("Betting account not implemented"),
+            AccountType::Betting => AccountAny::Betting(BettingAccount::new(event, false)),
         }
     }
 }
@@ -131,3 +508,580 @@ impl PartialEq for AccountAny {
         self.id() == other.id()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use nautilus_core::nanos::UnixNanos;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        accounts::{
+            any::AccountAny,
+            base::{Account, COMMISSION_HISTORY_CAPACITY, FILL_NOTIONALS_CAPACITY},
+            betting::BettingAccount,
+            cash::CashAccount,
+            margin::MarginAccount,
+            stubs::*,
+        },
+        enums::{AccountType, OrderSide, OrderType},
+        events::{account::stubs::*, AccountState, OrderFilled},
+        identifiers::{AccountId, Symbol},
+        instruments::{stubs::*, BettingInstrument, CurrencyPair, InstrumentAny},
+        orders::builder::OrderTestBuilder,
+        stubs::stub_order_filled,
+        types::{AccountBalance, Currency, Money, Price, Quantity},
+    };
+
+    #[rstest]
+    fn test_available_and_total(margin_account: MarginAccount) {
+        let account = AccountAny::Margin(margin_account);
+
+        assert_eq!(
+            account.available(Currency::USD()),
+            Some(Money::from("1500000 USD"))
+        );
+        assert_eq!(
+            account.total(Currency::USD()),
+            Some(Money::from("1525000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_available_and_total_absent_currency(cash_account: CashAccount) {
+        let account = AccountAny::Cash(cash_account);
+
+        assert_eq!(account.available(Currency::BTC()), None);
+        assert_eq!(account.total(Currency::BTC()), None);
+    }
+
+    #[rstest]
+    fn test_commission_history(mut cash_account_multi: CashAccount) {
+        // The account state for the earlier fills is applied after the later fill
+        cash_account_multi.record_commission(Money::from("0.003 BTC"), 4_000.into());
+        cash_account_multi.record_commission(Money::from("0.001 BTC"), 1_000.into());
+        cash_account_multi.record_commission(Money::from("0.01 ETH"), 2_000.into());
+        cash_account_multi.record_commission(Money::from("0.002 BTC"), 2_000.into());
+        cash_account_multi.record_commission(Money::from("0 BTC"), 3_000.into());
+        let state = cash_account_multi.last_event().unwrap();
+        cash_account_multi
+            .apply(AccountState {
+                ts_event: 5_000.into(),
+                ts_init: 5_000.into(),
+                ..state
+            })
+            .unwrap();
+
+        let account = AccountAny::Cash(cash_account_multi);
+        let history = account.commission_history();
+
+        assert_eq!(
+            history,
+            vec![
+                (
+                    UnixNanos::from(1_000),
+                    Currency::BTC(),
+                    Money::from("0.001 BTC")
+                ),
+                (
+                    UnixNanos::from(2_000),
+                    Currency::ETH(),
+                    Money::from("0.01 ETH")
+                ),
+                (
+                    UnixNanos::from(2_000),
+                    Currency::BTC(),
+                    Money::from("0.002 BTC")
+                ),
+                (
+                    UnixNanos::from(4_000),
+                    Currency::BTC(),
+                    Money::from("0.003 BTC")
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_commission_history_empty(cash_account: CashAccount) {
+        let account = AccountAny::Cash(cash_account);
+        assert!(account.commission_history().is_empty());
+    }
+
+    #[rstest]
+    fn test_commission_history_retains_most_recent(mut cash_account: CashAccount) {
+        for ts in 0..=COMMISSION_HISTORY_CAPACITY as u64 {
+            cash_account.record_commission(Money::from("1 USD"), ts.into());
+        }
+
+        let total = cash_account.commissions[&Currency::USD()];
+        let account = AccountAny::Cash(cash_account);
+        let history = account.commission_history();
+
+        assert_eq!(history.len(), COMMISSION_HISTORY_CAPACITY);
+        assert_eq!(history[0].0, UnixNanos::from(1));
+        assert_eq!(total, COMMISSION_HISTORY_CAPACITY as f64 + 1.0);
+    }
+
+    #[rstest]
+    fn test_merge_cash_sub_accounts(
+        cash_account: CashAccount,
+        cash_account_million_usd: CashAccount,
+    ) {
+        let sub1 = AccountAny::Cash(cash_account);
+        let sub2 = AccountAny::Cash(cash_account_million_usd);
+
+        let merged = AccountAny::merge(&[&sub1, &sub2]).unwrap();
+
+        assert_eq!(merged.id(), AccountId::new("SIM-AGGREGATE"));
+        assert_eq!(merged.account_type(), AccountType::Cash);
+        assert_eq!(merged.base_currency(), Some(Currency::USD()));
+        assert_eq!(merged.balances().len(), 1);
+        assert_eq!(
+            merged.balances()[&Currency::USD()],
+            AccountBalance::new(
+                Money::from("2525000 USD"),
+                Money::from("25000 USD"),
+                Money::from("2500000 USD"),
+            )
+        );
+        assert_eq!(
+            sub1.total(Currency::USD()),
+            Some(Money::from("1525000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_merge_invalid_accounts(
+        cash_account: CashAccount,
+        cash_account_multi: CashAccount,
+        margin_account: MarginAccount,
+    ) {
+        let cash = AccountAny::Cash(cash_account);
+        let multi = AccountAny::Cash(cash_account_multi);
+        let margin = AccountAny::Margin(margin_account);
+
+        assert!(AccountAny::merge(&[]).is_err());
+        assert!(AccountAny::merge(&[&cash, &margin]).is_err());
+        assert!(AccountAny::merge(&[&cash, &multi]).is_err());
+    }
+
+    #[rstest]
+    fn test_turnover(mut cash_account_multi: CashAccount, currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let fill_at = |ts: u64, qty: &str, px: &str| OrderFilled {
+            instrument_id: currency_pair_btcusdt.id,
+            last_qty: Quantity::from(qty),
+            last_px: Price::from(px),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+            ..Default::default()
+        };
+
+        cash_account_multi.update_fills(&instrument, &fill_at(1_000, "1.000000", "50000.00"));
+        cash_account_multi.update_fills(&instrument, &fill_at(2_000, "0.500000", "52000.00"));
+        cash_account_multi.update_fills(&instrument, &fill_at(3_000, "0.250000", "48000.00"));
+        cash_account_multi.update_fills(&instrument, &fill_at(4_000, "2.000000", "51000.00"));
+
+        let account = AccountAny::Cash(cash_account_multi);
+        let from = UnixNanos::from(2_000);
+        let to = UnixNanos::from(3_000);
+
+        assert_eq!(
+            account.turnover(from, to, Currency::USDT()).unwrap(),
+            Money::from("38000 USDT")
+        );
+        assert_eq!(
+            account.turnover(from, to, Currency::BTC()).unwrap(),
+            Money::from("0.75 BTC")
+        );
+        assert_eq!(
+            account
+                .turnover(
+                    UnixNanos::from(5_000),
+                    UnixNanos::from(6_000),
+                    Currency::USDT()
+                )
+                .unwrap(),
+            Money::from("0 USDT")
+        );
+        assert_eq!(
+            account
+                .turnover(from, to, Currency::ETH())
+                .unwrap_err()
+                .to_string(),
+            "Cannot convert fill notional 26000.00000000 USDT at 2000 to ETH"
+        );
+    }
+
+    #[rstest]
+    fn test_turnover_retains_most_recent_fills(
+        mut cash_account_multi: CashAccount,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        for ts in 0..=FILL_NOTIONALS_CAPACITY as u64 {
+            let fill = OrderFilled {
+                instrument_id: currency_pair_btcusdt.id,
+                last_qty: Quantity::from("1.000000"),
+                last_px: Price::from("50000.00"),
+                ts_event: ts.into(),
+                ts_init: ts.into(),
+                ..Default::default()
+            };
+            cash_account_multi.update_fills(&instrument, &fill);
+        }
+
+        let account = AccountAny::Cash(cash_account_multi);
+        let all = (UnixNanos::from(0), UnixNanos::from(u64::MAX));
+
+        assert_eq!(
+            account.turnover(all.0, all.1, Currency::BTC()).unwrap(),
+            Money::new(FILL_NOTIONALS_CAPACITY as f64, Currency::BTC())
+        );
+        assert_eq!(
+            account
+                .turnover(UnixNanos::from(0), UnixNanos::from(0), Currency::BTC())
+                .unwrap(),
+            Money::from("0 BTC")
+        );
+    }
+
+    #[rstest]
+    fn test_projected_balance_after_buy_cash(cash_account: CashAccount, audusd_sim: CurrencyPair) {
+        let account = AccountAny::Cash(cash_account);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let current = account.balances();
+        let projected = account
+            .projected_balance_after(&order, Price::from("0.80000"), &instrument)
+            .unwrap();
+
+        assert_eq!(
+            current[&Currency::USD()],
+            AccountBalance::new(
+                Money::from("1525000 USD"),
+                Money::from("25000 USD"),
+                Money::from("1500000 USD"),
+            )
+        );
+        assert_eq!(
+            projected[&Currency::USD()],
+            AccountBalance::new(
+                Money::from("1445000 USD"),
+                Money::from("25000 USD"),
+                Money::from("1420000 USD"),
+            )
+        );
+        // Single-currency account only tracks the base currency balance
+        assert!(!projected.contains_key(&Currency::AUD()));
+        assert_eq!(account.balances(), current);
+    }
+
+    #[rstest]
+    fn test_projected_balance_after_buy_multi_currency_cash(
+        cash_account_multi: CashAccount,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account_multi);
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(currency_pair_btcusdt.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000000"))
+            .build();
+
+        let projected = account
+            .projected_balance_after(&order, Price::from("50000.00"), &instrument)
+            .unwrap();
+
+        assert_eq!(
+            projected[&Currency::BTC()],
+            AccountBalance::new(
+                Money::from("11 BTC"),
+                Money::from("0 BTC"),
+                Money::from("11 BTC"),
+            )
+        );
+        assert_eq!(
+            projected[&Currency::USDT()],
+            AccountBalance::new(
+                Money::from("-50000 USDT"),
+                Money::from("0 USDT"),
+                Money::from("-50000 USDT"),
+            )
+        );
+        assert_eq!(
+            projected[&Currency::ETH()],
+            account.balances()[&Currency::ETH()]
+        );
+    }
+
+    #[rstest]
+    fn test_projected_balance_after_buy_margin(
+        margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let account = AccountAny::Margin(margin_account);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let current = account.balances();
+        let projected = account
+            .projected_balance_after(&order, Price::from("0.80000"), &instrument)
+            .unwrap();
+
+        // Initial margin of 0.03 plus twice the taker fee of 0.00002 on 80,000 USD notional
+        assert_eq!(
+            projected[&Currency::USD()],
+            AccountBalance::new(
+                Money::from("1525000 USD"),
+                Money::from("27403.20 USD"),
+                Money::from("1497596.80 USD"),
+            )
+        );
+        assert_eq!(
+            projected[&Currency::USD()].total,
+            current[&Currency::USD()].total
+        );
+        assert_eq!(account.balances(), current);
+    }
+
+    #[rstest]
+    fn test_projected_balance_after_margin_with_zero_leverage(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.set_leverage(audusd_sim.id, 0.0);
+        let account = AccountAny::Margin(margin_account);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = account.projected_balance_after(&order, Price::from("0.80000"), &instrument);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid leverage for AUD/USD.SIM: 0, must be positive"
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100 GBP", "900 GBP")]
+    #[case(OrderSide::Sell, "150 GBP", "850 GBP")]
+    fn test_projected_balance_after_betting(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] side: OrderSide,
+        #[case] expected_locked: &str,
+        #[case] expected_free: &str,
+    ) {
+        let account = AccountAny::Betting(betting_account);
+        let instrument_id = betting.id;
+        let instrument = InstrumentAny::Betting(betting);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .side(side)
+            .price(Price::from("2.50"))
+            .quantity(Quantity::from("100"))
+            .build();
+
+        let projected = account
+            .projected_balance_after(&order, Price::from("2.50"), &instrument)
+            .unwrap();
+
+        assert_eq!(
+            projected[&Currency::GBP()],
+            AccountBalance::new(
+                Money::from("1000 GBP"),
+                Money::from(expected_locked),
+                Money::from(expected_free),
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_projected_balance_after_no_order_side(
+        cash_account: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::NoOrderSide)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = account.projected_balance_after(&order, Price::from("0.80000"), &instrument);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid `OrderSide`, was NO_ORDER_SIDE"
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_in_base_eur(cash_account_million_usd: CashAccount) {
+        let account = AccountAny::Cash(cash_account_million_usd);
+        let gbpeur = InstrumentAny::CurrencyPair(default_fx_ccy(Symbol::from("GBP/EUR"), None));
+        let fill = stub_order_filled(
+            &gbpeur,
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.85000"),
+            None,
+        );
+        let fx_rates = HashMap::from([((Currency::EUR(), Currency::USD()), dec!(1.10))]);
+
+        let pnl = account
+            .calculate_pnls_in_base(gbpeur, fill, None, &fx_rates)
+            .unwrap();
+
+        assert_eq!(pnl, Money::from("-93500 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_in_base_jpy_uses_inverse_rate(
+        cash_account_million_usd: CashAccount,
+        usdjpy_idealpro: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account_million_usd);
+        let usdjpy = InstrumentAny::CurrencyPair(usdjpy_idealpro);
+        let fill = stub_order_filled(
+            &usdjpy,
+            OrderSide::Sell,
+            Quantity::from("100000"),
+            Price::from("150.000"),
+            None,
+        );
+        let fx_rates = HashMap::from([((Currency::USD(), Currency::JPY()), dec!(150))]);
+
+        let pnl = account
+            .calculate_pnls_in_base(usdjpy, fill, None, &fx_rates)
+            .unwrap();
+
+        assert_eq!(pnl, Money::from("100000 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_in_base_same_currency_needs_no_rate(
+        cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account_million_usd);
+        let audusd = InstrumentAny::CurrencyPair(audusd_sim);
+        let fill = stub_order_filled(
+            &audusd,
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            None,
+        );
+
+        let pnl = account
+            .calculate_pnls_in_base(audusd, fill, None, &HashMap::new())
+            .unwrap();
+
+        assert_eq!(pnl, Money::from("-80000 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_in_base_missing_rate(
+        cash_account_million_usd: CashAccount,
+        usdjpy_idealpro: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account_million_usd);
+        let usdjpy = InstrumentAny::CurrencyPair(usdjpy_idealpro);
+        let fill = stub_order_filled(
+            &usdjpy,
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("150.000"),
+            None,
+        );
+        let fx_rates = HashMap::from([((Currency::EUR(), Currency::USD()), dec!(1.10))]);
+
+        let result = account.calculate_pnls_in_base(usdjpy, fill, None, &fx_rates);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("No FX rate available to convert JPY to USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_in_base_no_base_currency(
+        cash_account_multi: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let account = AccountAny::Cash(cash_account_multi);
+        let audusd = InstrumentAny::CurrencyPair(audusd_sim);
+        let fill = stub_order_filled(
+            &audusd,
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            None,
+        );
+        let fx_rates = HashMap::from([((Currency::AUD(), Currency::USD()), dec!(1))]);
+
+        let result = account.calculate_pnls_in_base(audusd, fill, None, &fx_rates);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("has no base currency"));
+    }
+
+    #[rstest]
+    fn test_from_events(
+        cash_account_state_multi: AccountState,
+        cash_account_state_multi_changed_btc: AccountState,
+    ) {
+        let account = AccountAny::from_events(vec![
+            cash_account_state_multi,
+            cash_account_state_multi_changed_btc,
+        ])
+        .unwrap();
+
+        assert_eq!(account.id(), AccountId::from("SIM-001"));
+        assert_eq!(account.events().len(), 2);
+        assert_eq!(account.total(Currency::BTC()), Some(Money::from("9 BTC")));
+    }
+
+    #[rstest]
+    fn test_from_events_mismatched_account_ids(
+        cash_account_state_multi: AccountState,
+        mut cash_account_state_multi_changed_btc: AccountState,
+    ) {
+        cash_account_state_multi_changed_btc.account_id = AccountId::from("SIM-002");
+
+        let result = AccountAny::from_events(vec![
+            cash_account_state_multi,
+            cash_account_state_multi_changed_btc,
+        ]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Account ID mismatch: expected SIM-001, was SIM-002"
+        );
+    }
+}
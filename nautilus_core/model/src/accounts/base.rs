@@ -13,8 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
+use nautilus_core::nanos::UnixNanos;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +28,12 @@ use crate::{
     types::{AccountBalance, Currency, Money, Price, Quantity},
 };
 
+/// The maximum number of fill notionals retained for turnover, the oldest are dropped first.
+pub(crate) const FILL_NOTIONALS_CAPACITY: usize = 10_000;
+
+/// The maximum number of commissions retained in the history, the oldest are dropped first.
+pub(crate) const COMMISSION_HISTORY_CAPACITY: usize = 10_000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -41,6 +48,8 @@ pub struct BaseAccount {
     pub commissions: HashMap<Currency, f64>,
     pub balances: HashMap<Currency, AccountBalance>,
     pub balances_starting: HashMap<Currency, Money>,
+    pub commission_history: VecDeque<(UnixNanos, Money)>,
+    pub fill_notionals: VecDeque<(UnixNanos, Money, Option<Money>)>,
 }
 
 impl BaseAccount {
@@ -61,6 +70,8 @@ impl BaseAccount {
             commissions: HashMap::new(),
             balances,
             balances_starting,
+            commission_history: VecDeque::new(),
+            fill_notionals: VecDeque::new(),
         }
     }
 
@@ -120,17 +131,27 @@ impl BaseAccount {
         self.events.last().cloned()
     }
 
-    pub fn update_balances(&mut self, balances: Vec<AccountBalance>) {
-        for balance in balances {
-            // clone real balance without reference
+    /// Updates the account balances, validating every balance before any is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any balance is inconsistent (total != locked + free, or a
+    /// negative component) or has a total less than zero, in which case no balance is
+    /// updated.
+    pub fn update_balances(&mut self, balances: Vec<AccountBalance>) -> anyhow::Result<()> {
+        for balance in &balances {
+            balance.validate().map_err(|e| {
+                anyhow::anyhow!("Cannot update balances with an inconsistent balance: {e}")
+            })?;
             if balance.total.raw < 0 {
                 // TODO raise AccountBalanceNegative event
-                panic!("Cannot update balances with total less than 0.0")
-            } else {
-                // clear asset balance
-                self.balances.insert(balance.currency, balance);
+                anyhow::bail!("Cannot update balances with total less than 0.0: {balance}");
             }
         }
+        for balance in balances {
+            self.balances.insert(balance.currency, balance);
+        }
+        Ok(())
     }
 
     pub fn update_commissions(&mut self, commission: Money) {
@@ -145,9 +166,49 @@ impl BaseAccount {
             .insert(currency, total_commissions + commission.as_f64());
     }
 
-    pub fn base_apply(&mut self, event: AccountState) {
-        self.update_balances(event.balances.clone());
+    /// Adds the `commission` charged by a fill at `ts_event` to the total commissions and
+    /// the commission history.
+    pub fn record_commission(&mut self, commission: Money, ts_event: UnixNanos) {
+        if commission.as_decimal() == Decimal::ZERO {
+            return;
+        }
+
+        self.update_commissions(commission);
+        if self.commission_history.len() == COMMISSION_HISTORY_CAPACITY {
+            self.commission_history.pop_front();
+        }
+        self.commission_history.push_back((ts_event, commission));
+    }
+
+    /// Records the notional value of the `fill` in the quote currency, along with its
+    /// equivalent in the base currency of the instrument (if it has one).
+    pub fn update_fills(&mut self, instrument: &InstrumentAny, fill: &OrderFilled) {
+        let notional_quote =
+            instrument.calculate_notional_value(fill.last_qty, fill.last_px, Some(true));
+        let notional_base = instrument.base_currency().map(|base_currency| {
+            if instrument.is_inverse() {
+                instrument.calculate_notional_value(fill.last_qty, fill.last_px, None)
+            } else {
+                let amount = fill.last_qty.as_f64() * instrument.multiplier().as_f64();
+                Money::new(amount, base_currency)
+            }
+        });
+        if self.fill_notionals.len() == FILL_NOTIONALS_CAPACITY {
+            self.fill_notionals.pop_front();
+        }
+        self.fill_notionals
+            .push_back((fill.ts_event, notional_quote, notional_base));
+    }
+
+    /// Applies the given account state `event`, leaving the account unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event's balances are rejected by [`Self::update_balances`].
+    pub fn base_apply(&mut self, event: AccountState) -> anyhow::Result<()> {
+        self.update_balances(event.balances.clone())?;
         self.events.push(event);
+        Ok(())
     }
 
     pub fn base_calculate_balance_locked(
@@ -276,7 +337,7 @@ pub trait Account: 'static + Send {
     fn currencies(&self) -> Vec<Currency>;
     fn starting_balances(&self) -> HashMap<Currency, Money>;
     fn balances(&self) -> HashMap<Currency, AccountBalance>;
-    fn apply(&mut self, event: AccountState);
+    fn apply(&mut self, event: AccountState) -> anyhow::Result<()>;
     fn calculate_balance_locked(
         &mut self,
         instrument: InstrumentAny,
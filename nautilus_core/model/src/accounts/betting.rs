@@ -0,0 +1,786 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    accounts::base::{Account, BaseAccount},
+    enums::{AccountType, LiquiditySide, OrderSide, PositionSide},
+    events::{AccountState, OrderFilled},
+    identifiers::{
+        stubs::{account_id, uuid4},
+        AccountId,
+    },
+    instruments::InstrumentAny,
+    position::Position,
+    types::{AccountBalance, Currency, Money, Price, Quantity},
+};
+
+/// Provides a betting account.
+///
+/// This is a cash account (as for `BettingAccount` subclassing `CashAccount` in Python),
+/// with the locked balance for an
+/// order being its liability (the amount which would be lost if the bet settled
+/// against it) rather than its notional value.
+///
+/// The PnL of a fill is its trading PnL, realized only where it hedges an open position,
+/// while the payoff of the bets themselves is realized by [`BettingAccount::settle`] once
+/// the outcome of the selection is known.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct BettingAccount {
+    pub base: BaseAccount,
+}
+
+impl BettingAccount {
+    /// Creates a new [`BettingAccount`] instance.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Self {
+        Self {
+            base: BaseAccount::new(event, calculate_account_state),
+        }
+    }
+
+    #[must_use]
+    pub fn is_betting_account(&self) -> bool {
+        self.account_type == AccountType::Betting
+    }
+
+    /// Recalculates the locked and free balances for the `currency`, as for a cash account.
+    pub fn recalculate_balance(&mut self, currency: Currency) {
+        let current_balance = match self.balances.get(&currency) {
+            Some(balance) => *balance,
+            None => {
+                return;
+            }
+        };
+
+        let total_locked = self
+            .balances
+            .values()
+            .filter(|balance| balance.currency == currency)
+            .fold(Decimal::ZERO, |acc, balance| {
+                acc + balance.locked.as_decimal()
+            });
+
+        let new_balance = AccountBalance::new(
+            current_balance.total,
+            Money::new(total_locked.to_f64().unwrap(), currency),
+            Money::new(
+                (current_balance.total.as_decimal() - total_locked)
+                    .to_f64()
+                    .unwrap(),
+                currency,
+            ),
+        );
+
+        self.balances.insert(currency, new_balance);
+    }
+
+    /// Returns the balance impact of filling the given `quantity` at `price` on `side`.
+    ///
+    /// Backing (buying) costs the stake, laying (selling) costs the quantity.
+    pub fn balance_impact(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        side: OrderSide,
+    ) -> anyhow::Result<Money> {
+        let notional = quantity.as_decimal() * price.as_decimal();
+        let impact = match side {
+            OrderSide::Buy => -notional * (price.as_decimal() - Decimal::ONE),
+            OrderSide::Sell => -notional,
+            OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+        };
+        Ok(Money::new(
+            impact.to_f64().unwrap(),
+            instrument.quote_currency(),
+        ))
+    }
+
+    /// Returns the PnL of settling the `position` on the `outcome` of its selection.
+    ///
+    /// This is the payoff of every fill of the position for the outcome, less the
+    /// trading PnL already realized by [`Account::calculate_pnls`] for fills which
+    /// hedged the position.
+    pub fn settle(
+        &self,
+        instrument: &InstrumentAny,
+        position: &Position,
+        outcome: BetOutcome,
+    ) -> anyhow::Result<Money> {
+        let mut payoff = Decimal::ZERO;
+        for fill in &position.events {
+            payoff += match outcome {
+                BetOutcome::Win => win_payoff(fill.last_qty, fill.last_px, fill.order_side)?,
+                BetOutcome::Lose => lose_payoff(fill.last_qty, fill.order_side)?,
+            };
+        }
+        let realized = realized_trading_pnl(&position.events)?;
+        to_money(payoff - realized, instrument.quote_currency())
+    }
+}
+
+impl Account for BettingAccount {
+    fn id(&self) -> AccountId {
+        self.id
+    }
+
+    fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        self.base_currency
+    }
+
+    fn is_cash_account(&self) -> bool {
+        matches!(self.account_type, AccountType::Cash | AccountType::Betting)
+    }
+
+    fn is_margin_account(&self) -> bool {
+        self.account_type == AccountType::Margin
+    }
+
+    fn calculated_account_state(&self) -> bool {
+        false // TODO (implement this logic)
+    }
+
+    fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_total(currency)
+    }
+
+    fn balances_total(&self) -> HashMap<Currency, Money> {
+        self.base_balances_total()
+    }
+
+    fn balance_free(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_free(currency)
+    }
+
+    fn balances_free(&self) -> HashMap<Currency, Money> {
+        self.base_balances_free()
+    }
+
+    fn balance_locked(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_locked(currency)
+    }
+
+    fn balances_locked(&self) -> HashMap<Currency, Money> {
+        self.base_balances_locked()
+    }
+
+    fn last_event(&self) -> Option<AccountState> {
+        self.base_last_event()
+    }
+
+    fn events(&self) -> Vec<AccountState> {
+        self.events.clone()
+    }
+
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    fn currencies(&self) -> Vec<Currency> {
+        self.balances.keys().copied().collect()
+    }
+
+    fn starting_balances(&self) -> HashMap<Currency, Money> {
+        self.balances_starting.clone()
+    }
+
+    fn balances(&self) -> HashMap<Currency, AccountBalance> {
+        self.balances.clone()
+    }
+
+    fn apply(&mut self, event: AccountState) -> anyhow::Result<()> {
+        self.base_apply(event)
+    }
+
+    fn calculate_balance_locked(
+        &mut self,
+        instrument: InstrumentAny,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        if use_quote_for_inverse.unwrap_or(false) {
+            anyhow::bail!("`use_quote_for_inverse` is not applicable for betting accounts");
+        }
+        let locked = liability(quantity, price, side)?;
+        to_money(locked, instrument.quote_currency())
+    }
+
+    fn calculate_pnls(
+        &self,
+        instrument: InstrumentAny, // TODO: Make this a reference
+        fill: OrderFilled,         // TODO: Make this a reference
+        position: Option<Position>,
+    ) -> anyhow::Result<Vec<Money>> {
+        // Only the stake which hedges an open position realizes a trading PnL, the
+        // payoff of the remaining stake depends on the outcome and is settled later
+        let open_side = match position.as_ref().map(|position| position.side) {
+            Some(PositionSide::Long) => OrderSide::Buy,
+            Some(PositionSide::Short) => OrderSide::Sell,
+            _ => OrderSide::NoOrderSide,
+        };
+        let pnl = match position {
+            Some(position)
+                if open_side != OrderSide::NoOrderSide && open_side != fill.order_side =>
+            {
+                let open_px = Decimal::from_f64(position.avg_px_open).ok_or_else(|| {
+                    anyhow::anyhow!("Invalid `avg_px_open`, was {}", position.avg_px_open)
+                })?;
+                let hedged = fill
+                    .last_qty
+                    .as_decimal()
+                    .min(position.quantity.as_decimal());
+                hedge_pnl(hedged, open_px, fill.last_px.as_decimal(), open_side)?
+            }
+            _ => Decimal::ZERO,
+        };
+        Ok(vec![to_money(pnl, instrument.quote_currency())?])
+    }
+
+    fn calculate_commission(
+        &self,
+        instrument: InstrumentAny,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        self.base_calculate_commission(
+            instrument,
+            last_qty,
+            last_px,
+            liquidity_side,
+            use_quote_for_inverse,
+        )
+    }
+}
+
+impl Deref for BettingAccount {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BettingAccount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl PartialEq for BettingAccount {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BettingAccount {}
+
+impl Display for BettingAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BettingAccount(id={}, type={}, base={})",
+            self.id,
+            self.account_type,
+            self.base_currency.map_or_else(
+                || "None".to_string(),
+                |base_currency| format!("{}", base_currency.code)
+            ),
+        )
+    }
+}
+
+impl Default for BettingAccount {
+    /// Creates a new default [`BettingAccount`] instance.
+    fn default() -> Self {
+        let init_event = AccountState::new(
+            account_id(),
+            AccountType::Betting,
+            vec![AccountBalance::new(
+                Money::from("1000 GBP"),
+                Money::from("0 GBP"),
+                Money::from("1000 GBP"),
+            )],
+            vec![],
+            true,
+            uuid4(),
+            0.into(),
+            0.into(),
+            Some(Currency::GBP()),
+        );
+        Self::new(init_event, false)
+    }
+}
+
+/// Represents the outcome of the selection a bet is settled on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BetOutcome {
+    /// The selection won, paying out back bets.
+    Win,
+    /// The selection lost, paying out lay bets.
+    Lose,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Betting calculations
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the stake for a back bet of `quantity` at decimal odds `price`.
+#[must_use]
+pub fn stake(quantity: Quantity, price: Price) -> Decimal {
+    quantity.as_decimal() * (price.as_decimal() - Decimal::ONE)
+}
+
+/// Returns the amount at risk for a bet of `quantity` at `price` on `side`.
+///
+/// This is the quantity staked on a back (buy) bet, which is lost if the selection
+/// loses, and the liability of a lay (sell) bet, which is paid out if the selection wins.
+pub fn liability(quantity: Quantity, price: Price, side: OrderSide) -> anyhow::Result<Decimal> {
+    match side {
+        OrderSide::Buy => Ok(quantity.as_decimal()),
+        OrderSide::Sell => Ok(stake(quantity, price)),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+/// Returns the payoff if the selection wins for a bet of `quantity` at `price` on `side`.
+pub fn win_payoff(quantity: Quantity, price: Price, side: OrderSide) -> anyhow::Result<Decimal> {
+    match side {
+        OrderSide::Buy => Ok(stake(quantity, price)),
+        OrderSide::Sell => Ok(-stake(quantity, price)),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+/// Returns the payoff if the selection loses for a bet of `quantity` on `side`.
+pub fn lose_payoff(quantity: Quantity, side: OrderSide) -> anyhow::Result<Decimal> {
+    match side {
+        OrderSide::Buy => Ok(-quantity.as_decimal()),
+        OrderSide::Sell => Ok(quantity.as_decimal()),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+/// Returns the exposure (win payoff less lose payoff) for a bet of `quantity` at `price` on `side`.
+pub fn exposure(quantity: Quantity, price: Price, side: OrderSide) -> anyhow::Result<Decimal> {
+    Ok(win_payoff(quantity, price, side)? - lose_payoff(quantity, side)?)
+}
+
+/// Returns the trading PnL of hedging a `quantity` opened at `open_px` on `open_side`
+/// with an opposite bet at `close_px`.
+///
+/// The lose payoffs of the matched bets cancel out, leaving the difference between
+/// their win payoffs.
+fn hedge_pnl(
+    quantity: Decimal,
+    open_px: Decimal,
+    close_px: Decimal,
+    open_side: OrderSide,
+) -> anyhow::Result<Decimal> {
+    match open_side {
+        OrderSide::Buy => Ok(quantity * (open_px - close_px)),
+        OrderSide::Sell => Ok(quantity * (close_px - open_px)),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {open_side}"),
+    }
+}
+
+/// Returns the total trading PnL realized by the `fills` of a position, netting each
+/// fill against the stake opened by the fills before it.
+fn realized_trading_pnl(fills: &[OrderFilled]) -> anyhow::Result<Decimal> {
+    let mut open_side = OrderSide::NoOrderSide;
+    let mut open_qty = Decimal::ZERO;
+    let mut open_px = Decimal::ZERO;
+    let mut realized = Decimal::ZERO;
+    for fill in fills {
+        let qty = fill.last_qty.as_decimal();
+        let px = fill.last_px.as_decimal();
+        if open_qty.is_zero() || fill.order_side == open_side {
+            open_px = (open_px * open_qty + px * qty) / (open_qty + qty);
+            open_qty += qty;
+            open_side = fill.order_side;
+        } else {
+            let hedged = qty.min(open_qty);
+            realized += hedge_pnl(hedged, open_px, px, open_side)?;
+            open_qty -= hedged;
+            if qty > hedged {
+                open_side = fill.order_side;
+                open_qty = qty - hedged;
+                open_px = px;
+            }
+        }
+    }
+    Ok(realized)
+}
+
+fn to_money(amount: Decimal, currency: Currency) -> anyhow::Result<Money> {
+    let amount = amount
+        .to_f64()
+        .ok_or_else(|| anyhow::anyhow!("Cannot convert {amount} to `f64`"))?;
+    Money::new_checked(amount, currency)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::{exposure, liability, lose_payoff, stake, win_payoff, BetOutcome};
+    use crate::{
+        accounts::{any::AccountAny, base::Account, betting::BettingAccount, stubs::*},
+        enums::{AccountType, OrderSide},
+        events::{account::stubs::*, AccountState},
+        identifiers::{AccountId, TradeId},
+        instruments::{stubs::*, BettingInstrument, Instrument},
+        position::Position,
+        stubs::stub_order_filled,
+        types::{AccountBalance, Currency, Money, Price, Quantity},
+    };
+
+    #[rstest]
+    fn test_display(betting_account: BettingAccount) {
+        assert_eq!(
+            format!("{betting_account}"),
+            "BettingAccount(id=SIM-001, type=BETTING, base=GBP)"
+        );
+    }
+
+    #[rstest]
+    fn test_instantiate_betting_account(
+        betting_account: BettingAccount,
+        betting_account_state: AccountState,
+    ) {
+        assert_eq!(betting_account.id, AccountId::from("SIM-001"));
+        assert_eq!(betting_account.account_type, AccountType::Betting);
+        assert!(betting_account.is_betting_account());
+        assert!(betting_account.is_cash_account());
+        assert!(!betting_account.is_margin_account());
+        assert_eq!(betting_account.base_currency, Some(Currency::GBP()));
+        assert_eq!(
+            betting_account.last_event(),
+            Some(betting_account_state.clone())
+        );
+        assert_eq!(betting_account.events(), vec![betting_account_state]);
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("0 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_given_new_state_event_updates_correctly(
+        mut betting_account: BettingAccount,
+        betting_account_state: AccountState,
+    ) {
+        let mut new_state = betting_account_state.clone();
+        new_state.balances = vec![AccountBalance::new(
+            Money::from("1000 GBP"),
+            Money::from("150 GBP"),
+            Money::from("850 GBP"),
+        )];
+        betting_account.apply(new_state.clone()).unwrap();
+
+        assert_eq!(betting_account.last_event(), Some(new_state));
+        assert_eq!(betting_account.event_count(), 2);
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("850 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("150 GBP"))
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, Money::from("100 GBP"))]
+    #[case(OrderSide::Sell, Money::from("150 GBP"))]
+    fn test_calculate_balance_locked(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+    ) {
+        let balance_locked = betting_account
+            .calculate_balance_locked(
+                betting.into_any(),
+                side,
+                Quantity::from("100"),
+                Price::from("2.50"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(balance_locked, expected);
+    }
+
+    #[rstest]
+    fn test_calculate_balance_locked_rejects_use_quote_for_inverse(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let result = betting_account.calculate_balance_locked(
+            betting.into_any(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("2.50"),
+            Some(true),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, dec!(150), dec!(-100), dec!(250))]
+    #[case(OrderSide::Sell, dec!(-150), dec!(100), dec!(-250))]
+    fn test_betting_payoffs(
+        #[case] side: OrderSide,
+        #[case] expected_win: Decimal,
+        #[case] expected_lose: Decimal,
+        #[case] expected_exposure: Decimal,
+    ) {
+        let quantity = Quantity::from("100");
+        let price = Price::from("2.50");
+        assert_eq!(stake(quantity, price), dec!(150));
+        assert_eq!(win_payoff(quantity, price, side).unwrap(), expected_win);
+        assert_eq!(lose_payoff(quantity, side).unwrap(), expected_lose);
+        assert_eq!(exposure(quantity, price, side).unwrap(), expected_exposure);
+        assert_eq!(
+            liability(quantity, price, side).unwrap(),
+            -expected_win.min(expected_lose)
+        );
+        assert!(liability(quantity, price, OrderSide::NoOrderSide).is_err());
+    }
+
+    #[rstest]
+    fn test_balance_impact(betting_account: BettingAccount, betting: BettingInstrument) {
+        let instrument = betting.into_any();
+        let quantity = Quantity::from("100");
+        let price = Price::from("2.50");
+        assert_eq!(
+            betting_account
+                .balance_impact(&instrument, quantity, price, OrderSide::Buy)
+                .unwrap(),
+            Money::from("-375 GBP")
+        );
+        assert_eq!(
+            betting_account
+                .balance_impact(&instrument, quantity, price, OrderSide::Sell)
+                .unwrap(),
+            Money::from("-250 GBP")
+        );
+    }
+
+    #[rstest]
+    fn test_account_any_from_betting_account_state(betting_account_state: AccountState) {
+        let account = AccountAny::from(betting_account_state);
+        assert!(matches!(account, AccountAny::Betting(_)));
+        assert_eq!(account.id(), AccountId::from("SIM-001"));
+        assert_eq!(account.base_currency(), Some(Currency::GBP()));
+        assert_eq!(
+            account.balances_locked().get(&Currency::GBP()),
+            Some(&Money::from("0 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_opening_bet_realizes_nothing(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let instrument = betting.into_any();
+        let fill = stub_order_filled(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("20"),
+            Price::from("3.00"),
+            Some(TradeId::from("T-1")),
+        );
+
+        let pnls = betting_account
+            .calculate_pnls(instrument, fill, None)
+            .unwrap();
+
+        assert_eq!(pnls, vec![Money::from("0 GBP")]);
+    }
+
+    #[rstest]
+    #[case(BetOutcome::Win, Money::from("40 GBP"))]
+    #[case(BetOutcome::Lose, Money::from("-20 GBP"))]
+    fn test_settle_back_bet(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] outcome: BetOutcome,
+        #[case] expected: Money,
+    ) {
+        let instrument = betting.into_any();
+        let back = stub_order_filled(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("20"),
+            Price::from("3.00"),
+            Some(TradeId::from("T-1")),
+        );
+        let position = Position::new(&instrument, back);
+
+        let pnl = betting_account
+            .settle(&instrument, &position, outcome)
+            .unwrap();
+
+        // Stake of 20 at odds of 3.00 wins 20 * (3.00 - 1), or loses the stake
+        assert_eq!(pnl, expected);
+    }
+
+    #[rstest]
+    #[case(BetOutcome::Win, Money::from("-150 GBP"))]
+    #[case(BetOutcome::Lose, Money::from("100 GBP"))]
+    fn test_settle_lay_bet(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] outcome: BetOutcome,
+        #[case] expected: Money,
+    ) {
+        let instrument = betting.into_any();
+        let lay = stub_order_filled(
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from("100"),
+            Price::from("2.50"),
+            Some(TradeId::from("T-1")),
+        );
+        let position = Position::new(&instrument, lay);
+
+        let pnl = betting_account
+            .settle(&instrument, &position, outcome)
+            .unwrap();
+
+        // Laying 100 at odds of 2.50 loses the liability of 100 * (2.50 - 1), or wins
+        // the backer's stake
+        assert_eq!(pnl, expected);
+    }
+
+    #[rstest]
+    fn test_hedged_bet_at_same_odds_is_flat(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let instrument = betting.into_any();
+        let back = stub_order_filled(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("2.50"),
+            Some(TradeId::from("T-1")),
+        );
+        let lay = stub_order_filled(
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from("100"),
+            Price::from("2.50"),
+            Some(TradeId::from("T-2")),
+        );
+        let mut position = Position::new(&instrument, back);
+
+        let pnls = betting_account
+            .calculate_pnls(instrument.clone(), lay, Some(position.clone()))
+            .unwrap();
+        position.apply(&lay);
+
+        assert_eq!(pnls, vec![Money::from("0 GBP")]);
+        for outcome in [BetOutcome::Win, BetOutcome::Lose] {
+            assert_eq!(
+                betting_account
+                    .settle(&instrument, &position, outcome)
+                    .unwrap(),
+                Money::from("0 GBP")
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(BetOutcome::Win, Money::from("0 GBP"))]
+    #[case(BetOutcome::Lose, Money::from("-50 GBP"))]
+    fn test_hedged_bet_realizes_trading_pnl(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] outcome: BetOutcome,
+        #[case] expected_settlement: Money,
+    ) {
+        let instrument = betting.into_any();
+        let back = stub_order_filled(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("3.00"),
+            Some(TradeId::from("T-1")),
+        );
+        let lay = stub_order_filled(
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from("100"),
+            Price::from("2.50"),
+            Some(TradeId::from("T-2")),
+        );
+        let mut position = Position::new(&instrument, back);
+
+        let pnls = betting_account
+            .calculate_pnls(instrument.clone(), lay, Some(position.clone()))
+            .unwrap();
+        position.apply(&lay);
+        let settlement = betting_account
+            .settle(&instrument, &position, outcome)
+            .unwrap();
+
+        // Backing at 3.00 and laying at 2.50 wins 200 - 150 = 50 if the selection wins,
+        // and nothing if it loses
+        assert_eq!(pnls, vec![Money::from("50 GBP")]);
+        assert_eq!(settlement, expected_settlement);
+    }
+}
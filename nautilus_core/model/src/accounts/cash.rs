@@ -170,8 +170,8 @@ impl Account for CashAccount {
         self.balances.clone()
     }
 
-    fn apply(&mut self, event: AccountState) {
-        self.base_apply(event);
+    fn apply(&mut self, event: AccountState) -> anyhow::Result<()> {
+        self.base_apply(event)
     }
 
     fn calculate_balance_locked(
@@ -396,7 +396,9 @@ mod tests {
         cash_account_state_multi_changed_btc: AccountState,
     ) {
         // apply second account event
-        cash_account_multi.apply(cash_account_state_multi_changed_btc.clone());
+        cash_account_multi
+            .apply(cash_account_state_multi_changed_btc.clone())
+            .unwrap();
         assert_eq!(
             cash_account_multi.last_event(),
             Some(cash_account_state_multi_changed_btc.clone())
@@ -655,4 +657,24 @@ mod tests {
             .unwrap();
         assert_eq!(result, Money::from("5294 JPY"));
     }
+
+    #[rstest]
+    fn test_apply_rejects_inconsistent_balance(
+        mut cash_account: CashAccount,
+        cash_account_state: AccountState,
+    ) {
+        let balances_before = cash_account.balances();
+        let mut state = cash_account_state;
+        let balance = &mut state.balances[0];
+        balance.free += Money::new(1.0, balance.currency);
+
+        let result = cash_account.apply(state);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Cannot update balances with an inconsistent balance"));
+        assert_eq!(cash_account.balances(), balances_before);
+        assert_eq!(cash_account.event_count(), 1);
+    }
 }
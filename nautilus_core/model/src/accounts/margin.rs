@@ -22,7 +22,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -196,6 +196,36 @@ impl MarginAccount {
         }
     }
 
+    /// Returns the initial margin which would be locked for `quantity` of the `instrument`
+    /// at `price`, including the taker fee on entry and exit, without updating the account.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the leverage for the instrument is not positive.
+    /// - If the margin is not a valid [`Money`] amount.
+    pub fn projected_initial_margin(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+    ) -> anyhow::Result<Money> {
+        let instrument_id = instrument.id();
+        let leverage = self.get_leverage(&instrument_id);
+        if !(leverage.is_finite() && leverage > 0.0) {
+            anyhow::bail!("Invalid leverage for {instrument_id}: {leverage}, must be positive");
+        }
+
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let adjusted_notional = notional.as_decimal() / Decimal::try_from(leverage)?;
+        let margin =
+            adjusted_notional * (instrument.margin_init() + instrument.taker_fee() * Decimal::TWO);
+        let amount = margin
+            .to_f64()
+            .ok_or_else(|| anyhow::anyhow!("Cannot convert {margin} to f64"))?;
+        Money::new_checked(amount, notional.currency)
+    }
+
     pub fn calculate_maintenance_margin<T: Instrument>(
         &mut self,
         instrument: T,
@@ -249,6 +279,101 @@ impl MarginAccount {
         );
         self.balances.insert(currency, new_balance);
     }
+
+    /// Returns a health score for the account in [0, 1], where 1 is healthy and values near
+    /// 0 mean the account is close to liquidation.
+    ///
+    /// For each currency the equity is the total balance plus the unrealized PnL of the open
+    /// `positions` settled in it, valued at `mark_prices` (positions without a mark price are
+    /// valued at zero unrealized PnL). The score for the currency is then:
+    ///
+    /// `0.5 * (1 - maintenance / equity) + 0.5 * (equity - initial - maintenance) / equity`
+    ///
+    /// averaging the distance from the maintenance margin ratio reaching 1 with the free
+    /// buffer remaining after all margin requirements, clamped to [0, 1]. A currency with
+    /// no margin requirements scores 1, and one with non-positive equity scores 0. The
+    /// account health is the lowest score of its currencies.
+    #[must_use]
+    pub fn health_score(
+        &self,
+        mark_prices: &HashMap<InstrumentId, Price>,
+        positions: &[Position],
+    ) -> f64 {
+        let mut equity: HashMap<Currency, f64> = self
+            .balances
+            .iter()
+            .map(|(currency, balance)| (*currency, balance.total.as_f64()))
+            .collect();
+        for position in positions.iter().filter(|position| position.is_open()) {
+            if let Some(mark_price) = mark_prices.get(&position.instrument_id) {
+                *equity.entry(position.settlement_currency).or_default() +=
+                    position.unrealized_pnl(*mark_price).as_f64();
+            }
+        }
+
+        let mut requirements: HashMap<Currency, (f64, f64)> = HashMap::new();
+        for margin in self.margins.values() {
+            let (initial, maintenance) = requirements.entry(margin.currency).or_default();
+            *initial += margin.initial.as_f64();
+            *maintenance += margin.maintenance.as_f64();
+        }
+
+        requirements
+            .into_iter()
+            .filter(|(_, (initial, maintenance))| *initial + *maintenance > 0.0)
+            .map(|(currency, (initial, maintenance))| {
+                let equity = equity.get(&currency).copied().unwrap_or_default();
+                if equity <= 0.0 {
+                    return 0.0;
+                }
+                let margin_ratio = maintenance / equity;
+                let buffer = (equity - initial - maintenance) / equity;
+                0.5f64
+                    .mul_add(1.0 - margin_ratio, 0.5 * buffer)
+                    .clamp(0.0, 1.0)
+            })
+            .fold(1.0, f64::min)
+    }
+
+    /// Returns the amount needed to restore the maintenance margin, or `None` if the account
+    /// is healthy.
+    ///
+    /// For each currency the equity is the total balance plus the unrealized PnL of the open
+    /// `positions` settled in it, valued at `mark_prices` as for [`Self::health_score`], and
+    /// the shortfall is the maintenance margin in excess of the equity. Currencies are
+    /// checked in code order, so for a multi-currency account the first currency short of
+    /// its maintenance margin is returned.
+    #[must_use]
+    pub fn margin_shortfall(
+        &self,
+        mark_prices: &HashMap<InstrumentId, Price>,
+        positions: &[Position],
+    ) -> Option<Money> {
+        let mut equity: HashMap<Currency, i64> = self
+            .balances
+            .iter()
+            .map(|(currency, balance)| (*currency, balance.total.raw))
+            .collect();
+        for position in positions.iter().filter(|position| position.is_open()) {
+            if let Some(mark_price) = mark_prices.get(&position.instrument_id) {
+                *equity.entry(position.settlement_currency).or_default() +=
+                    position.unrealized_pnl(*mark_price).raw;
+            }
+        }
+
+        let mut maintenance: HashMap<Currency, i64> = HashMap::new();
+        for margin in self.margins.values() {
+            *maintenance.entry(margin.currency).or_default() += margin.maintenance.raw;
+        }
+
+        let mut currencies: Vec<Currency> = maintenance.keys().copied().collect();
+        currencies.sort_by_key(|currency| currency.code);
+        currencies.into_iter().find_map(|currency| {
+            let equity = equity.get(&currency).copied().unwrap_or_default();
+            let shortfall = maintenance[&currency] - equity;
+            (shortfall > 0).then(|| Money::from_raw(shortfall, currency))
+        })
+    }
 }
 
 impl Deref for MarginAccount {
@@ -328,8 +453,8 @@ impl Account for MarginAccount {
     fn balances(&self) -> HashMap<Currency, AccountBalance> {
         self.balances.clone()
     }
-    fn apply(&mut self, event: AccountState) {
-        self.base_apply(event);
+    fn apply(&mut self, event: AccountState) -> anyhow::Result<()> {
+        self.base_apply(event)
     }
     fn calculate_balance_locked(
         &mut self,
@@ -407,9 +532,11 @@ mod tests {
 
     use crate::{
         accounts::{stubs::*, Account, MarginAccount},
+        enums::OrderSide,
         events::{account::stubs::*, AccountState},
         identifiers::{stubs::*, InstrumentId},
-        instruments::{stubs::*, CryptoPerpetual, CurrencyPair},
+        instruments::{any::InstrumentAny, stubs::*, CryptoPerpetual, CurrencyPair},
+        stubs::stub_position,
         types::{Currency, Money, Price, Quantity},
     };
 
@@ -644,4 +771,134 @@ mod tests {
         );
         assert_eq!(result, Money::from("0.00042500 BTC"));
     }
+
+    #[rstest]
+    fn test_health_score_without_margins(margin_account: MarginAccount) {
+        assert_eq!(margin_account.health_score(&HashMap::new(), &[]), 1.0);
+    }
+
+    #[rstest]
+    fn test_health_score_healthy_account(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.update_initial_margin(audusd_sim.id, Money::from("20000 USD"));
+        margin_account.update_maintenance_margin(audusd_sim.id, Money::from("10000 USD"));
+        let position = stub_position(
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+        let mark_prices = HashMap::from([(audusd_sim.id, Price::from("1.05000"))]);
+
+        let score = margin_account.health_score(&mark_prices, &[position]);
+
+        // Equity of 1,525,000 + 5,000 unrealized PnL
+        let equity = 1_530_000.0;
+        let expected = 0.5 * (1.0 - 10_000.0 / equity) + 0.5 * (equity - 30_000.0) / equity;
+        assert!((score - expected).abs() < 1e-9);
+        assert!(score > 0.98);
+    }
+
+    #[rstest]
+    fn test_health_score_stressed_account(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.update_initial_margin(audusd_sim.id, Money::from("100000 USD"));
+        margin_account.update_maintenance_margin(audusd_sim.id, Money::from("1300000 USD"));
+        let position = stub_position(
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            OrderSide::Buy,
+            Quantity::from(1_000_000),
+            Price::from("1.00000"),
+        );
+        let healthy = margin_account.health_score(&HashMap::new(), std::slice::from_ref(&position));
+
+        // A 10% adverse move leaves equity of 1,425,000 against 1,400,000 of margin
+        let stressed_prices = HashMap::from([(audusd_sim.id, Price::from("0.90000"))]);
+        let stressed =
+            margin_account.health_score(&stressed_prices, std::slice::from_ref(&position));
+
+        // A 20% adverse move takes equity below the maintenance margin
+        let liquidation_prices = HashMap::from([(audusd_sim.id, Price::from("0.80000"))]);
+        let liquidation = margin_account.health_score(&liquidation_prices, &[position]);
+
+        assert!(healthy > stressed);
+        assert!(stressed > 0.0 && stressed < 0.1);
+        assert_eq!(liquidation, 0.0);
+    }
+
+    #[rstest]
+    fn test_margin_shortfall_healthy_account(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        assert_eq!(margin_account.margin_shortfall(&HashMap::new(), &[]), None);
+
+        margin_account.update_initial_margin(audusd_sim.id, Money::from("100000 USD"));
+        margin_account.update_maintenance_margin(audusd_sim.id, Money::from("1300000 USD"));
+        let position = stub_position(
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            OrderSide::Buy,
+            Quantity::from(1_000_000),
+            Price::from("1.00000"),
+        );
+
+        // A 10% adverse move leaves equity of 1,425,000 above the maintenance margin
+        let mark_prices = HashMap::from([(audusd_sim.id, Price::from("0.90000"))]);
+        assert_eq!(
+            margin_account.margin_shortfall(&mark_prices, &[position]),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_margin_shortfall_under_margined_account(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.update_initial_margin(audusd_sim.id, Money::from("100000 USD"));
+        margin_account.update_maintenance_margin(audusd_sim.id, Money::from("1300000 USD"));
+        let position = stub_position(
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            OrderSide::Buy,
+            Quantity::from(1_000_000),
+            Price::from("1.00000"),
+        );
+
+        // A 20% adverse move leaves equity of 1,325,000 against 1,300,000 of maintenance
+        // margin, while a 25% move leaves 1,275,000
+        let mark_prices = HashMap::from([(audusd_sim.id, Price::from("0.80000"))]);
+        assert_eq!(
+            margin_account.margin_shortfall(&mark_prices, std::slice::from_ref(&position)),
+            None
+        );
+        let mark_prices = HashMap::from([(audusd_sim.id, Price::from("0.75000"))]);
+        assert_eq!(
+            margin_account.margin_shortfall(&mark_prices, &[position]),
+            Some(Money::from("25000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_rejects_inconsistent_balance(
+        mut margin_account: MarginAccount,
+        margin_account_state: AccountState,
+    ) {
+        let balances_before = margin_account.balances();
+        let mut state = margin_account_state;
+        let balance = &mut state.balances[0];
+        balance.free += Money::new(1.0, balance.currency);
+
+        let result = margin_account.apply(state);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Cannot update balances with an inconsistent balance"));
+        assert_eq!(margin_account.balances(), balances_before);
+        assert_eq!(margin_account.event_count(), 1);
+    }
 }
@@ -13,10 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Account types such as `CashAccount` and `MarginAccount`.
+//! Account types such as `CashAccount`, `MarginAccount` and `BettingAccount`.
 
 pub mod any;
 pub mod base;
+pub mod betting;
 pub mod cash;
 pub mod margin;
 
@@ -27,6 +28,7 @@ pub mod stubs;
 pub use crate::accounts::{
     any::AccountAny,
     base::{Account, BaseAccount},
+    betting::BettingAccount,
     cash::CashAccount,
     margin::MarginAccount,
 };
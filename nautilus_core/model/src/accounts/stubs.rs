@@ -16,7 +16,7 @@
 use rstest::fixture;
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{base::Account, betting::BettingAccount, cash::CashAccount, margin::MarginAccount},
     enums::LiquiditySide,
     events::account::{state::AccountState, stubs::*},
     instruments::InstrumentAny,
@@ -33,6 +33,11 @@ pub fn cash_account(cash_account_state: AccountState) -> CashAccount {
     CashAccount::new(cash_account_state, true)
 }
 
+#[fixture]
+pub fn betting_account(betting_account_state: AccountState) -> BettingAccount {
+    BettingAccount::new(betting_account_state, true)
+}
+
 #[fixture]
 pub fn cash_account_million_usd(cash_account_state_million_usd: AccountState) -> CashAccount {
     CashAccount::new(cash_account_state_million_usd, true)
@@ -427,6 +427,42 @@ impl FromU8 for BookType {
     }
 }
 
+/// The reason an order was canceled.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum CancellationReason {
+    /// The cancel was requested by the user or strategy.
+    User = 1,
+    /// The order expired per its time in force.
+    Expired = 2,
+    /// The order was canceled by a triggered One-Cancels-the-Other contingency.
+    OcoTriggered = 3,
+    /// The order was canceled after being rejected by a risk check.
+    RiskRejected = 4,
+    /// The order was canceled by the venue.
+    Venue = 5,
+}
+
 /// The order contigency type which specifies the behavior of linked orders.
 ///
 /// [FIX 5.0 SP2 : ContingencyType <1385> field](https://www.onixs.biz/fix-dictionary/5.0.sp2/tagnum_1385.html).
@@ -806,6 +842,7 @@ impl FromU8 for OrderSide {
 }
 
 /// The specified order side (BUY or SELL).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum OrderSideSpecified {
     /// The order is a BUY.
     Buy = 1,
@@ -1225,6 +1262,7 @@ enum_strum_serde!(BarAggregation);
 enum_strum_serde!(BarIntervalType);
 enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
+enum_strum_serde!(CancellationReason);
 enum_strum_serde!(ContingencyType);
 enum_strum_serde!(CurrencyType);
 enum_strum_serde!(InstrumentCloseType);
@@ -15,7 +15,11 @@
 
 use std::fmt::{Display, Formatter};
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    nanos::UnixNanos,
+    serialization::{from_bytes, to_bytes, WireFormat},
+    uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -68,6 +72,24 @@ impl AccountState {
             ts_init,
         }
     }
+
+    /// Serializes the account state to bytes in the given wire `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if serialization fails.
+    pub fn to_bytes(&self, format: WireFormat) -> anyhow::Result<Vec<u8>> {
+        to_bytes(self, format)
+    }
+
+    /// Deserializes an account state from `bytes` encoded in the given wire `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `bytes` is not a valid encoding of an account state.
+    pub fn from_bytes(format: WireFormat, bytes: &[u8]) -> anyhow::Result<Self> {
+        from_bytes(format, bytes)
+    }
 }
 
 impl Display for AccountState {
@@ -100,6 +122,7 @@ impl PartialEq for AccountState {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::WireFormat;
     use rstest::rstest;
 
     use crate::events::{
@@ -107,6 +130,20 @@ mod tests {
         AccountState,
     };
 
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    #[case(WireFormat::Bincode)]
+    fn test_round_trip(#[case] format: WireFormat, margin_account_state: AccountState) {
+        let bytes = margin_account_state.to_bytes(format).unwrap();
+        let result = AccountState::from_bytes(format, &bytes).unwrap();
+
+        assert_eq!(result, margin_account_state);
+        assert_eq!(result.balances, margin_account_state.balances);
+        assert_eq!(result.margins, margin_account_state.margins);
+        assert_eq!(result.ts_event, margin_account_state.ts_event);
+    }
+
     #[rstest]
     fn test_equality() {
         let cash_account_state_1 = cash_account_state();
@@ -133,6 +133,25 @@ pub fn cash_account_state_multi_changed_btc() -> AccountState {
     )
 }
 
+#[fixture]
+pub fn betting_account_state() -> AccountState {
+    AccountState::new(
+        account_id(),
+        AccountType::Betting,
+        vec![AccountBalance::new(
+            Money::from("1000 GBP"),
+            Money::from("0 GBP"),
+            Money::from("1000 GBP"),
+        )],
+        vec![],
+        true,
+        uuid4(),
+        0.into(),
+        0.into(),
+        Some(Currency::GBP()),
+    )
+}
+
 #[fixture]
 pub fn margin_account_state() -> AccountState {
     AccountState::new(
@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use thiserror::Error;
+
+use crate::{
+    accounts::AccountAny,
+    enums::AccountType,
+    events::{AccountState, OrderEventAny},
+    identifiers::{AccountId, ClientOrderId},
+    orders::{OrderAny, OrderError},
+};
+
+/// Represents an event in a chronological log mixing order events and account states.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum DomainEvent {
+    Order(OrderEventAny),
+    Account(AccountState),
+}
+
+impl From<OrderEventAny> for DomainEvent {
+    fn from(event: OrderEventAny) -> Self {
+        Self::Order(event)
+    }
+}
+
+impl From<AccountState> for DomainEvent {
+    fn from(event: AccountState) -> Self {
+        Self::Account(event)
+    }
+}
+
+/// Represents an error replaying the events of a single aggregate.
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("First event for order {0} must be `OrderInitialized`")]
+    OrderNotInitialized(ClientOrderId),
+    #[error("Error applying event to order {0}: {1}")]
+    Order(ClientOrderId, OrderError),
+    #[error("Account type mismatch for account {0}: expected {1}, was {2}")]
+    AccountTypeMismatch(AccountId, AccountType, AccountType),
+    #[error("Error applying state to account {0}: {1}")]
+    Account(AccountId, anyhow::Error),
+}
+
+/// Replays the chronological `events`, routing each to the order or account it belongs to,
+/// returning the rebuilt orders and accounts along with any errors.
+///
+/// An aggregate which fails to replay is excluded from the results, and its later events
+/// are skipped, while the other aggregates continue to replay.
+#[must_use]
+pub fn replay(
+    events: Vec<DomainEvent>,
+) -> (
+    HashMap<ClientOrderId, OrderAny>,
+    HashMap<AccountId, AccountAny>,
+    Vec<ReplayError>,
+) {
+    let mut orders: HashMap<ClientOrderId, OrderAny> = HashMap::new();
+    let mut accounts: HashMap<AccountId, AccountAny> = HashMap::new();
+    let mut failed_orders: HashSet<ClientOrderId> = HashSet::new();
+    let mut failed_accounts: HashSet<AccountId> = HashSet::new();
+    let mut errors = Vec::new();
+
+    for event in events {
+        match event {
+            DomainEvent::Order(event) => {
+                let client_order_id = event.client_order_id();
+                if failed_orders.contains(&client_order_id) {
+                    continue;
+                }
+
+                let result = if let Some(order) = orders.get_mut(&client_order_id) {
+                    order
+                        .apply(event)
+                        .map_err(|e| ReplayError::Order(client_order_id, e))
+                } else if let OrderEventAny::Initialized(init) = event {
+                    orders.insert(client_order_id, OrderAny::from(init));
+                    Ok(())
+                } else {
+                    Err(ReplayError::OrderNotInitialized(client_order_id))
+                };
+
+                if let Err(e) = result {
+                    orders.remove(&client_order_id);
+                    failed_orders.insert(client_order_id);
+                    errors.push(e);
+                }
+            }
+            DomainEvent::Account(state) => {
+                let account_id = state.account_id;
+                if failed_accounts.contains(&account_id) {
+                    continue;
+                }
+
+                match accounts.entry(account_id) {
+                    Entry::Occupied(entry) if entry.get().account_type() != state.account_type => {
+                        let account = entry.remove();
+                        failed_accounts.insert(account_id);
+                        errors.push(ReplayError::AccountTypeMismatch(
+                            account_id,
+                            account.account_type(),
+                            state.account_type,
+                        ));
+                    }
+                    Entry::Occupied(mut entry) => {
+                        if let Err(e) = entry.get_mut().apply(state) {
+                            entry.remove();
+                            failed_accounts.insert(account_id);
+                            errors.push(ReplayError::Account(account_id, e));
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(AccountAny::from(state));
+                    }
+                }
+            }
+        }
+    }
+
+    (orders, accounts, errors)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{replay, DomainEvent, ReplayError};
+    use crate::{
+        enums::{LiquiditySide, OrderSide, OrderStatus, OrderType},
+        events::{account::stubs::*, AccountState},
+        identifiers::{stubs::account_id, AccountId},
+        instruments::{stubs::*, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderStubs, OrderTestBuilder},
+        types::{Currency, Money, Price, Quantity},
+    };
+
+    #[rstest]
+    fn test_replay_interleaved_order_and_account_events(
+        audusd_sim: CurrencyPair,
+        cash_account_state_million_usd: AccountState,
+        cash_account_state: AccountState,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order = TestOrderStubs::make_filled_order(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            LiquiditySide::Maker,
+        );
+        let order_events: Vec<DomainEvent> = order
+            .events()
+            .into_iter()
+            .map(|event| event.clone().into())
+            .collect();
+
+        let mut events = Vec::new();
+        events.push(cash_account_state_million_usd.into());
+        let mut order_events = order_events.into_iter();
+        events.extend(order_events.by_ref().take(2));
+        events.push(cash_account_state.clone().into());
+        events.extend(order_events);
+
+        let (orders, accounts, errors) = replay(events);
+
+        assert!(errors.is_empty());
+        let replayed = &orders[&order.client_order_id()];
+        assert_eq!(replayed.status(), OrderStatus::Filled);
+        assert_eq!(replayed.events().len(), order.events().len());
+        assert_eq!(replayed.filled_qty(), Quantity::from(100_000));
+
+        let account = &accounts[&account_id()];
+        assert_eq!(account.events().len(), 2);
+        assert_eq!(
+            account.total(Currency::USD()),
+            Some(cash_account_state.balances[0].total)
+        );
+    }
+
+    #[rstest]
+    fn test_replay_collects_errors_per_aggregate(
+        audusd_sim: CurrencyPair,
+        cash_account_state: AccountState,
+        margin_account_state: AccountState,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order = TestOrderStubs::make_filled_order(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            LiquiditySide::Maker,
+        );
+        let order_events = order.events();
+        let mut other_state = cash_account_state.clone();
+        other_state.account_id = AccountId::from("SIM-002");
+
+        // The order stream is missing its initialized event, and the first account
+        // changes type mid-stream
+        let events: Vec<DomainEvent> = vec![
+            cash_account_state.into(),
+            order_events[1].clone().into(),
+            other_state.into(),
+            margin_account_state.into(),
+            order_events[2].clone().into(),
+        ];
+
+        let (orders, accounts, errors) = replay(events);
+
+        assert!(orders.is_empty());
+        assert_eq!(accounts.len(), 1);
+        assert!(accounts.contains_key(&AccountId::from("SIM-002")));
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0],
+            ReplayError::OrderNotInitialized(id) if id == order.client_order_id()
+        ));
+        assert!(matches!(
+            errors[1],
+            ReplayError::AccountTypeMismatch(id, _, _) if id == account_id()
+        ));
+    }
+
+    #[rstest]
+    fn test_replay_rejects_inconsistent_account_state(
+        cash_account_state_million_usd: AccountState,
+        cash_account_state: AccountState,
+    ) {
+        let mut inconsistent = cash_account_state;
+        let balance = &mut inconsistent.balances[0];
+        balance.free += Money::new(1.0, balance.currency);
+
+        let (_, accounts, errors) = replay(vec![
+            cash_account_state_million_usd.into(),
+            inconsistent.into(),
+        ]);
+
+        assert!(accounts.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ReplayError::Account(id, _) if id == account_id()
+        ));
+    }
+}
@@ -16,12 +16,14 @@
 //! Events for the trading domain model.
 
 pub mod account;
+pub mod domain;
 pub mod order;
 pub mod position;
 
 // Re-exports
 pub use crate::events::{
     account::state::AccountState,
+    domain::{replay, DomainEvent, ReplayError},
     order::{
         accepted::OrderAccepted, any::OrderEventAny, cancel_rejected::OrderCancelRejected,
         canceled::OrderCanceled, denied::OrderDenied, emulated::OrderEmulated,
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{
+    nanos::UnixNanos,
+    serialization::{from_bytes, to_bytes, WireFormat},
+};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use ustr::Ustr;
@@ -98,6 +101,36 @@ impl OrderEventAny {
         }
     }
 
+    /// Serializes the event to bytes in the given wire `format`.
+    ///
+    /// [`WireFormat::Bincode`] is not supported for any event variant. Each event carries
+    /// an internal `type` tag and decodes its `reconciliation` flag from either a bool or
+    /// an integer, to stay compatible with the JSON produced by the Python events, and both
+    /// require a self-describing format to deserialize. Use [`WireFormat::MsgPack`] where a
+    /// compact encoding is needed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `format` is [`WireFormat::Bincode`].
+    /// - If serialization fails.
+    pub fn to_bytes(&self, format: WireFormat) -> anyhow::Result<Vec<u8>> {
+        check_wire_format(format)?;
+        to_bytes(self, format)
+    }
+
+    /// Deserializes an event from `bytes` encoded in the given wire `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `format` is [`WireFormat::Bincode`], see [`Self::to_bytes`].
+    /// - If `bytes` is not a valid encoding of an order event.
+    pub fn from_bytes(format: WireFormat, bytes: &[u8]) -> anyhow::Result<Self> {
+        check_wire_format(format)?;
+        from_bytes(format, bytes)
+    }
+
     #[must_use]
     pub fn trader_id(&self) -> TraderId {
         match self {
@@ -262,8 +295,110 @@ impl OrderEventAny {
 impl From<OrderEventAny> for OrderFilled {
     fn from(event: OrderEventAny) -> OrderFilled {
         match event {
-            OrderEventAny::Filled(event) => event,
+            OrderEventAny::PartiallyFilled(event) | OrderEventAny::Filled(event) => event,
             _ => panic!("Invalid `OrderEventAny` not `OrderFilled`, was {:?}", event),
         }
     }
 }
+
+fn check_wire_format(format: WireFormat) -> anyhow::Result<()> {
+    // The `type` tag and `reconciliation` flag are decoded with `deserialize_any`, which
+    // non self-describing formats cannot support
+    if format == WireFormat::Bincode {
+        anyhow::bail!("Order events cannot be serialized with {format:?}");
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::WireFormat;
+    use rstest::{fixture, rstest};
+
+    use crate::events::{
+        order::stubs::*, OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied,
+        OrderEmulated, OrderEventAny, OrderExpired, OrderFilled, OrderInitialized,
+        OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate, OrderRejected, OrderReleased,
+        OrderSubmitted, OrderTriggered, OrderUpdated,
+    };
+
+    #[fixture]
+    fn all_order_events(
+        order_initialized_buy_limit: OrderInitialized,
+        order_denied_max_submitted_rate: OrderDenied,
+        order_emulated: OrderEmulated,
+        order_released: OrderReleased,
+        order_submitted: OrderSubmitted,
+        order_accepted: OrderAccepted,
+        order_rejected_insufficient_margin: OrderRejected,
+        order_canceled: OrderCanceled,
+        order_expired: OrderExpired,
+        order_triggered: OrderTriggered,
+        order_pending_update: OrderPendingUpdate,
+        order_pending_cancel: OrderPendingCancel,
+        order_modify_rejected: OrderModifyRejected,
+        order_cancel_rejected: OrderCancelRejected,
+        order_updated: OrderUpdated,
+        order_filled: OrderFilled,
+    ) -> Vec<OrderEventAny> {
+        vec![
+            OrderEventAny::Initialized(order_initialized_buy_limit),
+            OrderEventAny::Denied(order_denied_max_submitted_rate),
+            OrderEventAny::Emulated(order_emulated),
+            OrderEventAny::Released(order_released),
+            OrderEventAny::Submitted(order_submitted),
+            OrderEventAny::Accepted(order_accepted),
+            OrderEventAny::Rejected(order_rejected_insufficient_margin),
+            OrderEventAny::Canceled(order_canceled),
+            OrderEventAny::Expired(order_expired),
+            OrderEventAny::Triggered(order_triggered),
+            OrderEventAny::PendingUpdate(order_pending_update),
+            OrderEventAny::PendingCancel(order_pending_cancel),
+            OrderEventAny::ModifyRejected(order_modify_rejected),
+            OrderEventAny::CancelRejected(order_cancel_rejected),
+            OrderEventAny::Updated(order_updated),
+            OrderEventAny::PartiallyFilled(order_filled),
+            OrderEventAny::Filled(order_filled),
+        ]
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    fn test_round_trip_order_events(
+        #[case] format: WireFormat,
+        all_order_events: Vec<OrderEventAny>,
+    ) {
+        assert_eq!(all_order_events.len(), 17);
+
+        for event in all_order_events {
+            let bytes = event.to_bytes(format).unwrap();
+            let result = OrderEventAny::from_bytes(format, &bytes).unwrap();
+            assert_eq!(result, event);
+            assert_eq!(result.event_type(), event.event_type());
+        }
+    }
+
+    #[rstest]
+    fn test_msgpack_smaller_than_json(all_order_events: Vec<OrderEventAny>) {
+        for event in all_order_events {
+            let json = event.to_bytes(WireFormat::Json).unwrap();
+            let msgpack = event.to_bytes(WireFormat::MsgPack).unwrap();
+
+            assert!(msgpack.len() < json.len(), "{event}");
+        }
+    }
+
+    #[rstest]
+    fn test_bincode_not_supported(all_order_events: Vec<OrderEventAny>) {
+        for event in all_order_events {
+            let json = event.to_bytes(WireFormat::Json).unwrap();
+
+            assert!(event.to_bytes(WireFormat::Bincode).is_err());
+            assert!(OrderEventAny::from_bytes(WireFormat::Bincode, &json).is_err());
+        }
+    }
+}
@@ -22,8 +22,8 @@ use ustr::Ustr;
 
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
-        TriggerType,
+        CancellationReason, ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce,
+        TrailingOffsetType, TriggerType,
     },
     events::OrderEvent,
     identifiers::{
@@ -53,6 +53,7 @@ pub struct OrderCanceled {
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
     pub venue_order_id: Option<VenueOrderId>,
     pub account_id: Option<AccountId>,
+    pub cancellation_reason: Option<CancellationReason>,
 }
 
 impl OrderCanceled {
@@ -69,6 +70,7 @@ impl OrderCanceled {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        cancellation_reason: Option<CancellationReason>,
     ) -> Self {
         Self {
             trader_id,
@@ -81,6 +83,7 @@ impl OrderCanceled {
             reconciliation: u8::from(reconciliation),
             venue_order_id,
             account_id,
+            cancellation_reason,
         }
     }
 }
@@ -20,11 +20,15 @@ use rstest::fixture;
 use ustr::Ustr;
 
 use crate::{
-    enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{
+        CancellationReason, ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce,
+        TriggerType,
+    },
     events::{
-        OrderAccepted, OrderCancelRejected, OrderDenied, OrderEmulated, OrderExpired, OrderFilled,
-        OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
-        OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered, OrderUpdated,
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
+        OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel,
+        OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered,
+        OrderUpdated,
     },
     identifiers::{
         stubs::{
@@ -266,6 +270,8 @@ pub fn order_updated(
         Some(account_id),
         Some(Price::from("22000")),
         None,
+        None,
+        None,
     )
 }
 
@@ -414,3 +420,28 @@ pub fn order_expired(
         Some(account_id),
     )
 }
+
+#[fixture]
+pub fn order_canceled(
+    trader_id: TraderId,
+    strategy_id_ema_cross: StrategyId,
+    instrument_id_btc_usdt: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    uuid4: UUID4,
+) -> OrderCanceled {
+    OrderCanceled::new(
+        trader_id,
+        strategy_id_ema_cross,
+        instrument_id_btc_usdt,
+        client_order_id,
+        uuid4,
+        UnixNanos::default(),
+        UnixNanos::default(),
+        false,
+        Some(venue_order_id),
+        Some(account_id),
+        Some(CancellationReason::User),
+    )
+}
@@ -51,6 +51,8 @@ pub struct OrderUpdated {
     pub quantity: Quantity,
     pub price: Option<Price>,
     pub trigger_price: Option<Price>,
+    pub limit_offset: Option<Price>,
+    pub trailing_offset: Option<Price>,
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
@@ -75,6 +77,8 @@ impl OrderUpdated {
         account_id: Option<AccountId>,
         price: Option<Price>,
         trigger_price: Option<Price>,
+        limit_offset: Option<Price>,
+        trailing_offset: Option<Price>,
     ) -> Self {
         Self {
             trader_id,
@@ -90,6 +94,8 @@ impl OrderUpdated {
             account_id,
             price,
             trigger_price,
+            limit_offset,
+            trailing_offset,
         }
     }
 }
@@ -228,11 +234,11 @@ impl OrderEvent for OrderUpdated {
     }
 
     fn limit_offset(&self) -> Option<Price> {
-        None
+        self.limit_offset
     }
 
     fn trailing_offset(&self) -> Option<Price> {
-        None
+        self.trailing_offset
     }
 
     fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
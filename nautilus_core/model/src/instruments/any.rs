@@ -498,6 +498,22 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn margin_init(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_init(),
+            Self::BinaryOption(inst) => inst.margin_init(),
+            Self::CryptoFuture(inst) => inst.margin_init(),
+            Self::CryptoPerpetual(inst) => inst.margin_init(),
+            Self::CurrencyPair(inst) => inst.margin_init(),
+            Self::Equity(inst) => inst.margin_init(),
+            Self::FuturesContract(inst) => inst.margin_init(),
+            Self::FuturesSpread(inst) => inst.margin_init(),
+            Self::OptionsContract(inst) => inst.margin_init(),
+            Self::OptionsSpread(inst) => inst.margin_init(),
+        }
+    }
+
     pub fn get_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
@@ -13,13 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{
+    correctness::check_equal, datetime::unix_nanos_to_iso8601, nanos::UnixNanos, uuid::UUID4,
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    base::{Order, OrderError},
+    base::{Order, OrderCore, OrderError},
     limit::LimitOrder,
     limit_if_touched::LimitIfTouchedOrder,
     market::MarketOrder,
@@ -31,19 +37,98 @@ use super::{
     trailing_stop_market::TrailingStopMarketOrder,
 };
 use crate::{
+    accounts::margin::MarginAccount,
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
-        PositionSide, TriggerType,
+        CancellationReason, ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified,
+        OrderStatus, OrderType, PositionSide, TimeInForce, TriggerType,
+    },
+    events::{
+        OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired, OrderModifyRejected,
+        OrderPendingCancel, OrderPendingUpdate, OrderTriggered, OrderUpdated,
     },
-    events::OrderEventAny,
     identifiers::{
-        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
-        VenueOrderId,
+        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
+        StrategyId, TraderId, VenueOrderId,
+    },
+    instruments::InstrumentAny,
+    orderbook::OrderBook,
+    position::Position,
+    types::{
+        price::check_price_increment, quantity::check_quantity_increment, Currency, Money, Price,
+        Quantity,
     },
-    types::{Price, Quantity},
 };
 
+/// Represents an order's price fields formatted at an instrument's price precision.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceStrings {
+    /// The formatted limit price (if applicable).
+    pub price: Option<String>,
+    /// The formatted trigger price (if applicable).
+    pub trigger_price: Option<String>,
+}
+
+/// Represents an order whose local state diverges from its state at the venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderDivergence {
+    /// The client order ID of the diverging order.
+    pub client_order_id: ClientOrderId,
+    /// The local order status.
+    pub local_status: OrderStatus,
+    /// The order status at the venue.
+    pub venue_status: OrderStatus,
+    /// The local filled quantity.
+    pub local_filled_qty: Quantity,
+    /// The filled quantity at the venue.
+    pub venue_filled_qty: Quantity,
+}
+
+/// Represents the result of reconciling local orders against the orders at a venue.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReconcileReport {
+    /// The orders present locally but not at the venue.
+    pub missing_at_venue: Vec<ClientOrderId>,
+    /// The orders present at the venue but not locally.
+    pub missing_locally: Vec<ClientOrderId>,
+    /// The orders whose status or filled quantity diverge.
+    pub divergent: Vec<OrderDivergence>,
+}
+
+impl ReconcileReport {
+    /// Returns whether the local orders are consistent with the venue.
+    #[must_use]
+    pub fn is_reconciled(&self) -> bool {
+        self.missing_at_venue.is_empty()
+            && self.missing_locally.is_empty()
+            && self.divergent.is_empty()
+    }
+}
+
+/// Represents the take-profit and stop-loss levels implied by an order's linked orders.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BracketLevels {
+    /// The take-profit price (if a linked take-profit order exists).
+    pub take_profit: Option<Price>,
+    /// The stop-loss trigger price (if a linked stop-loss order exists).
+    pub stop_loss: Option<Price>,
+}
+
+/// Represents an order's contribution to the inputs of a portfolio VaR calculation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RiskExposure {
+    /// The notional value of the order, positive when the order would add exposure and
+    /// negative when it would reduce exposure.
+    pub signed_notional: Decimal,
+    /// The currency of the notional value.
+    pub currency: Currency,
+}
+
+/// Wraps an order allowing polymorphism.
+///
+/// Serialized internally tagged by `order_type`, so the JSON schema is that of the
+/// wrapped order with an added `order_type` field, e.g. `"order_type": "TRAILING_STOP_LIMIT"`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "order_type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderAny {
     Limit(LimitOrder),
     LimitIfTouched(LimitIfTouchedOrder),
@@ -123,6 +208,65 @@ impl OrderAny {
         Self::StopMarket(order)
     }
 
+    /// Creates a reduce-only market order closing `pct` percent of the `position` quantity.
+    ///
+    /// The quantity is rounded down to the instrument size increment, so the order never
+    /// exceeds the position.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `pct` is not within (0, 100].
+    /// - If the position is flat.
+    /// - If the rounded quantity is zero.
+    pub fn from_position_pct(
+        position: &Position,
+        pct: Decimal,
+        client_order_id: ClientOrderId,
+        instrument: &InstrumentAny,
+        ts_init: UnixNanos,
+    ) -> Result<Self, OrderError> {
+        if pct <= Decimal::ZERO || pct > Decimal::ONE_HUNDRED {
+            return Err(OrderError::InvalidPositionPercentage(pct));
+        }
+        let order_side = match position.side {
+            PositionSide::Long => OrderSide::Sell,
+            PositionSide::Short => OrderSide::Buy,
+            _ => return Err(OrderError::FlatPosition(position.id)),
+        };
+
+        let increment = instrument.size_increment().as_decimal();
+        let raw_qty = position.quantity.as_decimal() * pct / Decimal::ONE_HUNDRED;
+        let qty = (raw_qty / increment).floor() * increment;
+        if qty.is_zero() {
+            return Err(OrderError::InvalidPositionPercentage(pct));
+        }
+
+        let order = MarketOrder::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            order_side,
+            Quantity::new(qty.to_f64().unwrap(), instrument.size_precision()),
+            TimeInForce::Gtc,
+            UUID4::new(),
+            ts_init,
+            true,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        Ok(Self::Market(order))
+    }
+
     pub fn from_events(events: Vec<OrderEventAny>) -> anyhow::Result<Self> {
         if events.is_empty() {
             anyhow::bail!("No order events provided to create OrderAny");
@@ -147,6 +291,87 @@ impl OrderAny {
         }
     }
 
+    /// Serializes a compact snapshot of the order state to JSON, retaining only the
+    /// `OrderInitialized` and last events of the history.
+    ///
+    /// The order state, including the `trade_ids`, `venue_order_ids`, `init_event` and
+    /// `last_event`, is preserved. Methods which read the event history itself only see
+    /// the retained events on an order restored from the snapshot: `events` and
+    /// `event_count`, and those replaying the events, i.e. `venue_order_statuses`,
+    /// `spread_capture`, `fill_gaps` and `to_mermaid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn snapshot(&self) -> anyhow::Result<String> {
+        let mut order = self.clone();
+        let core: &mut OrderCore = match &mut order {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        };
+        core.compact_events();
+        order.to_json()
+    }
+
+    /// Restores an order from a `snapshot`, applying the `events` received after the
+    /// snapshot was taken, so the order is rebuilt without replaying its full history.
+    ///
+    /// Methods which read the event history (see [`Self::snapshot`]) only reflect the
+    /// events retained in the snapshot and those applied after it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `snapshot` is not a valid order snapshot.
+    /// - If any of the `events` cannot be applied to the restored order.
+    pub fn from_snapshot(snapshot: &str, events: Vec<OrderEventAny>) -> anyhow::Result<Self> {
+        let mut order = Self::from_json(snapshot)?;
+        for event in events {
+            order.apply(event)?;
+        }
+        Ok(order)
+    }
+
+    /// Deserializes an order, with its full event history, from a JSON snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid order snapshot, or has an unknown `order_type`.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serializes the order, with its full event history, to a JSON snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    #[must_use]
+    pub fn events(&self) -> Vec<&OrderEventAny> {
+        match self {
+            Self::Limit(order) => order.events(),
+            Self::LimitIfTouched(order) => order.events(),
+            Self::Market(order) => order.events(),
+            Self::MarketIfTouched(order) => order.events(),
+            Self::MarketToLimit(order) => order.events(),
+            Self::StopLimit(order) => order.events(),
+            Self::StopMarket(order) => order.events(),
+            Self::TrailingStopLimit(order) => order.events(),
+            Self::TrailingStopMarket(order) => order.events(),
+        }
+    }
+
     #[must_use]
     pub fn last_event(&self) -> &OrderEventAny {
         match self {
@@ -342,6 +567,36 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_init,
+            Self::LimitIfTouched(order) => order.ts_init,
+            Self::Market(order) => order.ts_init,
+            Self::MarketIfTouched(order) => order.ts_init,
+            Self::MarketToLimit(order) => order.ts_init,
+            Self::StopLimit(order) => order.ts_init,
+            Self::StopMarket(order) => order.ts_init,
+            Self::TrailingStopLimit(order) => order.ts_init,
+            Self::TrailingStopMarket(order) => order.ts_init,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_last(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_last,
+            Self::LimitIfTouched(order) => order.ts_last,
+            Self::Market(order) => order.ts_last,
+            Self::MarketIfTouched(order) => order.ts_last,
+            Self::MarketToLimit(order) => order.ts_last,
+            Self::StopLimit(order) => order.ts_last,
+            Self::StopMarket(order) => order.ts_last,
+            Self::TrailingStopLimit(order) => order.ts_last,
+            Self::TrailingStopMarket(order) => order.ts_last,
+        }
+    }
+
     #[must_use]
     pub fn status(&self) -> OrderStatus {
         match self {
@@ -357,6 +612,22 @@ impl OrderAny {
         }
     }
 
+    /// Returns the reason the order was canceled, if canceled with a known reason.
+    #[must_use]
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        match self {
+            Self::Limit(order) => order.cancellation_reason,
+            Self::LimitIfTouched(order) => order.cancellation_reason,
+            Self::Market(order) => order.cancellation_reason,
+            Self::MarketIfTouched(order) => order.cancellation_reason,
+            Self::MarketToLimit(order) => order.cancellation_reason,
+            Self::StopLimit(order) => order.cancellation_reason,
+            Self::StopMarket(order) => order.cancellation_reason,
+            Self::TrailingStopLimit(order) => order.cancellation_reason,
+            Self::TrailingStopMarket(order) => order.cancellation_reason,
+        }
+    }
+
     #[must_use]
     pub fn filled_qty(&self) -> Quantity {
         match self {
@@ -522,6 +793,17 @@ impl OrderAny {
         }
     }
 
+    /// Returns whether filling the leaves quantity of the order would increase exposure
+    /// given the current net `position`, so the order would violate a reduce-only
+    /// instruction.
+    ///
+    /// With no position (or a flat one) any order increases exposure, as does an order
+    /// on the same side as the position or one large enough to flip it.
+    #[must_use]
+    pub fn would_increase_exposure(&self, position: Option<&Position>) -> bool {
+        position.is_none_or(|position| !self.would_reduce_only(position.side, position.quantity))
+    }
+
     #[must_use]
     pub fn is_reduce_only(&self) -> bool {
         match self {
@@ -597,6 +879,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn order_list_id(&self) -> Option<OrderListId> {
+        match self {
+            Self::Limit(order) => order.order_list_id,
+            Self::LimitIfTouched(order) => order.order_list_id,
+            Self::Market(order) => order.order_list_id,
+            Self::MarketIfTouched(order) => order.order_list_id,
+            Self::MarketToLimit(order) => order.order_list_id,
+            Self::StopLimit(order) => order.order_list_id,
+            Self::StopMarket(order) => order.order_list_id,
+            Self::TrailingStopLimit(order) => order.order_list_id,
+            Self::TrailingStopMarket(order) => order.order_list_id,
+        }
+    }
+
     #[must_use]
     pub fn linked_order_ids(&self) -> Option<Vec<ClientOrderId>> {
         match self {
@@ -611,6 +908,624 @@ impl OrderAny {
             Self::TrailingStopMarket(order) => order.linked_order_ids.clone(),
         }
     }
+
+    /// Returns the take-profit and stop-loss levels implied by the `siblings` linked to the
+    /// order, such as the child orders of a bracket order's entry leg.
+    ///
+    /// A sibling is linked if it's in the order's linked order IDs or has the order as its
+    /// parent. Limit and if-touched orders are take-profits, priced at their limit price
+    /// (or trigger price for market-if-touched). Stop orders are stop-losses, priced at
+    /// their trigger price.
+    #[must_use]
+    pub fn bracket_levels(&self, siblings: &[&OrderAny]) -> BracketLevels {
+        let client_order_id = self.client_order_id();
+        let linked_order_ids = self.linked_order_ids().unwrap_or_default();
+        let mut levels = BracketLevels::default();
+
+        for sibling in siblings.iter().filter(|sibling| {
+            sibling.client_order_id() != client_order_id
+                && (linked_order_ids.contains(&sibling.client_order_id())
+                    || sibling.parent_order_id() == Some(client_order_id))
+        }) {
+            match sibling.order_type() {
+                OrderType::Limit | OrderType::LimitIfTouched | OrderType::MarketIfTouched => {
+                    levels.take_profit = levels
+                        .take_profit
+                        .or_else(|| sibling.price().or_else(|| sibling.trigger_price()));
+                }
+                OrderType::StopMarket
+                | OrderType::StopLimit
+                | OrderType::TrailingStopMarket
+                | OrderType::TrailingStopLimit => {
+                    levels.stop_loss = levels.stop_loss.or_else(|| sibling.trigger_price());
+                }
+                OrderType::Market | OrderType::MarketToLimit => {}
+            }
+        }
+        levels
+    }
+
+    /// Returns whether the order's leaves quantity could fully fill against `book` within
+    /// `max_slippage_ticks` price increments of the best opposing price.
+    ///
+    /// The order's limit price (if applicable) further bounds the acceptable fill price.
+    #[must_use]
+    pub fn would_fill_within(
+        &self,
+        book: &OrderBook,
+        max_slippage_ticks: u32,
+        instrument: &InstrumentAny,
+    ) -> bool {
+        let side = self.order_side();
+        let slippage = instrument.price_increment().as_f64() * f64::from(max_slippage_ticks);
+        let bound = match side.as_specified() {
+            OrderSideSpecified::Buy => book.best_ask_price().map(|best_ask| {
+                let bound = instrument.make_price(best_ask.as_f64() + slippage);
+                self.price().map_or(bound, |price| bound.min(price))
+            }),
+            OrderSideSpecified::Sell => book.best_bid_price().map(|best_bid| {
+                let bound = instrument.make_price(best_bid.as_f64() - slippage);
+                self.price().map_or(bound, |price| bound.max(price))
+            }),
+        };
+
+        match bound {
+            Some(bound) => book.get_quantity_for_price(bound, side) >= self.leaves_qty().as_f64(),
+            None => false,
+        }
+    }
+
+    /// Returns the order's price fields as decimal strings at the `instrument` price precision.
+    #[must_use]
+    pub fn price_strings(&self, instrument: &InstrumentAny) -> PriceStrings {
+        let format_price = |price: Price| instrument.make_price(price.as_f64()).to_string();
+        PriceStrings {
+            price: self.price().map(format_price),
+            trigger_price: self.trigger_price().map(format_price),
+        }
+    }
+
+    /// Returns the effective leverage of the order on the margin `account`, as the notional
+    /// value at `price` over the initial margin which would be locked for the order, see
+    /// [`MarginAccount::projected_initial_margin`].
+    ///
+    /// Returns zero if no margin would be locked.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the leverage of the `account` for the instrument is not positive.
+    /// - If the margin is not a valid [`Money`] amount.
+    pub fn effective_leverage(
+        &self,
+        account: &MarginAccount,
+        instrument: &InstrumentAny,
+        price: Price,
+    ) -> anyhow::Result<Decimal> {
+        let margin = account.projected_initial_margin(instrument, self.quantity(), price)?;
+        if margin.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+
+        let notional = instrument.calculate_notional_value(self.quantity(), price, None);
+        Ok(notional.as_decimal() / margin.as_decimal())
+    }
+
+    /// Validates the order against the `instrument`, so it is not rejected by the venue
+    /// for a tick or lot size violation.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order is not for the `instrument`.
+    /// - If the quantity is not a multiple of the instrument size increment, see
+    ///   [`Quantity::round_to_size_increment`] to align it.
+    /// - If the price or trigger price is not a multiple of the instrument price increment.
+    /// - If a trailing stop limit order's limit price is unmarketable once the stop
+    ///   triggers, see [`TrailingStopLimitOrder::check_limit_price`].
+    pub fn validate(&self, instrument: &InstrumentAny) -> anyhow::Result<()> {
+        check_equal(
+            self.instrument_id(),
+            instrument.id(),
+            "instrument_id",
+            "instrument.id",
+        )?;
+        check_quantity_increment(self.quantity(), instrument.size_increment())?;
+        for price in [self.price(), self.trigger_price()].into_iter().flatten() {
+            check_price_increment(price, instrument.price_increment())?;
+        }
+        if let Self::TrailingStopLimit(order) = self {
+            order.check_limit_price()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the order's contribution to portfolio VaR inputs, as the notional value of
+    /// its leaves quantity at `price`.
+    ///
+    /// The notional is signed by order side (positive for a BUY, negative for a SELL),
+    /// except for reduce-only orders which can only offset an existing position, so
+    /// always contribute negative exposure.
+    #[must_use]
+    pub fn risk_exposure(&self, price: Price, instrument: &InstrumentAny) -> RiskExposure {
+        let notional = instrument.calculate_notional_value(self.leaves_qty(), price, None);
+        let amount = notional.as_decimal();
+        let signed_notional = if self.is_reduce_only() {
+            -amount
+        } else {
+            match self.order_side_specified() {
+                OrderSideSpecified::Buy => amount,
+                OrderSideSpecified::Sell => -amount,
+            }
+        };
+
+        RiskExposure {
+            signed_notional,
+            currency: notional.currency,
+        }
+    }
+
+    /// Returns the slippage budget (in basis points) remaining for the order, as the
+    /// `budget_bps` less the realized slippage of its average fill price versus the
+    /// `reference` (arrival) price.
+    ///
+    /// Slippage is positive when the fills are worse than the reference price for the
+    /// order side, so the result is negative once the order is over budget. Returns the
+    /// full budget if the order has no fills yet.
+    #[must_use]
+    pub fn slippage_budget_remaining(&self, budget_bps: Decimal, reference: Price) -> Decimal {
+        let avg_px = match self {
+            Self::Limit(order) => order.avg_px(),
+            Self::LimitIfTouched(order) => order.avg_px(),
+            Self::Market(order) => order.avg_px(),
+            Self::MarketIfTouched(order) => order.avg_px(),
+            Self::MarketToLimit(order) => order.avg_px(),
+            Self::StopLimit(order) => order.avg_px(),
+            Self::StopMarket(order) => order.avg_px(),
+            Self::TrailingStopLimit(order) => order.avg_px(),
+            Self::TrailingStopMarket(order) => order.avg_px(),
+        };
+        let Some(avg_px) = avg_px.and_then(Decimal::from_f64) else {
+            return budget_bps;
+        };
+        if reference.is_zero() {
+            return budget_bps;
+        }
+
+        let reference = reference.as_decimal();
+        let slippage = match self.order_side_specified() {
+            OrderSideSpecified::Buy => avg_px - reference,
+            OrderSideSpecified::Sell => reference - avg_px,
+        };
+
+        budget_bps - slippage / reference * Decimal::from(10_000)
+    }
+
+    /// Returns the status of each venue order the order has been worked as, reconstructed
+    /// from the order events.
+    ///
+    /// Events without a venue order ID are ignored. A venue order first seen on an update
+    /// is treated as accepted, and rejected modify or cancel requests restore the status
+    /// the venue order had before the request.
+    #[must_use]
+    pub fn venue_order_statuses(&self) -> HashMap<VenueOrderId, OrderStatus> {
+        let mut statuses: HashMap<VenueOrderId, OrderStatus> = HashMap::new();
+        let mut previous: HashMap<VenueOrderId, OrderStatus> = HashMap::new();
+
+        for event in self.events() {
+            let (venue_order_id, status) = match event {
+                OrderEventAny::Accepted(e) => (e.venue_order_id, OrderStatus::Accepted),
+                OrderEventAny::PartiallyFilled(e) => {
+                    (e.venue_order_id, OrderStatus::PartiallyFilled)
+                }
+                OrderEventAny::Filled(e) => (e.venue_order_id, OrderStatus::Filled),
+                OrderEventAny::Triggered(OrderTriggered {
+                    venue_order_id: Some(id),
+                    ..
+                }) => (*id, OrderStatus::Triggered),
+                OrderEventAny::Canceled(OrderCanceled {
+                    venue_order_id: Some(id),
+                    ..
+                }) => (*id, OrderStatus::Canceled),
+                OrderEventAny::Expired(OrderExpired {
+                    venue_order_id: Some(id),
+                    ..
+                }) => (*id, OrderStatus::Expired),
+                OrderEventAny::PendingUpdate(OrderPendingUpdate {
+                    venue_order_id: Some(id),
+                    ..
+                }) => (*id, OrderStatus::PendingUpdate),
+                OrderEventAny::PendingCancel(OrderPendingCancel {
+                    venue_order_id: Some(id),
+                    ..
+                }) => (*id, OrderStatus::PendingCancel),
+                OrderEventAny::Updated(OrderUpdated {
+                    venue_order_id: Some(id),
+                    ..
+                })
+                | OrderEventAny::ModifyRejected(OrderModifyRejected {
+                    venue_order_id: Some(id),
+                    ..
+                })
+                | OrderEventAny::CancelRejected(OrderCancelRejected {
+                    venue_order_id: Some(id),
+                    ..
+                }) => {
+                    let restored = match statuses.get(id) {
+                        Some(OrderStatus::PendingUpdate | OrderStatus::PendingCancel) => {
+                            previous.get(id).copied().unwrap_or(OrderStatus::Accepted)
+                        }
+                        Some(status) => *status,
+                        None => OrderStatus::Accepted,
+                    };
+                    (*id, restored)
+                }
+                _ => continue,
+            };
+
+            if let Some(current) = statuses.insert(venue_order_id, status) {
+                if !matches!(
+                    current,
+                    OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+                ) {
+                    previous.insert(venue_order_id, current);
+                }
+            }
+        }
+
+        statuses
+    }
+
+    /// Returns the limit price for an adaptive order which starts passive at `start` and
+    /// linearly becomes more aggressive, reaching `aggressive` once `duration_ns` has
+    /// elapsed since the order was initialized.
+    ///
+    /// The price is rounded to the nearest `instrument` price increment.
+    #[must_use]
+    pub fn adaptive_price(
+        &self,
+        start: Price,
+        aggressive: Price,
+        now: UnixNanos,
+        duration_ns: u64,
+        instrument: &InstrumentAny,
+    ) -> Price {
+        self.adaptive_price_with_curve(
+            start,
+            aggressive,
+            now,
+            duration_ns,
+            instrument,
+            |progress| progress,
+        )
+    }
+
+    /// Returns the limit price for an adaptive order as per [`Self::adaptive_price`], with
+    /// the schedule shaped by `curve`.
+    ///
+    /// The `curve` maps the elapsed fraction of the duration in [0, 1] to the fraction of
+    /// the distance from `start` to `aggressive`, and is clamped to [0, 1].
+    #[must_use]
+    pub fn adaptive_price_with_curve(
+        &self,
+        start: Price,
+        aggressive: Price,
+        now: UnixNanos,
+        duration_ns: u64,
+        instrument: &InstrumentAny,
+        curve: impl Fn(f64) -> f64,
+    ) -> Price {
+        let elapsed = now.as_u64().saturating_sub(self.ts_init().as_u64());
+        let progress = if duration_ns == 0 {
+            1.0
+        } else {
+            (elapsed as f64 / duration_ns as f64).min(1.0)
+        };
+        let fraction = curve(progress).clamp(0.0, 1.0);
+
+        let value = start.as_f64() + (aggressive.as_f64() - start.as_f64()) * fraction;
+        let increment = instrument.price_increment().as_f64();
+        instrument.make_price((value / increment).round() * increment)
+    }
+
+    /// Returns a heuristic estimate of the probability the passive order is fully filled
+    /// within `horizon_ns`.
+    ///
+    /// Market orders are modeled as a Poisson process arriving at `arrival_rate` units of
+    /// quantity per second, which must consume the `queue_ahead` of the order and then its
+    /// leaves quantity (rounded up to whole units). Cancellations ahead in the queue are
+    /// ignored, so the estimate is conservative.
+    #[must_use]
+    pub fn fill_probability(
+        &self,
+        queue_ahead: Quantity,
+        arrival_rate: Decimal,
+        horizon_ns: u64,
+    ) -> f64 {
+        let required = (queue_ahead.as_f64() + self.leaves_qty().as_f64()).ceil();
+        if required <= 0.0 {
+            return 1.0;
+        }
+
+        let rate = arrival_rate.to_f64().unwrap_or(0.0).max(0.0);
+        let expected = rate * horizon_ns as f64 / 1_000_000_000.0;
+        if expected == 0.0 {
+            return 0.0;
+        }
+
+        // P(N >= required) = 1 - P(N < required), summing the Poisson terms in log space
+        // so a large expected arrival count does not underflow
+        let ln_expected = expected.ln();
+        let mut ln_term = -expected;
+        let mut below = 0.0;
+        for i in 0..required as u64 {
+            if i > 0 {
+                ln_term += ln_expected - (i as f64).ln();
+            }
+            below += ln_term.exp();
+        }
+
+        (1.0 - below).clamp(0.0, 1.0)
+    }
+
+    /// Returns the time-weighted average of the mid price `observations` over the interval
+    /// the order was open, e.g. as a TWAP execution benchmark.
+    ///
+    /// Each observation holds until the next, and the interval runs from the order
+    /// `ts_init` until `ts_last` once the order is closed, otherwise until the latest
+    /// observation. Observations may be given in any order. If the interval is empty
+    /// the mid in effect at its end is returned, and `None` if there are no observations.
+    #[must_use]
+    pub fn benchmark_twap(&self, observations: &[(UnixNanos, Price)]) -> Option<Price> {
+        if observations.is_empty() {
+            return None;
+        }
+
+        let mut observations = observations.to_vec();
+        observations.sort_by_key(|(ts, _)| *ts);
+
+        let start = self.ts_init().as_u64();
+        let end = if self.is_closed() {
+            self.ts_last().as_u64()
+        } else {
+            observations[observations.len() - 1].0.as_u64()
+        };
+
+        let mut weighted = 0.0;
+        let mut total = 0.0;
+        for (i, (ts, mid)) in observations.iter().enumerate() {
+            let next = observations.get(i + 1).map_or(end, |(ts, _)| ts.as_u64());
+            let from = ts.as_u64().max(start);
+            let to = next.min(end);
+            if to > from {
+                let duration = (to - from) as f64;
+                weighted += mid.as_f64() * duration;
+                total += duration;
+            }
+        }
+
+        let precision = observations[0].1.precision;
+        if total == 0.0 {
+            let (_, mid) = observations
+                .iter()
+                .rev()
+                .find(|(ts, _)| ts.as_u64() <= end)
+                .unwrap_or(&observations[0]);
+            return Some(*mid);
+        }
+
+        Some(Price::new(weighted / total, precision))
+    }
+
+    /// Returns the spread captured by the passive (maker) fills of the order relative to
+    /// the `mid_at_fill` price, in the instrument quote currency.
+    ///
+    /// Each maker fill contributes its distance from the mid in the favorable direction
+    /// for the order side (below the mid for a BUY, above for a SELL) times its quantity
+    /// and the instrument multiplier. Fills through the mid contribute negatively and
+    /// taker fills are ignored.
+    #[must_use]
+    pub fn spread_capture(&self, mid_at_fill: Price, instrument: &InstrumentAny) -> Money {
+        let mid = mid_at_fill.as_decimal();
+        let multiplier = instrument.multiplier().as_decimal();
+        let side = self.order_side_specified();
+
+        let capture: Decimal = self
+            .events()
+            .into_iter()
+            .filter_map(|event| match event {
+                OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill)
+                    if fill.liquidity_side == LiquiditySide::Maker =>
+                {
+                    Some(fill)
+                }
+                _ => None,
+            })
+            .map(|fill| {
+                let distance = match side {
+                    OrderSideSpecified::Buy => mid - fill.last_px.as_decimal(),
+                    OrderSideSpecified::Sell => fill.last_px.as_decimal() - mid,
+                };
+                distance * fill.last_qty.as_decimal() * multiplier
+            })
+            .sum();
+
+        Money::new(capture.to_f64().unwrap(), instrument.quote_currency())
+    }
+
+    /// Returns the intervals in nanoseconds between the `ts_event` of consecutive fills
+    /// of the order, e.g. to detect executions which stalled.
+    ///
+    /// Returns an empty vector if the order has fewer than two fills.
+    #[must_use]
+    pub fn fill_gaps(&self) -> Vec<u64> {
+        let fill_times: Vec<UnixNanos> = self
+            .events()
+            .into_iter()
+            .filter_map(|event| match event {
+                OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                    Some(fill.ts_event)
+                }
+                _ => None,
+            })
+            .collect();
+
+        fill_times
+            .windows(2)
+            .map(|pair| pair[1].as_u64().saturating_sub(pair[0].as_u64()))
+            .collect()
+    }
+
+    /// Returns a Mermaid state diagram of the status transitions the order went through,
+    /// replayed from its event history.
+    ///
+    /// Each edge is labeled with the event type and its ISO 8601 `ts_event`. Events which
+    /// don't change the status (e.g. partial fills or updates) appear as self-transitions.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let events = self.events();
+        let mut lines = vec!["stateDiagram-v2".to_string()];
+
+        let mut replay = match events.first() {
+            Some(OrderEventAny::Initialized(init)) => Self::from(init.clone()),
+            _ => return lines.join("\n"),
+        };
+        let mut prev = replay.status();
+        lines.push(format!(
+            "    [*] --> {prev} : Initialized @ {}",
+            unix_nanos_to_iso8601(events[0].ts_event())
+        ));
+
+        for event in events.into_iter().skip(1) {
+            if replay.apply(event.clone()).is_err() {
+                break;
+            }
+            let next = replay.status();
+            lines.push(format!(
+                "    {prev} --> {next} : {:?} @ {}",
+                event.event_type(),
+                unix_nanos_to_iso8601(event.ts_event())
+            ));
+            prev = next;
+        }
+
+        lines.join("\n")
+    }
+
+    /// Returns a concise one-line summary of the order for logging, e.g.
+    /// `BUY 1.500000 BTCUSDT LIMIT @ 50000.00 [ACCEPTED 0.500000/1.500000]`.
+    ///
+    /// Trailing orders also include their trailing offset and offset type.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} {} {} {}",
+            self.order_side(),
+            self.quantity(),
+            self.instrument_id().symbol,
+            self.order_type(),
+        );
+        if let Some(price) = self.price() {
+            summary.push_str(&format!(" @ {price}"));
+        }
+        if let Some(trigger_price) = self.trigger_price() {
+            summary.push_str(&format!(" trigger {trigger_price}"));
+        }
+        match self {
+            Self::TrailingStopLimit(order) => summary.push_str(&format!(
+                " offset {} {} limit_offset {}",
+                order.trailing_offset, order.trailing_offset_type, order.limit_offset,
+            )),
+            Self::TrailingStopMarket(order) => summary.push_str(&format!(
+                " offset {} {}",
+                order.trailing_offset, order.trailing_offset_type,
+            )),
+            _ => {}
+        }
+        summary.push_str(&format!(
+            " [{} {}/{}]",
+            self.status(),
+            self.filled_qty(),
+            self.quantity(),
+        ));
+        summary
+    }
+}
+
+/// Returns the signed net position quantity (buys minus sells) implied by the fills of `orders`.
+///
+/// The result is signed in the same way as [`Position::signed_qty`](crate::position::Position),
+/// and is exact so it can be compared against the venue position without rounding error.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `orders` are not all for the same instrument.
+pub fn net_position_qty(orders: &[&OrderAny]) -> anyhow::Result<Decimal> {
+    let Some(first) = orders.first() else {
+        return Ok(Decimal::ZERO);
+    };
+    let instrument_id = first.instrument_id();
+
+    let mut net = Decimal::ZERO;
+    for order in orders {
+        check_equal(
+            order.instrument_id(),
+            instrument_id,
+            "order.instrument_id",
+            "instrument_id",
+        )?;
+        match order.order_side() {
+            OrderSide::Buy => net += order.filled_qty().as_decimal(),
+            OrderSide::Sell => net -= order.filled_qty().as_decimal(),
+            OrderSide::NoOrderSide => {}
+        }
+    }
+    Ok(net)
+}
+
+/// Reconciles the `local` orders against the `venue` orders, matched on client order ID.
+///
+/// Reports the orders missing on either side, and the matched orders whose status or
+/// filled quantity diverge, each in the order given.
+#[must_use]
+pub fn reconcile_orders(local: &[OrderAny], venue: &[OrderAny]) -> ReconcileReport {
+    let venue_by_id: HashMap<ClientOrderId, &OrderAny> = venue
+        .iter()
+        .map(|order| (order.client_order_id(), order))
+        .collect();
+    let local_by_id: HashMap<ClientOrderId, &OrderAny> = local
+        .iter()
+        .map(|order| (order.client_order_id(), order))
+        .collect();
+
+    let mut report = ReconcileReport::default();
+    for order in local {
+        let client_order_id = order.client_order_id();
+        let Some(venue_order) = venue_by_id.get(&client_order_id) else {
+            report.missing_at_venue.push(client_order_id);
+            continue;
+        };
+
+        if order.status() != venue_order.status() || order.filled_qty() != venue_order.filled_qty()
+        {
+            report.divergent.push(OrderDivergence {
+                client_order_id,
+                local_status: order.status(),
+                venue_status: venue_order.status(),
+                local_filled_qty: order.filled_qty(),
+                venue_filled_qty: venue_order.filled_qty(),
+            });
+        }
+    }
+
+    report.missing_locally = venue
+        .iter()
+        .map(OrderAny::client_order_id)
+        .filter(|client_order_id| !local_by_id.contains_key(client_order_id))
+        .collect();
+
+    report
 }
 
 impl PartialEq for OrderAny {
@@ -921,3 +1836,1403 @@ impl PartialEq for StopOrderAny {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::nanos::UnixNanos;
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    use super::{
+        net_position_qty, reconcile_orders, BracketLevels, OrderAny, OrderDivergence, PriceStrings,
+        ReconcileReport, RiskExposure,
+    };
+    use crate::{
+        accounts::{margin::MarginAccount, stubs::*},
+        data::order::BookOrder,
+        enums::{
+            BookType, CancellationReason, ContingencyType, LiquiditySide, OrderSide, OrderStatus,
+            OrderType, PositionSide, TrailingOffsetType,
+        },
+        events::{OrderCanceled, OrderEventAny, OrderFilled, OrderUpdated},
+        identifiers::{ClientOrderId, TradeId, VenueOrderId},
+        instruments::{
+            stubs::*, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity, InstrumentAny,
+        },
+        orderbook::OrderBook,
+        orders::{
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+            OrderError, OrderTestBuilder,
+        },
+        stubs::{stub_order_filled, stub_position},
+        types::{Money, Price, Quantity},
+    };
+
+    fn book_with_asks(equity: &Equity) -> OrderBook {
+        let mut book = OrderBook::new(equity.id, BookType::L2_MBP);
+        let asks = [("100.00", 50), ("100.01", 50), ("100.05", 100)];
+        for (i, (price, size)) in asks.into_iter().enumerate() {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(size),
+                0, // order_id not applicable
+            );
+            book.add(order, 0, i as u64, (i as u64).into());
+        }
+        book
+    }
+
+    #[rstest]
+    #[case(100, 1, true)]
+    #[case(101, 1, false)]
+    #[case(150, 4, false)]
+    #[case(150, 5, true)]
+    #[case(201, 10, false)]
+    fn test_would_fill_within_market_order(
+        equity_aapl: Equity,
+        #[case] quantity: i64,
+        #[case] max_slippage_ticks: u32,
+        #[case] expected: bool,
+    ) {
+        let book = book_with_asks(&equity_aapl);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(quantity))
+            .build();
+
+        let result = order.would_fill_within(
+            &book,
+            max_slippage_ticks,
+            &InstrumentAny::Equity(equity_aapl),
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_would_fill_within_bounded_by_limit_price(equity_aapl: Equity) {
+        let book = book_with_asks(&equity_aapl);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("100.01"))
+            .quantity(Quantity::from(150))
+            .build();
+
+        assert!(!order.would_fill_within(&book, 5, &InstrumentAny::Equity(equity_aapl)));
+    }
+
+    #[rstest]
+    fn test_would_fill_within_empty_book(equity_aapl: Equity) {
+        let book = OrderBook::new(equity_aapl.id, BookType::L2_MBP);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(1))
+            .build();
+
+        assert!(!order.would_fill_within(&book, 10, &InstrumentAny::Equity(equity_aapl)));
+    }
+
+    #[rstest]
+    fn test_net_position_qty_mixed_sides(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let make_filled = |side: OrderSide, quantity: i64| {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(audusd_sim.id)
+                .side(side)
+                .quantity(Quantity::from(quantity))
+                .build();
+            TestOrderStubs::make_filled_order(&order, &instrument, LiquiditySide::Taker)
+        };
+        let buy1 = make_filled(OrderSide::Buy, 100_000);
+        let buy2 = make_filled(OrderSide::Buy, 50_000);
+        let sell = make_filled(OrderSide::Sell, 200_000);
+        let unfilled = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(10_000))
+            .build();
+
+        assert_eq!(
+            net_position_qty(&[&buy1, &buy2]).unwrap(),
+            Decimal::from(150_000)
+        );
+        assert_eq!(
+            net_position_qty(&[&buy1, &buy2, &sell, &unfilled]).unwrap(),
+            Decimal::from(-50_000)
+        );
+        assert_eq!(net_position_qty(&[]).unwrap(), Decimal::ZERO);
+    }
+
+    #[rstest]
+    fn test_net_position_qty_fractional_is_exact(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let make_filled = |side: OrderSide, quantity: &str| {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(currency_pair_btcusdt.id)
+                .side(side)
+                .quantity(Quantity::from(quantity))
+                .build();
+            TestOrderStubs::make_filled_order(&order, &instrument, LiquiditySide::Taker)
+        };
+        let buy1 = make_filled(OrderSide::Buy, "0.100000");
+        let buy2 = make_filled(OrderSide::Buy, "0.200000");
+        let sell = make_filled(OrderSide::Sell, "0.300000");
+
+        assert_eq!(
+            net_position_qty(&[&buy1, &buy2, &sell]).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[rstest]
+    fn test_net_position_qty_mixed_instruments(audusd_sim: CurrencyPair, equity_aapl: Equity) {
+        let order1 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(1))
+            .build();
+        let order2 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(1))
+            .build();
+
+        let result = net_position_qty(&[&order1, &order2]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "'order.instrument_id' value of AAPL.XNAS was not equal to 'instrument_id' value of AUD/USD.SIM"
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_orders(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let make_order = |client_order_id: &str| {
+            OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(audusd_sim.id)
+                .client_order_id(ClientOrderId::new(client_order_id))
+                .side(OrderSide::Buy)
+                .price(Price::from("0.80000"))
+                .quantity(Quantity::from(100_000))
+                .build()
+        };
+        let make_partially_filled = |order: &OrderAny| {
+            let mut order = TestOrderStubs::make_accepted_order(order);
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                None,
+                None,
+                None,
+                Some(Quantity::from(40_000)),
+                None,
+                None,
+                None,
+                None,
+            );
+            order.apply(fill).unwrap();
+            order
+        };
+
+        // O-1 matches, O-2 is partially filled at the venue, O-3 is accepted at the venue,
+        // O-4 is missing at the venue and O-5 is missing locally
+        let o1 = TestOrderStubs::make_accepted_order(&make_order("O-1"));
+        let o2 = TestOrderStubs::make_accepted_order(&make_order("O-2"));
+        let o3 = make_order("O-3");
+        let o4 = TestOrderStubs::make_accepted_order(&make_order("O-4"));
+        let o5 = TestOrderStubs::make_accepted_order(&make_order("O-5"));
+        let local = [o1.clone(), o2.clone(), o3.clone(), o4];
+        let venue = [
+            o5,
+            TestOrderStubs::make_accepted_order(&o3),
+            make_partially_filled(&make_order("O-2")),
+            o1,
+        ];
+
+        let report = reconcile_orders(&local, &venue);
+
+        assert!(!report.is_reconciled());
+        assert_eq!(report.missing_at_venue, vec![ClientOrderId::new("O-4")]);
+        assert_eq!(report.missing_locally, vec![ClientOrderId::new("O-5")]);
+        assert_eq!(
+            report.divergent,
+            vec![
+                OrderDivergence {
+                    client_order_id: ClientOrderId::new("O-2"),
+                    local_status: OrderStatus::Accepted,
+                    venue_status: OrderStatus::PartiallyFilled,
+                    local_filled_qty: Quantity::from(0),
+                    venue_filled_qty: Quantity::from(40_000),
+                },
+                OrderDivergence {
+                    client_order_id: ClientOrderId::new("O-3"),
+                    local_status: OrderStatus::Initialized,
+                    venue_status: OrderStatus::Accepted,
+                    local_filled_qty: Quantity::from(0),
+                    venue_filled_qty: Quantity::from(0),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_orders_when_consistent(audusd_sim: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let orders = [TestOrderStubs::make_accepted_order(&order)];
+
+        let report = reconcile_orders(&orders, &orders);
+
+        assert!(report.is_reconciled());
+        assert_eq!(report, ReconcileReport::default());
+        assert!(reconcile_orders(&[], &[]).is_reconciled());
+    }
+
+    #[rstest]
+    fn test_price_strings_pads_to_two_decimals(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("100"))
+            .trigger_price(Price::from("99.5"))
+            .quantity(Quantity::from(10))
+            .build();
+
+        let result = order.price_strings(&InstrumentAny::Equity(equity_aapl));
+
+        assert_eq!(
+            result,
+            PriceStrings {
+                price: Some("100.00".to_string()),
+                trigger_price: Some("99.50".to_string()),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_price_strings_pads_to_eight_decimals(
+        #[with(8, 6, Price::from("0.00000001"))] crypto_future_btcusdt: CryptoFuture,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(crypto_future_btcusdt.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("0.05"))
+            .quantity(Quantity::from("1.000000"))
+            .build();
+
+        let result = order.price_strings(&InstrumentAny::CryptoFuture(crypto_future_btcusdt));
+
+        assert_eq!(result.price, Some("0.05000000".to_string()));
+        assert_eq!(result.trigger_price, None);
+    }
+
+    #[rstest]
+    fn test_price_strings_market_order_has_no_prices(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(10))
+            .build();
+
+        let result = order.price_strings(&InstrumentAny::Equity(equity_aapl));
+
+        assert_eq!(result.price, None);
+        assert_eq!(result.trigger_price, None);
+    }
+
+    #[rstest]
+    #[case(CancellationReason::User)]
+    #[case(CancellationReason::Expired)]
+    #[case(CancellationReason::OcoTriggered)]
+    #[case(CancellationReason::RiskRejected)]
+    #[case(CancellationReason::Venue)]
+    fn test_cancellation_reason_recorded(
+        #[case] reason: CancellationReason,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        assert_eq!(order.cancellation_reason(), None);
+
+        let canceled = OrderCanceled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: order.venue_order_id(),
+            account_id: order.account_id(),
+            cancellation_reason: Some(reason),
+            ..Default::default()
+        };
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Canceled);
+        assert_eq!(order.cancellation_reason(), Some(reason));
+    }
+
+    #[rstest]
+    fn test_cancellation_reason_none_when_not_provided(audusd_sim: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+
+        let canceled = OrderCanceled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            ..Default::default()
+        };
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Canceled);
+        assert_eq!(order.cancellation_reason(), None);
+    }
+
+    #[rstest]
+    #[case(1.0, "33.288948")]
+    #[case(10.0, "332.889481")]
+    #[case(50.0, "1664.585934")]
+    fn test_effective_leverage(
+        #[case] leverage: f64,
+        #[case] expected: &str,
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.set_leverage(audusd_sim.id, leverage);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = order
+            .effective_leverage(
+                &margin_account,
+                &InstrumentAny::CurrencyPair(audusd_sim),
+                Price::from("0.80000"),
+            )
+            .unwrap();
+
+        assert_eq!(result.round_dp(6), Decimal::from_str(expected).unwrap());
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-10.0)]
+    #[case(f64::NAN)]
+    fn test_effective_leverage_with_invalid_leverage(
+        #[case] leverage: f64,
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.set_leverage(audusd_sim.id, leverage);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = order.effective_leverage(
+            &margin_account,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            Price::from("0.80000"),
+        );
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid leverage for AUD/USD.SIM"));
+    }
+
+    #[rstest]
+    fn test_effective_leverage_with_zero_margin(
+        margin_account: MarginAccount,
+        mut audusd_sim: CurrencyPair,
+    ) {
+        audusd_sim.margin_init = Decimal::ZERO;
+        audusd_sim.taker_fee = Decimal::ZERO;
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = order
+            .effective_leverage(
+                &margin_account,
+                &InstrumentAny::CurrencyPair(audusd_sim),
+                Price::from("0.80000"),
+            )
+            .unwrap();
+
+        assert_eq!(result, Decimal::ZERO);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "80000.00")]
+    #[case(OrderSide::Sell, "-80000.00")]
+    fn test_risk_exposure(
+        audusd_sim: CurrencyPair,
+        #[case] side: OrderSide,
+        #[case] expected: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(side)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = order.risk_exposure(
+            Price::from("0.80000"),
+            &InstrumentAny::CurrencyPair(audusd_sim),
+        );
+
+        assert_eq!(
+            result,
+            RiskExposure {
+                signed_notional: Decimal::from_str(expected).unwrap(),
+                currency: audusd_sim.quote_currency,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy)]
+    #[case(OrderSide::Sell)]
+    fn test_risk_exposure_reduce_only(audusd_sim: CurrencyPair, #[case] side: OrderSide) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .reduce_only(true)
+            .build();
+
+        let result = order.risk_exposure(
+            Price::from("0.80000"),
+            &InstrumentAny::CurrencyPair(audusd_sim),
+        );
+
+        assert_eq!(
+            result.signed_notional,
+            Decimal::from_str("-80000.00").unwrap()
+        );
+        assert_eq!(result.currency, audusd_sim.quote_currency);
+    }
+
+    #[rstest]
+    fn test_risk_exposure_excludes_filled_qty(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            Some(Quantity::from(4)),
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(fill).unwrap();
+
+        let result = order.risk_exposure(Price::from("100.00"), &instrument);
+
+        assert_eq!(result.signed_notional, Decimal::from_str("600.00").unwrap());
+    }
+
+    #[rstest]
+    fn test_summary_trailing_stop_limit(currency_pair_btcusdt: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(currency_pair_btcusdt.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("50000.00"))
+            .trigger_price(Price::from("49500.00"))
+            .limit_offset(Price::from("50.00"))
+            .trailing_offset(Price::from("100.00"))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .quantity(Quantity::from("1.500000"))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::CurrencyPair(currency_pair_btcusdt),
+            None,
+            None,
+            Some(Price::from("50000.00")),
+            Some(Quantity::from("0.500000")),
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(fill).unwrap();
+
+        assert_eq!(
+            order.summary(),
+            "BUY 1.500000 BTCUSDT TRAILING_STOP_LIMIT @ 50000.00 trigger 49500.00 \
+            offset 100.00 PRICE limit_offset 50.00 [PARTIALLY_FILLED 0.500000/1.500000]"
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.05", "5")]
+    #[case(OrderSide::Buy, "99.90", "20")]
+    #[case(OrderSide::Sell, "99.95", "5")]
+    #[case(OrderSide::Buy, "100.20", "-10")]
+    #[case(OrderSide::Sell, "99.75", "-15")]
+    fn test_slippage_budget_remaining(
+        #[case] side: OrderSide,
+        #[case] fill_px: &str,
+        #[case] expected: &str,
+        equity_aapl: Equity,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(side)
+            .quantity(Quantity::from(100))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::Equity(equity_aapl),
+            None,
+            None,
+            Some(Price::from(fill_px)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(fill).unwrap();
+
+        let result = order.slippage_budget_remaining(Decimal::from(10), Price::from("100.00"));
+
+        assert_eq!(result, Decimal::from_str(expected).unwrap());
+    }
+
+    #[rstest]
+    fn test_slippage_budget_remaining_without_fills(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100))
+            .build();
+
+        let result = order.slippage_budget_remaining(Decimal::from(10), Price::from("100.00"));
+
+        assert_eq!(result, Decimal::from(10));
+    }
+
+    #[rstest]
+    fn test_fill_gaps(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Sell)
+            .price(Price::from("50000.00"))
+            .quantity(Quantity::from("1.000000"))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        assert!(order.fill_gaps().is_empty());
+
+        let fills = [
+            ("T-1", "0.250000", 1_000_000_000),
+            ("T-2", "0.250000", 1_500_000_000),
+            ("T-3", "0.250000", 31_500_000_000),
+            ("T-4", "0.250000", 31_500_000_250),
+        ];
+        for (i, (trade_id, qty, ts_filled)) in fills.into_iter().enumerate() {
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(trade_id)),
+                None,
+                Some(Price::from("50000.00")),
+                Some(Quantity::from(qty)),
+                None,
+                None,
+                Some(UnixNanos::from(ts_filled)),
+                None,
+            );
+            order.apply(fill).unwrap();
+            if i == 0 {
+                assert!(order.fill_gaps().is_empty());
+            }
+            // Gaps between the partial fills
+            if i == 2 {
+                assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+                assert_eq!(order.fill_gaps(), vec![500_000_000, 30_000_000_000]);
+            }
+        }
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.fill_gaps(), vec![500_000_000, 30_000_000_000, 250]);
+    }
+
+    #[rstest]
+    fn test_to_mermaid(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("50000.00"))
+            .quantity(Quantity::from("1.000000"))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        for (trade_id, ts_filled) in [("T-1", 1_000_000_000), ("T-2", 2_500_000_000)] {
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(trade_id)),
+                None,
+                Some(Price::from("50000.00")),
+                Some(Quantity::from("0.500000")),
+                None,
+                None,
+                Some(UnixNanos::from(ts_filled)),
+                None,
+            );
+            order.apply(fill).unwrap();
+        }
+
+        let expected = [
+            "stateDiagram-v2",
+            "    [*] --> INITIALIZED : Initialized @ 1970-01-01T00:00:00.000000000Z",
+            "    INITIALIZED --> SUBMITTED : Submitted @ 1970-01-01T00:00:00.000000000Z",
+            "    SUBMITTED --> ACCEPTED : Accepted @ 1970-01-01T00:00:00.000000000Z",
+            "    ACCEPTED --> PARTIALLY_FILLED : PartiallyFilled @ 1970-01-01T00:00:01.000000000Z",
+            "    PARTIALLY_FILLED --> FILLED : Filled @ 1970-01-01T00:00:02.500000000Z",
+        ];
+        assert_eq!(order.to_mermaid(), expected.join("\n"));
+    }
+
+    #[rstest]
+    fn test_summary_limit(currency_pair_btcusdt: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(currency_pair_btcusdt.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("50000.00"))
+            .quantity(Quantity::from("1.500000"))
+            .build();
+
+        assert_eq!(
+            order.summary(),
+            "SELL 1.500000 BTCUSDT LIMIT @ 50000.00 [INITIALIZED 0.000000/1.500000]"
+        );
+    }
+
+    #[rstest]
+    fn test_bracket_levels_full_bracket(audusd_sim: CurrencyPair) {
+        let entry_id = ClientOrderId::new("O-ENTRY");
+        let tp_id = ClientOrderId::new("O-TP");
+        let sl_id = ClientOrderId::new("O-SL");
+        let entry = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(entry_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .contingency_type(ContingencyType::Oto)
+            .linked_order_ids(vec![tp_id, sl_id])
+            .build();
+        let take_profit = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(tp_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1.10000"))
+            .quantity(Quantity::from(100_000))
+            .parent_order_id(entry_id)
+            .build();
+        let stop_loss = OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(sl_id)
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("0.95000"))
+            .quantity(Quantity::from(100_000))
+            .parent_order_id(entry_id)
+            .build();
+        let unrelated = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(ClientOrderId::new("O-OTHER"))
+            .side(OrderSide::Sell)
+            .price(Price::from("1.20000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let levels = entry.bracket_levels(&[&entry, &unrelated, &take_profit, &stop_loss]);
+
+        assert_eq!(
+            levels,
+            BracketLevels {
+                take_profit: Some(Price::from("1.10000")),
+                stop_loss: Some(Price::from("0.95000")),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_bracket_levels_partial_bracket(audusd_sim: CurrencyPair) {
+        let entry_id = ClientOrderId::new("O-ENTRY");
+        let entry = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(entry_id)
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let stop_loss = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(ClientOrderId::new("O-SL"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.05100"))
+            .trigger_price(Price::from("1.05000"))
+            .quantity(Quantity::from(100_000))
+            .parent_order_id(entry_id)
+            .build();
+
+        let levels = entry.bracket_levels(&[&stop_loss]);
+
+        assert_eq!(levels.take_profit, None);
+        assert_eq!(levels.stop_loss, Some(Price::from("1.05000")));
+        assert_eq!(entry.bracket_levels(&[]), BracketLevels::default());
+    }
+
+    #[rstest]
+    #[case::half("100000", Decimal::from(50), "50000")]
+    #[case::half_rounded_down("100001", Decimal::from(50), "50000")]
+    #[case::full("100001", Decimal::ONE_HUNDRED, "100001")]
+    fn test_from_position_pct(
+        audusd_sim: CurrencyPair,
+        #[case] position_qty: &str,
+        #[case] pct: Decimal,
+        #[case] expected_qty: &str,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let position = stub_position(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from(position_qty),
+            Price::from("1.00000"),
+        );
+
+        let order = OrderAny::from_position_pct(
+            &position,
+            pct,
+            ClientOrderId::from("O-CLOSE"),
+            &instrument,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::Market);
+        assert_eq!(order.order_side(), OrderSide::Sell);
+        assert_eq!(order.quantity(), Quantity::from(expected_qty));
+        assert_eq!(order.instrument_id(), position.instrument_id);
+        assert_eq!(order.client_order_id(), ClientOrderId::from("O-CLOSE"));
+        assert!(order.is_reduce_only());
+    }
+
+    #[rstest]
+    #[case::zero(Decimal::ZERO)]
+    #[case::negative(Decimal::from(-10))]
+    #[case::over_full(Decimal::from(101))]
+    #[case::rounds_to_zero(Decimal::from_str("0.0001").unwrap())]
+    fn test_from_position_pct_invalid(audusd_sim: CurrencyPair, #[case] pct: Decimal) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let position = stub_position(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("100001"),
+            Price::from("1.00000"),
+        );
+
+        let result = OrderAny::from_position_pct(
+            &position,
+            pct,
+            ClientOrderId::from("O-CLOSE"),
+            &instrument,
+            UnixNanos::default(),
+        );
+
+        assert!(matches!(result, Err(OrderError::InvalidPositionPercentage(p)) if p == pct));
+    }
+
+    #[rstest]
+    #[case::buy(OrderSide::Buy, "99.95", "99.90", "1.00")]
+    #[case::sell(OrderSide::Sell, "100.05", "100.10", "1.00")]
+    #[case::buy_through_mid(OrderSide::Buy, "100.02", "99.98", "-0.10")]
+    fn test_spread_capture(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] first_px: &str,
+        #[case] second_px: &str,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(side)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(30))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+
+        let fills = [
+            ("T-1", first_px, 10, LiquiditySide::Maker),
+            ("T-2", second_px, 5, LiquiditySide::Maker),
+            ("T-3", "105.00", 15, LiquiditySide::Taker),
+        ];
+        for (trade_id, px, qty, liquidity_side) in fills {
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(trade_id)),
+                None,
+                Some(Price::from(px)),
+                Some(Quantity::from(qty)),
+                Some(liquidity_side),
+                None,
+                None,
+                None,
+            );
+            order.apply(fill).unwrap();
+        }
+
+        assert_eq!(
+            order.spread_capture(Price::from("100.00"), &instrument),
+            Money::from(format!("{expected} USD").as_str())
+        );
+    }
+
+    #[rstest]
+    fn test_spread_capture_no_fills(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .build();
+
+        assert_eq!(
+            order.spread_capture(Price::from("100.00"), &instrument),
+            Money::from("0 USD")
+        );
+    }
+
+    #[rstest]
+    #[case::start(1_000, "100.00")]
+    #[case::midpoint(1_500, "100.50")]
+    #[case::end(2_000, "101.00")]
+    #[case::after_end(5_000, "101.00")]
+    #[case::before_init(0, "100.00")]
+    #[case::rounded_to_tick(1_333, "100.35")]
+    #[case::rounded_down_to_tick(1_320, "100.30")]
+    fn test_adaptive_price(
+        ethusdt_bitmex: CryptoPerpetual,
+        #[case] now: u64,
+        #[case] expected: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(ethusdt_bitmex.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .ts_init(UnixNanos::from(1_000))
+            .build();
+
+        let price = order.adaptive_price(
+            Price::from("100.00"),
+            Price::from("101.00"),
+            UnixNanos::from(now),
+            1_000,
+            &InstrumentAny::CryptoPerpetual(ethusdt_bitmex),
+        );
+
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case::start(1_000, "100.00")]
+    #[case::midpoint(1_500, "99.75")]
+    #[case::end(2_000, "99.00")]
+    fn test_adaptive_price_with_curve(
+        equity_aapl: Equity,
+        #[case] now: u64,
+        #[case] expected: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .ts_init(UnixNanos::from(1_000))
+            .build();
+
+        let price = order.adaptive_price_with_curve(
+            Price::from("100.00"),
+            Price::from("99.00"),
+            UnixNanos::from(now),
+            1_000,
+            &InstrumentAny::Equity(equity_aapl),
+            |progress| progress * progress,
+        );
+
+        assert_eq!(price, Price::from(expected));
+    }
+
+    fn split_order(audusd_sim: CurrencyPair) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+
+        let fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            Some(TradeId::new("E-001")),
+            None,
+            Some(Price::from("1.00000")),
+            Some(Quantity::from(40_000)),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        order.apply(OrderEventAny::PartiallyFilled(fill)).unwrap();
+
+        let updated = OrderUpdated {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            quantity: order.quantity(),
+            venue_order_id: Some(VenueOrderId::from("V-002")),
+            account_id: order.account_id(),
+            ..Default::default()
+        };
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_venue_order_statuses_initialized_order_is_empty(audusd_sim: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        assert!(order.venue_order_statuses().is_empty());
+    }
+
+    #[rstest]
+    fn test_venue_order_statuses_split_execution(audusd_sim: CurrencyPair) {
+        let order = split_order(audusd_sim);
+
+        let statuses = order.venue_order_statuses();
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(
+            statuses[&VenueOrderId::from("V-001")],
+            OrderStatus::PartiallyFilled
+        );
+        assert_eq!(
+            statuses[&VenueOrderId::from("V-002")],
+            OrderStatus::Accepted
+        );
+    }
+
+    #[rstest]
+    fn test_venue_order_statuses_after_cancel_and_fill(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut order = split_order(audusd_sim);
+
+        let canceled = OrderCanceled {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: Some(VenueOrderId::from("V-002")),
+            account_id: order.account_id(),
+            ..Default::default()
+        };
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+        // A late fill for the original venue order
+        let mut fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            Some(TradeId::new("E-002")),
+            None,
+            Some(Price::from("1.00000")),
+            Some(Quantity::from(60_000)),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        fill.venue_order_id = VenueOrderId::from("V-001");
+        order.apply(OrderEventAny::Filled(fill)).unwrap();
+
+        let statuses = order.venue_order_statuses();
+
+        assert_eq!(statuses[&VenueOrderId::from("V-001")], OrderStatus::Filled);
+        assert_eq!(
+            statuses[&VenueOrderId::from("V-002")],
+            OrderStatus::Canceled
+        );
+    }
+
+    #[rstest]
+    fn test_benchmark_twap_open_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AAPL.XNAS".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .ts_init(UnixNanos::from(1_000))
+            .build();
+        let observations = [
+            (UnixNanos::from(2_500), Price::from("102.00")),
+            (UnixNanos::from(500), Price::from("99.00")),
+            (UnixNanos::from(3_000), Price::from("103.00")),
+            (UnixNanos::from(1_500), Price::from("101.00")),
+        ];
+
+        // 99.00 for 500ns, 101.00 for 1000ns, 102.00 for 500ns
+        assert_eq!(
+            order.benchmark_twap(&observations),
+            Some(Price::from("100.75"))
+        );
+    }
+
+    #[rstest]
+    fn test_benchmark_twap_closed_order_excludes_later_observations(audusd_sim: CurrencyPair) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(UnixNanos::from(2_000)),
+            None,
+        );
+        order.apply(fill).unwrap();
+        let observations = [
+            (UnixNanos::from(0), Price::from("1.00000")),
+            (UnixNanos::from(1_000), Price::from("1.00020")),
+            (UnixNanos::from(3_000), Price::from("1.00100")),
+        ];
+
+        assert!(order.is_closed());
+        assert_eq!(
+            order.benchmark_twap(&observations),
+            Some(Price::from("1.00010"))
+        );
+    }
+
+    #[rstest]
+    fn test_benchmark_twap_empty_interval_returns_latest_mid() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AAPL.XNAS".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(10))
+            .ts_init(UnixNanos::from(1_000))
+            .build();
+        let observations = [(UnixNanos::from(500), Price::from("99.00"))];
+
+        assert_eq!(
+            order.benchmark_twap(&observations),
+            Some(Price::from("99.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_benchmark_twap_no_observations() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id("AAPL.XNAS".into())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(10))
+            .build();
+
+        assert_eq!(order.benchmark_twap(&[]), None);
+    }
+
+    fn passive_buy(quantity: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AAPL.XNAS".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(quantity))
+            .build()
+    }
+
+    #[rstest]
+    fn test_fill_probability_single_unit() {
+        let order = passive_buy("1");
+
+        let probability = order.fill_probability(Quantity::from(0), Decimal::ONE, 1_000_000_000);
+
+        assert!((probability - (1.0 - (-1.0_f64).exp())).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_fill_probability_decreases_with_queue_ahead() {
+        let order = passive_buy("10");
+        let rate = Decimal::from(50);
+
+        let probabilities: Vec<f64> = [0, 50, 100, 150, 500]
+            .into_iter()
+            .map(|queue| order.fill_probability(Quantity::from(queue), rate, 2_000_000_000))
+            .collect();
+
+        assert!(probabilities.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(probabilities[0] > 0.99);
+        assert!(probabilities[4] < 0.01);
+    }
+
+    #[rstest]
+    fn test_fill_probability_increases_with_horizon_and_rate() {
+        let order = passive_buy("10");
+        let queue_ahead = Quantity::from(40);
+
+        let short = order.fill_probability(queue_ahead, Decimal::from(50), 500_000_000);
+        let long = order.fill_probability(queue_ahead, Decimal::from(50), 2_000_000_000);
+        let faster = order.fill_probability(queue_ahead, Decimal::from(100), 500_000_000);
+
+        assert!(short < long);
+        assert!(short < faster);
+    }
+
+    #[rstest]
+    #[case::no_arrivals(Decimal::ZERO, 1_000_000_000, 0.0)]
+    #[case::no_horizon(Decimal::ONE, 0, 0.0)]
+    fn test_fill_probability_without_arrivals(
+        #[case] arrival_rate: Decimal,
+        #[case] horizon_ns: u64,
+        #[case] expected: f64,
+    ) {
+        let order = passive_buy("10");
+
+        let probability = order.fill_probability(Quantity::from(5), arrival_rate, horizon_ns);
+
+        assert_eq!(probability, expected);
+    }
+
+    #[rstest]
+    fn test_fill_probability_large_expected_arrivals() {
+        let order = passive_buy("1000");
+
+        // 2,000,000 expected arrivals against 1,001,000 required
+        let probability = order.fill_probability(
+            Quantity::from(1_000),
+            Decimal::from(1_000_000),
+            2_000_000_000,
+        );
+
+        assert!(probability > 0.999_999);
+    }
+
+    #[rstest]
+    #[case::no_position(PositionSide::NoPositionSide, OrderSide::Sell, 50_000, true)]
+    #[case::flat_sell(PositionSide::Flat, OrderSide::Sell, 50_000, true)]
+    #[case::flat_buy(PositionSide::Flat, OrderSide::Buy, 50_000, true)]
+    #[case::long_buy(PositionSide::Long, OrderSide::Buy, 50_000, true)]
+    #[case::long_partial_close(PositionSide::Long, OrderSide::Sell, 50_000, false)]
+    #[case::long_full_close(PositionSide::Long, OrderSide::Sell, 100_000, false)]
+    #[case::long_flip(PositionSide::Long, OrderSide::Sell, 150_000, true)]
+    #[case::short_sell(PositionSide::Short, OrderSide::Sell, 50_000, true)]
+    #[case::short_full_close(PositionSide::Short, OrderSide::Buy, 100_000, false)]
+    #[case::short_flip(PositionSide::Short, OrderSide::Buy, 100_001, true)]
+    fn test_would_increase_exposure_reduce_only(
+        audusd_sim: CurrencyPair,
+        #[case] position_side: PositionSide,
+        #[case] side: OrderSide,
+        #[case] quantity: i64,
+        #[case] expected: bool,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(side)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(quantity))
+            .reduce_only(true)
+            .build();
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let (quantity, price) = (Quantity::from(100_000), Price::from("1.00000"));
+        let position = match position_side {
+            PositionSide::Long => Some(stub_position(&instrument, OrderSide::Buy, quantity, price)),
+            PositionSide::Short => {
+                Some(stub_position(&instrument, OrderSide::Sell, quantity, price))
+            }
+            PositionSide::Flat => {
+                let mut position = stub_position(&instrument, OrderSide::Buy, quantity, price);
+                let close = stub_order_filled(
+                    &instrument,
+                    OrderSide::Sell,
+                    quantity,
+                    price,
+                    Some(TradeId::new("2")),
+                );
+                position.apply(&close);
+                Some(position)
+            }
+            PositionSide::NoPositionSide => None,
+        };
+
+        assert_eq!(order.would_increase_exposure(position.as_ref()), expected);
+    }
+
+    #[rstest]
+    fn test_validate(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let quantity = Quantity::from("1.2345");
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(quantity)
+            .build();
+        let err = order.validate(&instrument).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("should be a multiple of the size increment 0.001"));
+
+        let rounded = quantity.round_to_size_increment(instrument.size_increment());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(rounded)
+            .build();
+        assert_eq!(rounded, Quantity::from("1.234"));
+        assert!(order.validate(&instrument).is_ok());
+    }
+
+    #[rstest]
+    #[case::price("1000.005", "999.00")]
+    #[case::trigger_price("1000.00", "999.005")]
+    fn test_validate_price_increment(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] trigger_price: &str,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .trigger_price(Price::from(trigger_price))
+            .quantity(Quantity::from("1.000"))
+            .build();
+
+        let err = order.validate(&instrument).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("should be a multiple of the price increment 0.01"));
+    }
+
+    #[rstest]
+    fn test_validate_other_instrument(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        assert!(order.validate(&instrument).is_err());
+    }
+
+    #[rstest]
+    #[case(OrderType::Market)]
+    #[case(OrderType::Limit)]
+    #[case(OrderType::StopMarket)]
+    #[case(OrderType::StopLimit)]
+    #[case(OrderType::MarketToLimit)]
+    #[case(OrderType::MarketIfTouched)]
+    #[case(OrderType::LimitIfTouched)]
+    #[case(OrderType::TrailingStopMarket)]
+    #[case(OrderType::TrailingStopLimit)]
+    fn test_json_round_trip(#[case] order_type: OrderType, audusd_sim: CurrencyPair) {
+        let order = OrderTestBuilder::new(order_type)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .trigger_price(Price::from("1.00010"))
+            .limit_offset(Price::from("0.00005"))
+            .trailing_offset(Price::from("0.00010"))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order = TestOrderStubs::make_accepted_order(&order);
+
+        let json = order.to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let restored = OrderAny::from_json(&json).unwrap();
+
+        assert_eq!(value["order_type"], order_type.to_string());
+        assert_eq!(restored.order_type(), order_type);
+        assert_eq!(restored.status(), OrderStatus::Accepted);
+        assert_eq!(restored.events(), order.events());
+        assert_eq!(restored.to_json().unwrap(), json);
+    }
+
+    #[rstest]
+    fn test_from_json_unknown_order_type() {
+        let json = r#"{"order_type":"ICEBERG","core":{}}"#;
+        assert!(OrderAny::from_json(json).is_err());
+    }
+}
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use rust_decimal::Decimal;
@@ -23,8 +23,8 @@ use ustr::Ustr;
 use super::any::OrderAny;
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
-        TimeInForce, TrailingOffsetType, TriggerType,
+        CancellationReason, ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType,
+        PositionSide, TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
@@ -53,6 +53,33 @@ const LIMIT_ORDER_TYPES: &[OrderType] = &[
     OrderType::MarketIfTouched,
 ];
 
+/// Creates order tags from the `key=value` pairs, to be read back with [`Order::tag`].
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If a key is empty or contains `=`.
+/// - If a key is duplicated.
+pub fn tags_from_pairs<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> anyhow::Result<Vec<Ustr>>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut keys = HashSet::new();
+    let mut tags = Vec::new();
+    for (key, value) in pairs {
+        let key = key.as_ref();
+        if key.is_empty() || key.contains('=') {
+            anyhow::bail!("Invalid tag key '{key}': must be non-empty and not contain '='");
+        }
+        if !keys.insert(key.to_string()) {
+            anyhow::bail!("Duplicate tag key '{key}'");
+        }
+        tags.push(Ustr::from(&format!("{key}={}", value.as_ref())));
+    }
+    Ok(tags)
+}
+
 const LOCAL_ACTIVE_ORDER_STATUS: &[OrderStatus] = &[
     OrderStatus::Initialized,
     OrderStatus::Emulated,
@@ -73,6 +100,20 @@ pub enum OrderError {
     AlreadyInitialized,
     #[error("Order had no previous state")]
     NoPreviousState,
+    #[error("Invalid contingency for order {0}: {1}")]
+    InvalidContingency(ClientOrderId, String),
+    #[error("Unsupported trailing offset type: {0}")]
+    UnsupportedTrailingOffsetType(TrailingOffsetType),
+    #[error("Invalid `{0}`: must not be negative, was {1}")]
+    InvalidOffset(&'static str, Price),
+    #[error("A `tick_size` is required for a TICKS trailing offset")]
+    MissingTickSize,
+    #[error("Trailed price {0} is out of range")]
+    TrailedPriceOutOfRange(Decimal),
+    #[error("Invalid percentage of position, was {0}")]
+    InvalidPositionPercentage(Decimal),
+    #[error("Cannot reduce flat position {0}")]
+    FlatPosition(PositionId),
 }
 
 #[must_use]
@@ -102,12 +143,15 @@ impl OrderStatus {
             (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
             (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
             (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventAny::Updated(_)) => Self::Initialized,
             (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
             (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
             (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
+            (Self::Emulated, OrderEventAny::Updated(_)) => Self::Emulated,  // Emulated orders
             (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
             (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
             (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
+            (Self::Released, OrderEventAny::Updated(_)) => Self::Released,  // Emulated orders
             (Self::Submitted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
             (Self::Submitted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
             (Self::Submitted, OrderEventAny::Rejected(_)) => Self::Rejected,
@@ -115,6 +159,7 @@ impl OrderStatus {
             (Self::Submitted, OrderEventAny::Accepted(_)) => Self::Accepted,
             (Self::Submitted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Submitted, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Submitted, OrderEventAny::Updated(_)) => Self::Submitted,
             (Self::Accepted, OrderEventAny::Rejected(_)) => Self::Rejected,  // StopLimit order
             (Self::Accepted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
             (Self::Accepted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
@@ -123,6 +168,7 @@ impl OrderStatus {
             (Self::Accepted, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::Accepted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Accepted, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Accepted, OrderEventAny::Updated(_)) => Self::Accepted,
             (Self::Canceled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
             (Self::Canceled, OrderEventAny::Filled(_)) => Self::Filled,  // Real world possibility
             (Self::PendingUpdate, OrderEventAny::Rejected(_)) => Self::Rejected,
@@ -148,16 +194,75 @@ impl OrderStatus {
             (Self::Triggered, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::Triggered, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Triggered, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Triggered, OrderEventAny::Updated(_)) => Self::Triggered,
             (Self::PartiallyFilled, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
             (Self::PartiallyFilled, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
             (Self::PartiallyFilled, OrderEventAny::Canceled(_)) => Self::Canceled,
             (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::PartiallyFilled, OrderEventAny::Updated(_)) => Self::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
     }
+
+    /// Returns whether the status is considered _open_.
+    #[must_use]
+    pub const fn is_open(self) -> bool {
+        match self {
+            Self::Accepted
+            | Self::Triggered
+            | Self::PendingUpdate
+            | Self::PendingCancel
+            | Self::PartiallyFilled => true,
+            Self::Initialized
+            | Self::Denied
+            | Self::Emulated
+            | Self::Released
+            | Self::Submitted
+            | Self::Rejected
+            | Self::Canceled
+            | Self::Expired
+            | Self::Filled => false,
+        }
+    }
+
+    /// Returns whether the status is considered _closed_.
+    #[must_use]
+    pub const fn is_closed(self) -> bool {
+        match self {
+            Self::Denied | Self::Rejected | Self::Canceled | Self::Expired | Self::Filled => true,
+            Self::Initialized
+            | Self::Emulated
+            | Self::Released
+            | Self::Submitted
+            | Self::Accepted
+            | Self::Triggered
+            | Self::PendingUpdate
+            | Self::PendingCancel
+            | Self::PartiallyFilled => false,
+        }
+    }
+
+    /// Returns whether the status is considered _in-flight_.
+    #[must_use]
+    pub const fn is_inflight(self) -> bool {
+        match self {
+            Self::Submitted | Self::PendingUpdate | Self::PendingCancel => true,
+            Self::Initialized
+            | Self::Denied
+            | Self::Emulated
+            | Self::Released
+            | Self::Accepted
+            | Self::Rejected
+            | Self::Canceled
+            | Self::Expired
+            | Self::Triggered
+            | Self::PartiallyFilled
+            | Self::Filled => false,
+        }
+    }
 }
 
 pub trait Order: 'static + Send {
@@ -224,6 +329,40 @@ pub trait Order: 'static + Send {
 
     fn trade_ids(&self) -> Vec<&TradeId>;
 
+    /// Returns the next visible slice of an iceberg order, as the `display_qty` capped at
+    /// the `leaves_qty`, e.g. to replenish the displayed quantity after a slice fills.
+    ///
+    /// Returns `None` if the order has no `display_qty` or nothing left to fill.
+    fn next_display_slice(&self) -> Option<Quantity> {
+        let display_qty = self.display_qty()?;
+        let leaves_qty = self.leaves_qty();
+        if !leaves_qty.is_positive() {
+            return None;
+        }
+        Some(display_qty.min(leaves_qty))
+    }
+
+    /// Returns the value of the first `key=value` tag with the given `key`.
+    ///
+    /// Tags without a `=` separator are ignored.
+    fn tag(&self, key: &str) -> Option<&str> {
+        self.tags()?
+            .iter()
+            .find_map(|tag| match tag.as_str().split_once('=') {
+                Some((k, v)) if k == key => Some(v),
+                _ => None,
+            })
+    }
+
+    /// Returns the reason the order was rejected by the venue (e.g. a post-only order which
+    /// would have crossed the spread), or `None` if the order has not been rejected.
+    fn rejected_reason(&self) -> Option<Ustr> {
+        self.events().iter().rev().find_map(|event| match event {
+            OrderEventAny::Rejected(event) => Some(event.reason),
+            _ => None,
+        })
+    }
+
     fn is_buy(&self) -> bool {
         self.side() == OrderSide::Buy
     }
@@ -285,14 +424,7 @@ pub trait Order: 'static + Send {
             }
         }
 
-        matches!(
-            self.status(),
-            OrderStatus::Accepted
-                | OrderStatus::Triggered
-                | OrderStatus::PendingCancel
-                | OrderStatus::PendingUpdate
-                | OrderStatus::PartiallyFilled
-        )
+        self.status().is_open()
     }
 
     fn is_canceled(&self) -> bool {
@@ -300,14 +432,7 @@ pub trait Order: 'static + Send {
     }
 
     fn is_closed(&self) -> bool {
-        matches!(
-            self.status(),
-            OrderStatus::Denied
-                | OrderStatus::Rejected
-                | OrderStatus::Canceled
-                | OrderStatus::Expired
-                | OrderStatus::Filled
-        )
+        self.status().is_closed()
     }
 
     fn is_inflight(&self) -> bool {
@@ -317,10 +442,7 @@ pub trait Order: 'static + Send {
             }
         }
 
-        matches!(
-            self.status(),
-            OrderStatus::Submitted | OrderStatus::PendingCancel | OrderStatus::PendingUpdate
-        )
+        self.status().is_inflight()
     }
 
     fn is_pending_update(&self) -> bool {
@@ -433,6 +555,7 @@ pub struct OrderCore {
     pub leaves_qty: Quantity,
     pub avg_px: Option<f64>,
     pub slippage: Option<f64>,
+    pub cancellation_reason: Option<CancellationReason>,
     pub init_id: UUID4,
     pub ts_init: UnixNanos,
     pub ts_last: UnixNanos,
@@ -479,6 +602,7 @@ impl OrderCore {
             leaves_qty: init.quantity,
             avg_px: None,
             slippage: None,
+            cancellation_reason: None,
             init_id: init.event_id,
             ts_init: init.ts_event,
             ts_last: init.ts_event,
@@ -489,7 +613,13 @@ impl OrderCore {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
+        let new_status = match (self.status, &event) {
+            // An update resolves a pending update back to the status prior to the request
+            (OrderStatus::PendingUpdate, OrderEventAny::Updated(_)) => {
+                self.previous_status.ok_or(OrderError::NoPreviousState)?
+            }
+            _ => self.status.transition(&event)?,
+        };
         self.previous_status = Some(self.status);
         self.status = new_status;
 
@@ -564,7 +694,9 @@ impl OrderCore {
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
 
-    fn canceled(&mut self, _event: &OrderCanceled) {}
+    fn canceled(&mut self, event: &OrderCanceled) {
+        self.cancellation_reason = event.cancellation_reason;
+    }
 
     fn expired(&mut self, _event: &OrderExpired) {}
 
@@ -585,26 +717,27 @@ impl OrderCore {
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
+        // Weight the average by the quantity filled prior to this fill
+        self.set_avg_px(event.last_qty, event.last_px);
         self.filled_qty += event.last_qty;
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
-        self.set_avg_px(event.last_qty, event.last_px);
     }
 
+    /// Sets the average fill price to the cumulative notional over the cumulative quantity,
+    /// including the `last_qty` filled at `last_px`.
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
-        if self.avg_px.is_none() {
-            self.avg_px = Some(last_px.as_f64());
-        }
-
         let filled_qty = self.filled_qty.as_f64();
         let total_qty = filled_qty + last_qty.as_f64();
+        if total_qty <= 0.0 {
+            // Nothing filled yet, so there is no average to take
+            return;
+        }
 
-        let avg_px = self
-            .avg_px
-            .unwrap()
-            .mul_add(filled_qty, last_px.as_f64() * last_qty.as_f64())
-            / total_qty;
-        self.avg_px = Some(avg_px);
+        // The notional of the prior fills is recovered from their average price
+        let filled_notional = self.avg_px.unwrap_or(0.0) * filled_qty;
+        let total_notional = last_px.as_f64().mul_add(last_qty.as_f64(), filled_notional);
+        self.avg_px = Some(total_notional / total_qty);
     }
 
     pub fn set_slippage(&mut self, price: Price) {
@@ -675,6 +808,16 @@ impl OrderCore {
     pub fn init_event(&self) -> Option<OrderEventAny> {
         self.events.first().cloned()
     }
+
+    /// Compacts the event history to the `OrderInitialized` and last events, which is all
+    /// the state derived from the events needs once they have been applied.
+    pub fn compact_events(&mut self) {
+        if self.events.len() > 2 {
+            let last = self.events.pop().unwrap();
+            self.events.truncate(1);
+            self.events.push(last);
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -689,8 +832,10 @@ mod tests {
     use crate::{
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
-            accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder,
+            denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, pending_update::OrderPendingUpdateBuilder,
+            submitted::OrderSubmittedBuilder, updated::OrderUpdatedBuilder,
         },
         orders::MarketOrder,
     };
@@ -804,4 +949,162 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    #[case(OrderStatus::Initialized, Some(OrderStatus::Initialized))]
+    #[case(OrderStatus::Emulated, Some(OrderStatus::Emulated))]
+    #[case(OrderStatus::Released, Some(OrderStatus::Released))]
+    #[case(OrderStatus::Submitted, Some(OrderStatus::Submitted))]
+    #[case(OrderStatus::Accepted, Some(OrderStatus::Accepted))]
+    #[case(OrderStatus::Triggered, Some(OrderStatus::Triggered))]
+    #[case(OrderStatus::PartiallyFilled, Some(OrderStatus::PartiallyFilled))]
+    #[case(OrderStatus::PendingCancel, None)]
+    #[case(OrderStatus::Denied, None)]
+    #[case(OrderStatus::Rejected, None)]
+    #[case(OrderStatus::Canceled, None)]
+    #[case(OrderStatus::Expired, None)]
+    #[case(OrderStatus::Filled, None)]
+    fn test_order_status_transition_updated(
+        #[case] mut status: OrderStatus,
+        #[case] expected: Option<OrderStatus>,
+    ) {
+        let event = OrderEventAny::Updated(OrderUpdatedBuilder::default().build().unwrap());
+
+        let result = status.transition(&event);
+
+        match expected {
+            Some(expected) => assert_eq!(result.unwrap(), expected),
+            None => assert!(matches!(result, Err(OrderError::InvalidStateTransition))),
+        }
+    }
+
+    #[rstest]
+    fn test_order_updated_resolves_pending_update_to_previous_status() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let pending = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(50_000))
+            .build()
+            .unwrap();
+
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+
+        order.apply(OrderEventAny::PendingUpdate(pending)).unwrap();
+        assert_eq!(order.status(), OrderStatus::PendingUpdate);
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.quantity(), Quantity::from(50_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(50_000));
+        assert_eq!(order.event_count(), 6);
+    }
+
+    #[rstest]
+    fn test_order_updated_rejected_once_closed() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let canceled = OrderCanceledBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default().build().unwrap();
+
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+        let result = order.apply(OrderEventAny::Updated(updated));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert_eq!(order.status(), OrderStatus::Canceled);
+    }
+
+    #[rstest]
+    fn test_tag_parses_key_value_tags() {
+        let tags = vec![
+            Ustr::from("legacy"),
+            Ustr::from("strategy=mean-reversion"),
+            Ustr::from("expr=a=b"),
+        ];
+        let order: MarketOrder = OrderInitializedBuilder::default()
+            .tags(Some(tags))
+            .build()
+            .unwrap()
+            .into();
+
+        assert_eq!(order.tag("strategy"), Some("mean-reversion"));
+        assert_eq!(order.tag("expr"), Some("a=b"));
+        assert_eq!(order.tag("legacy"), None);
+        assert_eq!(order.tag("missing"), None);
+    }
+
+    #[rstest]
+    fn test_tag_without_tags() {
+        let order = MarketOrder::default();
+        assert_eq!(order.tag("strategy"), None);
+    }
+
+    #[rstest]
+    fn test_tags_from_pairs() {
+        let tags = tags_from_pairs([("strategy", "momentum"), ("signal", "")]).unwrap();
+        let order: MarketOrder = OrderInitializedBuilder::default()
+            .tags(Some(tags))
+            .build()
+            .unwrap()
+            .into();
+
+        assert_eq!(order.tag("strategy"), Some("momentum"));
+        assert_eq!(order.tag("signal"), Some(""));
+    }
+
+    #[rstest]
+    fn test_tags_from_pairs_rejects_duplicate_keys() {
+        let result = tags_from_pairs([("strategy", "momentum"), ("strategy", "carry")]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Duplicate tag key 'strategy'"
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("a=b")]
+    fn test_tags_from_pairs_rejects_invalid_keys(#[case] key: &str) {
+        assert!(tags_from_pairs([(key, "value")]).is_err());
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Initialized, false, false, false)]
+    #[case(OrderStatus::Denied, false, true, false)]
+    #[case(OrderStatus::Emulated, false, false, false)]
+    #[case(OrderStatus::Released, false, false, false)]
+    #[case(OrderStatus::Submitted, false, false, true)]
+    #[case(OrderStatus::Accepted, true, false, false)]
+    #[case(OrderStatus::Rejected, false, true, false)]
+    #[case(OrderStatus::Canceled, false, true, false)]
+    #[case(OrderStatus::Expired, false, true, false)]
+    #[case(OrderStatus::Triggered, true, false, false)]
+    #[case(OrderStatus::PendingUpdate, true, false, true)]
+    #[case(OrderStatus::PendingCancel, true, false, true)]
+    #[case(OrderStatus::PartiallyFilled, true, false, false)]
+    #[case(OrderStatus::Filled, false, true, false)]
+    fn test_order_status_groupings(
+        #[case] status: OrderStatus,
+        #[case] is_open: bool,
+        #[case] is_closed: bool,
+        #[case] is_inflight: bool,
+    ) {
+        assert_eq!(status.is_open(), is_open);
+        assert_eq!(status.is_closed(), is_closed);
+        assert_eq!(status.is_inflight(), is_inflight);
+
+        let mut order = MarketOrder::default();
+        order.status = status;
+        let order = OrderAny::Market(order);
+        assert_eq!(order.is_open(), is_open);
+        assert_eq!(order.is_closed(), is_closed);
+        assert_eq!(order.is_inflight(), is_inflight);
+    }
 }
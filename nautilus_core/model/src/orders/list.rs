@@ -13,13 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
 use nautilus_core::{correctness::check_slice_not_empty, nanos::UnixNanos};
 use serde::{Deserialize, Serialize};
 
-use super::any::OrderAny;
-use crate::identifiers::{InstrumentId, OrderListId, StrategyId};
+use super::{any::OrderAny, base::OrderError};
+use crate::{
+    enums::ContingencyType,
+    identifiers::{ClientOrderId, InstrumentId, OrderListId, StrategyId},
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(
@@ -56,6 +62,164 @@ impl OrderList {
             ts_init,
         }
     }
+
+    /// Validates that the contingencies of the orders form a consistent graph.
+    ///
+    /// - Linked and parent orders must be in the list.
+    /// - The parent chains must not contain cycles.
+    /// - An OTO parent must be before its children, and link to them as its children.
+    /// - OCO and OUO members must have the same contingency type and be mutually linked.
+    /// - Orders without a contingency type must not link to other orders.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any of the above checks fail, for the first order which is inconsistent.
+    pub fn validate_contingencies(&self) -> Result<(), OrderError> {
+        check_contingencies(&self.orders)
+    }
+}
+
+/// Validates that the `orders` form a consistent order list.
+///
+/// - The orders must be non-empty, with unique client order IDs.
+/// - The orders must all have the same order list ID (or none).
+/// - The contingencies must form a consistent graph, see [`OrderList::validate_contingencies`].
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If any of the above checks fail, for the first order which is inconsistent.
+pub fn validate_order_list(orders: &[OrderAny]) -> anyhow::Result<()> {
+    check_slice_not_empty(orders, stringify!(orders))?;
+
+    let order_list_id = orders[0].order_list_id();
+    let mut client_order_ids = HashSet::new();
+    for order in orders {
+        let client_order_id = order.client_order_id();
+        if !client_order_ids.insert(client_order_id) {
+            anyhow::bail!("Duplicate order {client_order_id} in order list");
+        }
+        if order.order_list_id() != order_list_id {
+            anyhow::bail!(
+                "Order {client_order_id} has order list ID {:?}, expected {:?}",
+                order.order_list_id(),
+                order_list_id,
+            );
+        }
+    }
+
+    check_contingencies(orders)?;
+    Ok(())
+}
+
+fn check_contingencies(orders: &[OrderAny]) -> Result<(), OrderError> {
+    let positions: HashMap<ClientOrderId, usize> = orders
+        .iter()
+        .enumerate()
+        .map(|(i, order)| (order.client_order_id(), i))
+        .collect();
+    let invalid = |order: &OrderAny, reason: String| -> Result<(), OrderError> {
+        Err(OrderError::InvalidContingency(
+            order.client_order_id(),
+            reason,
+        ))
+    };
+
+    for (i, order) in orders.iter().enumerate() {
+        let client_order_id = order.client_order_id();
+        let linked_order_ids = order.linked_order_ids().unwrap_or_default();
+
+        for linked_order_id in &linked_order_ids {
+            if *linked_order_id == client_order_id {
+                return invalid(order, "linked to itself".to_string());
+            }
+            if !positions.contains_key(linked_order_id) {
+                return invalid(order, format!("dangling link to {linked_order_id}"));
+            }
+        }
+
+        // Walk the parent chain to detect cycles and dangling parents
+        let mut visited = HashSet::from([client_order_id]);
+        let mut current = order;
+        while let Some(parent_order_id) = current.parent_order_id() {
+            let Some(&parent_pos) = positions.get(&parent_order_id) else {
+                return invalid(order, format!("dangling parent {parent_order_id}"));
+            };
+            if !visited.insert(parent_order_id) {
+                return invalid(order, format!("cycle through parent {parent_order_id}"));
+            }
+            current = &orders[parent_pos];
+        }
+
+        if let Some(parent_order_id) = order.parent_order_id() {
+            let parent_pos = positions[&parent_order_id];
+            let parent = &orders[parent_pos];
+            if parent.contingency_type() != Some(ContingencyType::Oto) {
+                return invalid(order, format!("parent {parent_order_id} is not OTO"));
+            }
+            if parent_pos > i {
+                return invalid(order, format!("parent {parent_order_id} is after child"));
+            }
+            if !parent
+                .linked_order_ids()
+                .unwrap_or_default()
+                .contains(&client_order_id)
+            {
+                return invalid(
+                    order,
+                    format!("parent {parent_order_id} does not link child"),
+                );
+            }
+        }
+
+        match order
+            .contingency_type()
+            .unwrap_or(ContingencyType::NoContingency)
+        {
+            ContingencyType::NoContingency => {
+                if !linked_order_ids.is_empty() {
+                    return invalid(order, "linked orders without contingency".to_string());
+                }
+            }
+            ContingencyType::Oto => {
+                if linked_order_ids.is_empty() {
+                    return invalid(order, "OTO without linked orders".to_string());
+                }
+                for child_id in &linked_order_ids {
+                    let child = &orders[positions[child_id]];
+                    if child.parent_order_id() != Some(client_order_id) {
+                        return invalid(order, format!("child {child_id} has another parent"));
+                    }
+                }
+            }
+            contingency_type @ (ContingencyType::Oco | ContingencyType::Ouo) => {
+                if linked_order_ids.is_empty() {
+                    return invalid(order, format!("{contingency_type} without linked orders"));
+                }
+                for member_id in &linked_order_ids {
+                    let member = &orders[positions[member_id]];
+                    if member.contingency_type() != Some(contingency_type) {
+                        return invalid(
+                            order,
+                            format!("linked order {member_id} is not {contingency_type}"),
+                        );
+                    }
+                    if !member
+                        .linked_order_ids()
+                        .unwrap_or_default()
+                        .contains(&client_order_id)
+                    {
+                        return invalid(
+                            order,
+                            format!("linked order {member_id} does not link back"),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 impl PartialEq for OrderList {
@@ -90,7 +254,7 @@ mod tests {
     use super::*;
     use crate::{
         enums::{OrderSide, OrderType},
-        identifiers::{OrderListId, StrategyId},
+        identifiers::{ClientOrderId, OrderListId, StrategyId},
         instruments::{stubs::*, CurrencyPair},
         orders::OrderTestBuilder,
         types::{Price, Quantity},
@@ -131,4 +295,215 @@ mod tests {
             "OrderList(id=OL-001, instrument_id=AUD/USD.SIM, strategy_id=S-001, orders="
         ));
     }
+
+    type OrderSpec = (
+        &'static str,
+        ContingencyType,
+        &'static [&'static str],
+        Option<&'static str>,
+    );
+
+    fn order_list(instrument: &CurrencyPair, specs: &[OrderSpec]) -> OrderList {
+        let orders = specs
+            .iter()
+            .map(|(id, contingency_type, linked, parent)| {
+                let mut builder = OrderTestBuilder::new(OrderType::Limit);
+                builder
+                    .instrument_id(instrument.id)
+                    .client_order_id(ClientOrderId::new(id))
+                    .side(OrderSide::Buy)
+                    .price(Price::from("1.00000"))
+                    .quantity(Quantity::from(100_000))
+                    .contingency_type(*contingency_type)
+                    .linked_order_ids(linked.iter().map(ClientOrderId::new).collect());
+                if let Some(parent) = parent {
+                    builder.parent_order_id(ClientOrderId::new(parent));
+                }
+                builder.build()
+            })
+            .collect();
+
+        OrderList::new(
+            OrderListId::from("OL-001"),
+            instrument.id,
+            StrategyId::default(),
+            orders,
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_validate_contingencies_bracket(audusd_sim: CurrencyPair) {
+        let order_list = order_list(
+            &audusd_sim,
+            &[
+                ("O-1", ContingencyType::Oto, &["O-2", "O-3"], None),
+                ("O-2", ContingencyType::Ouo, &["O-3"], Some("O-1")),
+                ("O-3", ContingencyType::Ouo, &["O-2"], Some("O-1")),
+            ],
+        );
+
+        assert!(order_list.validate_contingencies().is_ok());
+    }
+
+    #[rstest]
+    fn test_validate_contingencies_oco_pair(audusd_sim: CurrencyPair) {
+        let order_list = order_list(
+            &audusd_sim,
+            &[
+                ("O-1", ContingencyType::Oco, &["O-2"], None),
+                ("O-2", ContingencyType::Oco, &["O-1"], None),
+                ("O-3", ContingencyType::NoContingency, &[], None),
+            ],
+        );
+
+        assert!(order_list.validate_contingencies().is_ok());
+    }
+
+    const MALFORMED_DANGLING_LINK: &[OrderSpec] = &[
+        ("O-1", ContingencyType::Oco, &["O-2"], None),
+        ("O-2", ContingencyType::Oco, &["O-1", "O-9"], None),
+    ];
+
+    const MALFORMED_DANGLING_PARENT: &[OrderSpec] =
+        &[("O-1", ContingencyType::NoContingency, &[], Some("O-9"))];
+
+    const MALFORMED_PARENT_CYCLE: &[OrderSpec] = &[
+        ("O-1", ContingencyType::Oto, &["O-2"], Some("O-2")),
+        ("O-2", ContingencyType::Oto, &["O-1"], Some("O-1")),
+    ];
+
+    const MALFORMED_CHILD_BEFORE_PARENT: &[OrderSpec] = &[
+        ("O-2", ContingencyType::NoContingency, &[], Some("O-1")),
+        ("O-1", ContingencyType::Oto, &["O-2"], None),
+    ];
+
+    const MALFORMED_PARENT_NOT_OTO: &[OrderSpec] = &[
+        ("O-1", ContingencyType::NoContingency, &[], None),
+        ("O-2", ContingencyType::NoContingency, &[], Some("O-1")),
+    ];
+
+    const MALFORMED_OCO_NOT_MUTUAL: &[OrderSpec] = &[
+        ("O-1", ContingencyType::Oco, &["O-2"], None),
+        ("O-2", ContingencyType::Oco, &["O-3"], None),
+        ("O-3", ContingencyType::Oco, &["O-2"], None),
+    ];
+
+    const MALFORMED_MISMATCHED_CONTINGENCY: &[OrderSpec] = &[
+        ("O-1", ContingencyType::Oco, &["O-2"], None),
+        ("O-2", ContingencyType::Ouo, &["O-1"], None),
+    ];
+
+    const MALFORMED_LINKS_WITHOUT_CONTINGENCY: &[OrderSpec] = &[
+        ("O-1", ContingencyType::NoContingency, &["O-2"], None),
+        ("O-2", ContingencyType::NoContingency, &[], None),
+    ];
+
+    #[rstest]
+    #[case::dangling_link(MALFORMED_DANGLING_LINK, "O-2", "dangling link to O-9")]
+    #[case::dangling_parent(MALFORMED_DANGLING_PARENT, "O-1", "dangling parent O-9")]
+    #[case::parent_cycle(MALFORMED_PARENT_CYCLE, "O-1", "cycle through parent O-1")]
+    #[case::child_before_parent(MALFORMED_CHILD_BEFORE_PARENT, "O-2", "parent O-1 is after child")]
+    #[case::parent_not_oto(MALFORMED_PARENT_NOT_OTO, "O-2", "parent O-1 is not OTO")]
+    #[case::oco_not_mutual(MALFORMED_OCO_NOT_MUTUAL, "O-1", "linked order O-2 does not link back")]
+    #[case::mismatched_contingency(
+        MALFORMED_MISMATCHED_CONTINGENCY,
+        "O-1",
+        "linked order O-2 is not OCO"
+    )]
+    #[case::links_without_contingency(
+        MALFORMED_LINKS_WITHOUT_CONTINGENCY,
+        "O-1",
+        "linked orders without contingency"
+    )]
+    fn test_validate_contingencies_malformed(
+        audusd_sim: CurrencyPair,
+        #[case] specs: &[OrderSpec],
+        #[case] client_order_id: &str,
+        #[case] reason: &str,
+    ) {
+        let order_list = order_list(&audusd_sim, specs);
+
+        let err = order_list.validate_contingencies().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Invalid contingency for order {client_order_id}: {reason}")
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_list_oco_pair(audusd_sim: CurrencyPair) {
+        let order_list = order_list(
+            &audusd_sim,
+            &[
+                ("O-1", ContingencyType::Oco, &["O-2"], None),
+                ("O-2", ContingencyType::Oco, &["O-1"], None),
+            ],
+        );
+
+        assert!(validate_order_list(&order_list.orders).is_ok());
+    }
+
+    #[rstest]
+    fn test_validate_order_list_dangling_link(audusd_sim: CurrencyPair) {
+        let order_list = order_list(
+            &audusd_sim,
+            &[
+                ("O-1", ContingencyType::Oco, &["O-2"], None),
+                ("O-2", ContingencyType::Oco, &["O-1", "O-9"], None),
+            ],
+        );
+
+        let err = validate_order_list(&order_list.orders).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid contingency for order O-2: dangling link to O-9"
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_list_duplicate_order(audusd_sim: CurrencyPair) {
+        let order_list = order_list(
+            &audusd_sim,
+            &[
+                ("O-1", ContingencyType::NoContingency, &[], None),
+                ("O-1", ContingencyType::NoContingency, &[], None),
+            ],
+        );
+
+        let err = validate_order_list(&order_list.orders).unwrap_err();
+
+        assert_eq!(err.to_string(), "Duplicate order O-1 in order list");
+    }
+
+    #[rstest]
+    fn test_validate_order_list_mismatched_order_list_id(audusd_sim: CurrencyPair) {
+        let order1 = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(ClientOrderId::new("O-1"))
+            .order_list_id(OrderListId::from("OL-001"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order2 = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .client_order_id(ClientOrderId::new("O-2"))
+            .order_list_id(OrderListId::from("OL-002"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let result = validate_order_list(&[order1, order2]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_validate_order_list_empty() {
+        assert!(validate_order_list(&[]).is_err());
+    }
 }
@@ -345,6 +345,10 @@ impl Order for MarketIfTouchedOrder {
             self.update(event);
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
+        let ts_triggered = match event {
+            OrderEventAny::Triggered(ref event) => Some(event.ts_event),
+            _ => None,
+        };
 
         self.core.apply(event)?;
 
@@ -352,6 +356,11 @@ impl Order for MarketIfTouchedOrder {
             self.core.set_slippage(self.trigger_price);
         };
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        }
+
         Ok(())
     }
 
@@ -418,3 +427,119 @@ impl From<OrderInitialized> for MarketIfTouchedOrder {
         )
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use rstest::rstest;
+
+    use super::MarketIfTouchedOrder;
+    use crate::{
+        enums::{OrderSide, OrderStatus, OrderType, TriggerType},
+        events::{OrderEventAny, OrderTriggered, OrderUpdated},
+        identifiers::InstrumentId,
+        instruments::{stubs::*, Equity},
+        orders::{stubs::TestOrderStubs, Order, OrderAny, OrderError, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+
+    fn market_if_touched(instrument: &Equity) -> MarketIfTouchedOrder {
+        let order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+            .instrument_id(instrument.id)
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("99.00"))
+            .trigger_type(TriggerType::LastPrice)
+            .trigger_instrument_id(InstrumentId::from("SPY.XNAS"))
+            .quantity(Quantity::from(10))
+            .build();
+        MarketIfTouchedOrder::from(TestOrderStubs::make_accepted_order(&order))
+    }
+
+    fn triggered(order: &MarketIfTouchedOrder, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Triggered(OrderTriggered::new(
+            order.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            UUID4::new(),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+            false,
+            order.venue_order_id,
+            order.account_id,
+        ))
+    }
+
+    #[rstest]
+    fn test_initialize(equity_aapl: Equity) {
+        let order = market_if_touched(&equity_aapl);
+
+        assert_eq!(order.order_type(), OrderType::MarketIfTouched);
+        assert_eq!(order.price(), None);
+        assert_eq!(order.trigger_price(), Some(Price::from("99.00")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::LastPrice));
+        assert_eq!(
+            order.trigger_instrument_id(),
+            Some(InstrumentId::from("SPY.XNAS"))
+        );
+        assert!(!order.is_triggered);
+        assert_eq!(order.ts_triggered, None);
+        assert!(matches!(order.into_any(), OrderAny::MarketIfTouched(_)));
+    }
+
+    #[rstest]
+    fn test_apply_triggered(equity_aapl: Equity) {
+        let mut order = market_if_touched(&equity_aapl);
+
+        order.apply(triggered(&order, 1_000)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Triggered);
+        assert!(order.is_triggered);
+        assert_eq!(order.ts_triggered, Some(UnixNanos::from(1_000)));
+    }
+
+    #[rstest]
+    fn test_apply_triggered_twice_is_rejected(equity_aapl: Equity) {
+        let mut order = market_if_touched(&equity_aapl);
+        order.apply(triggered(&order, 1_000)).unwrap();
+
+        let result = order.apply(triggered(&order, 2_000));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert_eq!(order.ts_triggered, Some(UnixNanos::from(1_000)));
+    }
+
+    #[rstest]
+    fn test_apply_updated_after_triggered(equity_aapl: Equity) {
+        let mut order = market_if_touched(&equity_aapl);
+        order.apply(triggered(&order, 1_000)).unwrap();
+
+        let updated = OrderUpdated::new(
+            order.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            Quantity::from(5),
+            UUID4::new(),
+            UnixNanos::from(2_000),
+            UnixNanos::from(2_000),
+            false,
+            order.venue_order_id,
+            order.account_id,
+            None,
+            Some(Price::from("98.50")),
+            None,
+            None,
+        );
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Triggered);
+        assert!(order.is_triggered);
+        assert_eq!(order.trigger_price(), Some(Price::from("98.50")));
+        assert_eq!(order.quantity(), Quantity::from(5));
+        assert_eq!(order.leaves_qty(), Quantity::from(5));
+    }
+}
@@ -29,6 +29,7 @@ pub mod market_if_touched;
 pub mod market_to_limit;
 pub mod stop_limit;
 pub mod stop_market;
+pub mod trailing;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
 
@@ -37,12 +38,16 @@ pub mod stubs;
 
 // Re-exports
 pub use crate::orders::{
-    any::{LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny},
-    base::{Order, OrderError},
+    any::{
+        net_position_qty, reconcile_orders, BracketLevels, LimitOrderAny, OrderAny,
+        OrderDivergence, PassiveOrderAny, PriceStrings, ReconcileReport, RiskExposure,
+        StopOrderAny,
+    },
+    base::{tags_from_pairs, Order, OrderError},
     builder::OrderTestBuilder,
     limit::LimitOrder,
     limit_if_touched::LimitIfTouchedOrder,
-    list::OrderList,
+    list::{validate_order_list, OrderList},
     market::MarketOrder,
     market_if_touched::MarketIfTouchedOrder,
     market_to_limit::MarketToLimitOrder,
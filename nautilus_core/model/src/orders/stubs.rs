@@ -113,7 +113,11 @@ impl TestOrderEventStubs {
             Some(position_id),
             Some(commission),
         );
-        OrderEventAny::Filled(event)
+        if last_qty < order.leaves_qty() {
+            OrderEventAny::PartiallyFilled(event)
+        } else {
+            OrderEventAny::Filled(event)
+        }
     }
 }
 
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trailing stop price calculations shared by the trailing stop order types.
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{
+    enums::{OrderSide, OrderSideSpecified, TrailingOffsetType, TriggerType},
+    orders::OrderError,
+    types::{fixed::FIXED_PRECISION, Price},
+};
+
+/// Returns the reference price to trail from.
+///
+/// The `last_trade` price is used for last price trigger types when available,
+/// otherwise the `market_price` is used.
+#[must_use]
+pub fn trailing_reference(
+    trigger_type: TriggerType,
+    market_price: Price,
+    last_trade: Option<Price>,
+) -> Price {
+    match (trigger_type, last_trade) {
+        (TriggerType::LastPrice | TriggerType::LastOrBidAsk, Some(last_trade)) => last_trade,
+        _ => market_price,
+    }
+}
+
+/// Calculates the trailed price `offset` away from the `reference` price, returning it
+/// only if it moves the `current` price in the favorable direction for the `side`.
+///
+/// The offset is applied as per [`apply_trailing_offset`], with `TICKS` offsets measured
+/// in ticks of the `tick_size`, and the trailed price is rounded to the nearest multiple
+/// of the `tick_size` so it is valid for the instrument.
+///
+/// # Errors
+///
+/// This function returns an error if the offset cannot be applied.
+pub fn calculate_trailing_price(
+    side: OrderSideSpecified,
+    offset_type: TrailingOffsetType,
+    offset: Price,
+    reference: Price,
+    current: Price,
+    tick_size: Price,
+) -> Result<Option<Price>, OrderError> {
+    if offset.raw < 0 {
+        return Err(OrderError::InvalidOffset("offset", offset));
+    }
+    let price = offset_price(
+        reference,
+        offset.as_decimal(),
+        offset_type,
+        side.as_order_side(),
+        Some(tick_size),
+    )?;
+    let price = round_to_tick(decimal_to_price(price, reference.precision)?, tick_size);
+    let trails = match side {
+        OrderSideSpecified::Buy => price < current,
+        OrderSideSpecified::Sell => price > current,
+    };
+
+    Ok(trails.then_some(price))
+}
+
+/// Rounds the `price` to the nearest multiple of the `tick_size` (halves round up), at the
+/// `tick_size` precision.
+fn round_to_tick(price: Price, tick_size: Price) -> Price {
+    if tick_size.raw <= 0 {
+        return price;
+    }
+    let remainder = price.raw.rem_euclid(tick_size.raw);
+    let mut raw = price.raw - remainder;
+    if remainder * 2 >= tick_size.raw {
+        raw += tick_size.raw;
+    }
+    Price::from_raw(raw, tick_size.precision)
+}
+
+/// Applies the trailing `offset` to the `base` price, above it for a BUY and below it
+/// for a SELL, at the `base` price precision.
+///
+/// The `offset` is interpreted according to the `offset_type`:
+/// - `PRICE`: an absolute price amount.
+/// - `BASIS_POINTS`: basis points of the `base` price.
+/// - `TICKS`: a number of ticks of the `tick_size`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `offset` is negative.
+/// - If the `side` is not BUY or SELL.
+/// - If the `offset_type` is `TICKS` and no `tick_size` is given.
+/// - If the `offset_type` is `PRICE_TIER` (set by the venue) or `NO_TRAILING_OFFSET`.
+/// - If the trailed price is out of range.
+pub fn apply_trailing_offset(
+    base: Price,
+    offset: Decimal,
+    offset_type: TrailingOffsetType,
+    side: OrderSide,
+    tick_size: Option<Price>,
+) -> anyhow::Result<Price> {
+    anyhow::ensure!(
+        !offset.is_sign_negative(),
+        "Invalid `offset`: must not be negative, was {offset}"
+    );
+    let price = offset_price(base, offset, offset_type, side, tick_size)?;
+    Ok(decimal_to_price(price, base.precision)?)
+}
+
+/// Returns the `base` price moved by the `offset` for the `side`, as per
+/// [`apply_trailing_offset`] before rounding.
+fn offset_price(
+    base: Price,
+    offset: Decimal,
+    offset_type: TrailingOffsetType,
+    side: OrderSide,
+    tick_size: Option<Price>,
+) -> Result<Decimal, OrderError> {
+    let amount = match offset_type {
+        TrailingOffsetType::Price => offset,
+        TrailingOffsetType::BasisPoints => base.as_decimal() * offset / Decimal::from(10_000),
+        TrailingOffsetType::Ticks => match tick_size {
+            Some(tick_size) => offset * tick_size.as_decimal(),
+            None => return Err(OrderError::MissingTickSize),
+        },
+        TrailingOffsetType::PriceTier | TrailingOffsetType::NoTrailingOffset => {
+            return Err(OrderError::UnsupportedTrailingOffsetType(offset_type))
+        }
+    };
+
+    match side {
+        OrderSide::Buy => Ok(base.as_decimal() + amount),
+        OrderSide::Sell => Ok(base.as_decimal() - amount),
+        OrderSide::NoOrderSide => Err(OrderError::NoOrderSide),
+    }
+}
+
+/// Converts the `value` to a price rounded to the `precision`, without an `f64` round trip.
+fn decimal_to_price(value: Decimal, precision: u8) -> Result<Price, OrderError> {
+    let scale = Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION)));
+    value
+        .round_dp(u32::from(precision))
+        .checked_mul(scale)
+        .and_then(|raw| raw.to_i64())
+        .map(|raw| Price::from_raw(raw, precision))
+        .ok_or(OrderError::TrailedPriceOutOfRange(value))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::buy_price(OrderSide::Buy, TrailingOffsetType::Price, "1.25", "101.25")]
+    #[case::sell_price(OrderSide::Sell, TrailingOffsetType::Price, "1.25", "98.75")]
+    #[case::buy_basis_points(OrderSide::Buy, TrailingOffsetType::BasisPoints, "50", "100.50")]
+    #[case::sell_basis_points(OrderSide::Sell, TrailingOffsetType::BasisPoints, "50", "99.50")]
+    #[case::buy_ticks(OrderSide::Buy, TrailingOffsetType::Ticks, "3", "100.15")]
+    #[case::sell_ticks(OrderSide::Sell, TrailingOffsetType::Ticks, "3", "99.85")]
+    fn test_apply_trailing_offset(
+        #[case] side: OrderSide,
+        #[case] offset_type: TrailingOffsetType,
+        #[case] offset: &str,
+        #[case] expected: &str,
+    ) {
+        let price = apply_trailing_offset(
+            Price::from("100.00"),
+            Decimal::from_str(offset).unwrap(),
+            offset_type,
+            side,
+            Some(Price::from("0.05")),
+        )
+        .unwrap();
+
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_apply_trailing_offset_basis_points_relative_to_base() {
+        let price = apply_trailing_offset(
+            Price::from("2000.00"),
+            Decimal::from(50),
+            TrailingOffsetType::BasisPoints,
+            OrderSide::Sell,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(price, Price::from("1990.00"));
+    }
+
+    #[rstest]
+    #[case(
+        TrailingOffsetType::Ticks,
+        OrderSide::Buy,
+        "3",
+        "A `tick_size` is required"
+    )]
+    #[case(TrailingOffsetType::PriceTier, OrderSide::Buy, "3", "PRICE_TIER")]
+    #[case(
+        TrailingOffsetType::NoTrailingOffset,
+        OrderSide::Buy,
+        "3",
+        "NO_TRAILING_OFFSET"
+    )]
+    #[case(
+        TrailingOffsetType::Price,
+        OrderSide::NoOrderSide,
+        "3",
+        "must have a side"
+    )]
+    #[case(
+        TrailingOffsetType::Price,
+        OrderSide::Buy,
+        "-1",
+        "must not be negative"
+    )]
+    fn test_apply_trailing_offset_invalid(
+        #[case] offset_type: TrailingOffsetType,
+        #[case] side: OrderSide,
+        #[case] offset: &str,
+        #[case] expected: &str,
+    ) {
+        let result = apply_trailing_offset(
+            Price::from("100.00"),
+            Decimal::from_str(offset).unwrap(),
+            offset_type,
+            side,
+            None,
+        );
+
+        assert!(result.unwrap_err().to_string().contains(expected));
+    }
+
+    #[rstest]
+    #[case::buy_trails(OrderSideSpecified::Buy, "100.00", "101.00", Some("100.15"))]
+    #[case::buy_unfavorable(OrderSideSpecified::Buy, "100.00", "100.10", None)]
+    #[case::sell_trails(OrderSideSpecified::Sell, "100.00", "99.00", Some("99.85"))]
+    #[case::sell_unfavorable(OrderSideSpecified::Sell, "100.00", "99.90", None)]
+    fn test_calculate_trailing_price(
+        #[case] side: OrderSideSpecified,
+        #[case] reference: &str,
+        #[case] current: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let price = calculate_trailing_price(
+            side,
+            TrailingOffsetType::Ticks,
+            Price::from("3"),
+            Price::from(reference),
+            Price::from(current),
+            Price::from("0.05"),
+        )
+        .unwrap();
+
+        assert_eq!(price, expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case::rounds_up(OrderSideSpecified::Sell, "7", "99.95")]
+    #[case::rounds_down(OrderSideSpecified::Sell, "12", "99.90")]
+    #[case::buy_rounds_down(OrderSideSpecified::Buy, "27", "100.25")]
+    #[case::buy_exact(OrderSideSpecified::Buy, "5", "100.05")]
+    fn test_calculate_trailing_price_rounds_to_tick_size(
+        #[case] side: OrderSideSpecified,
+        #[case] basis_points: &str,
+        #[case] expected: &str,
+    ) {
+        let current = match side {
+            OrderSideSpecified::Buy => Price::from("101.00"),
+            OrderSideSpecified::Sell => Price::from("99.00"),
+        };
+
+        let price = calculate_trailing_price(
+            side,
+            TrailingOffsetType::BasisPoints,
+            Price::from(basis_points),
+            Price::from("100.00"),
+            current,
+            Price::from("0.05"),
+        )
+        .unwrap();
+
+        assert_eq!(price, Some(Price::from(expected)));
+    }
+}
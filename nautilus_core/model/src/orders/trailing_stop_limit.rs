@@ -19,23 +19,27 @@ use std::{
 };
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
     base::{Order, OrderCore, OrderError},
+    trailing::{calculate_trailing_price, trailing_reference},
 };
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
-        TrailingOffsetType, TriggerType,
+        ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
+        TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::{OrderEventAny, OrderInitialized, OrderUpdated},
     identifiers::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
+    instruments::any::InstrumentAny,
+    orderbook::book::OrderBook,
     types::{Price, Quantity},
 };
 
@@ -146,6 +150,233 @@ impl TrailingStopLimitOrder {
             ts_triggered: None,
         }
     }
+
+    /// Creates a new [`TrailingStopLimitOrder`] instance, validating it against the
+    /// `instrument` with [`OrderAny::validate`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `instrument_id` is not the ID of the `instrument`.
+    /// - If `quantity` is not a multiple of the instrument size increment.
+    /// - If `price` or `trigger_price` is not a multiple of the instrument price increment.
+    /// - If `price` is unmarketable once the stop triggers, see [`Self::check_limit_price`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        exec_spawn_id: Option<ClientOrderId>,
+        tags: Option<Vec<Ustr>>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Self> {
+        let order = OrderAny::TrailingStopLimit(Self::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+            init_id,
+            ts_init,
+        ));
+        order.validate(instrument)?;
+        Ok(order.into())
+    }
+
+    /// Checks the limit `price` is marketable once the stop triggers, see
+    /// [`OrderAny::validate`](crate::orders::any::OrderAny::validate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `price` is above `trigger_price` for a SELL, or below it for a
+    /// BUY, so the limit would be unmarketable as soon as the stop triggers.
+    pub fn check_limit_price(&self) -> anyhow::Result<()> {
+        let (price, trigger_price) = (self.price, self.trigger_price);
+        match self.side {
+            OrderSide::Sell if price > trigger_price => anyhow::bail!(
+                "Invalid SELL `price` {price}, should not be above `trigger_price` {trigger_price}"
+            ),
+            OrderSide::Buy if price < trigger_price => anyhow::bail!(
+                "Invalid BUY `price` {price}, should not be below `trigger_price` {trigger_price}"
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether the stop is triggered with a buffer of `buffer_ticks` beyond the
+    /// trigger price, measured against the depth of the `book` rather than the top level.
+    ///
+    /// The depth-weighted price of the side moving through the trigger (asks for a SELL
+    /// stop, bids for a BUY stop) over the order's leaves quantity must breach the buffer,
+    /// so a thin one-tick wick through the trigger price does not trigger the order.
+    #[must_use]
+    pub fn trigger_with_buffer(
+        &self,
+        book: &OrderBook,
+        buffer_ticks: u32,
+        instrument: &InstrumentAny,
+    ) -> bool {
+        let buffer = instrument.price_increment().as_f64() * f64::from(buffer_ticks);
+        let trigger_price = self.trigger_price.as_f64();
+
+        match self.side.as_specified() {
+            OrderSideSpecified::Buy => {
+                let avg_px = book.get_avg_px_for_quantity(self.leaves_qty, OrderSide::Sell);
+                avg_px > 0.0 && avg_px >= trigger_price + buffer
+            }
+            OrderSideSpecified::Sell => {
+                let avg_px = book.get_avg_px_for_quantity(self.leaves_qty, OrderSide::Buy);
+                avg_px > 0.0 && avg_px <= trigger_price - buffer
+            }
+        }
+    }
+
+    /// Returns the signed distance in price between the `last_price` and the trigger price.
+    ///
+    /// The distance is positive while the market is on the untriggered side of the stop
+    /// (above a SELL stop, below a BUY stop) and negative once it has moved through it.
+    #[must_use]
+    pub fn trigger_distance(&self, last_price: Price) -> Decimal {
+        let distance = last_price.as_decimal() - self.trigger_price.as_decimal();
+        match self.side.as_specified() {
+            OrderSideSpecified::Buy => -distance,
+            OrderSideSpecified::Sell => distance,
+        }
+    }
+
+    /// Returns the signed trigger distance as a fraction of the `last_price`, so stops on
+    /// instruments with different price levels can be compared.
+    ///
+    /// Returns zero if the `last_price` is zero.
+    #[must_use]
+    pub fn trigger_distance_fraction(&self, last_price: Price) -> Decimal {
+        let last = last_price.as_decimal();
+        if last.is_zero() {
+            return Decimal::ZERO;
+        }
+        self.trigger_distance(last_price) / last
+    }
+
+    /// Recalculates the trigger and limit prices from the current market and applies an
+    /// [`OrderUpdated`] event if either moves in the favorable direction.
+    ///
+    /// The `last_trade` price is used as the reference for last price trigger types when
+    /// available, otherwise the `market_price` is used. Once the order is triggered the
+    /// trigger price is fixed and only the limit price continues to trail.
+    ///
+    /// For [`TrailingOffsetType::Ticks`] the offsets are measured in ticks of the
+    /// instrument `price_increment`.
+    ///
+    /// Returns the applied event, or `None` if neither price moved.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the trailing offset cannot be applied, see [`super::trailing::apply_trailing_offset`].
+    /// - If the update is not a valid transition for the current order status.
+    pub fn update_trailing(
+        &mut self,
+        market_price: Price,
+        last_trade: Option<Price>,
+        price_increment: Price,
+        ts_event: UnixNanos,
+    ) -> Result<Option<OrderUpdated>, OrderError> {
+        let side = self.side.as_specified();
+        let reference = trailing_reference(self.trigger_type, market_price, last_trade);
+
+        let trigger_price = if self.is_triggered {
+            None
+        } else {
+            calculate_trailing_price(
+                side,
+                self.trailing_offset_type,
+                self.trailing_offset,
+                reference,
+                self.trigger_price,
+                price_increment,
+            )?
+        };
+        let price = calculate_trailing_price(
+            side,
+            self.trailing_offset_type,
+            self.limit_offset,
+            reference,
+            self.price,
+            price_increment,
+        )?;
+        if trigger_price.is_none() && price.is_none() {
+            return Ok(None);
+        }
+
+        let event = OrderUpdated::new(
+            self.trader_id,
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.quantity,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            false,
+            self.venue_order_id,
+            self.account_id,
+            price,
+            trigger_price,
+            None,
+            None,
+        );
+        self.apply(OrderEventAny::Updated(event))?;
+
+        Ok(Some(event))
+    }
 }
 
 impl Deref for TrailingStopLimitOrder {
@@ -357,16 +588,40 @@ impl Order for TrailingStopLimitOrder {
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
         if let OrderEventAny::Updated(ref event) = event {
+            let offsets = [
+                ("limit_offset", event.limit_offset),
+                ("trailing_offset", event.trailing_offset),
+            ];
+            for (name, offset) in offsets {
+                if let Some(offset) = offset.filter(|offset| offset.raw < 0) {
+                    return Err(OrderError::InvalidOffset(name, offset));
+                }
+            }
             self.update(event);
         };
-        let is_order_filled = matches!(event, OrderEventAny::Filled(_));
+        let is_order_filled = matches!(
+            event,
+            OrderEventAny::PartiallyFilled(_) | OrderEventAny::Filled(_)
+        );
+        let ts_triggered = match event {
+            OrderEventAny::Triggered(ref event) => Some(event.ts_event),
+            _ => None,
+        };
 
+        // A second trigger is rejected as an invalid state transition, leaving the
+        // original trigger time in place
         self.core.apply(event)?;
 
+        // Recalculated on every partial fill from the volume-weighted average fill price
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        }
+
         Ok(())
     }
 
@@ -379,6 +634,14 @@ impl Order for TrailingStopLimitOrder {
             self.trigger_price = trigger_price;
         }
 
+        if let Some(limit_offset) = event.limit_offset {
+            self.limit_offset = limit_offset;
+        }
+
+        if let Some(trailing_offset) = event.trailing_offset {
+            self.trailing_offset = trailing_offset;
+        }
+
         self.quantity = event.quantity;
         self.leaves_qty = self.quantity - self.filled_qty;
     }
@@ -442,3 +705,924 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
         )
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use ustr::Ustr;
+
+    use super::TrailingStopLimitOrder;
+    use crate::{
+        data::order::BookOrder,
+        enums::{
+            BookType, OrderSide, OrderStatus, OrderType, TimeInForce, TrailingOffsetType,
+            TriggerType,
+        },
+        events::{OrderEventAny, OrderFilled, OrderRejected, OrderTriggered, OrderUpdated},
+        identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId},
+        instruments::{any::InstrumentAny, stubs::*, Equity},
+        orderbook::book::OrderBook,
+        orders::{
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+            Order, OrderAny, OrderError, OrderTestBuilder,
+        },
+        types::{Price, Quantity},
+    };
+
+    fn sell_stop(instrument: &Equity) -> TrailingStopLimitOrder {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(instrument.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("99.90"))
+            .trigger_price(Price::from("100.00"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        TrailingStopLimitOrder::from(order)
+    }
+
+    fn book_with_asks(instrument: &Equity, asks: &[(&str, i64)]) -> OrderBook {
+        let mut book = OrderBook::new(instrument.id, BookType::L2_MBP);
+        for (i, (price, size)) in asks.iter().enumerate() {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(*price),
+                Quantity::from(*size),
+                i as u64,
+            );
+            book.add(order, 0, i as u64, 0.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_trigger_with_buffer_ignores_thin_wick(equity_aapl: Equity) {
+        let order = sell_stop(&equity_aapl);
+        let book = book_with_asks(&equity_aapl, &[("99.97", 1), ("100.05", 100)]);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert!(!order.trigger_with_buffer(&book, 2, &instrument));
+        assert!(!order.trigger_with_buffer(&book, 0, &instrument));
+    }
+
+    #[rstest]
+    fn test_trigger_with_buffer_on_sustained_breach(equity_aapl: Equity) {
+        let order = sell_stop(&equity_aapl);
+        let book = book_with_asks(&equity_aapl, &[("99.95", 100), ("99.96", 100)]);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert!(order.trigger_with_buffer(&book, 2, &instrument));
+    }
+
+    #[rstest]
+    fn test_trigger_with_buffer_breach_within_buffer(equity_aapl: Equity) {
+        let order = sell_stop(&equity_aapl);
+        let book = book_with_asks(&equity_aapl, &[("99.99", 100)]);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert!(order.trigger_with_buffer(&book, 0, &instrument));
+        assert!(!order.trigger_with_buffer(&book, 2, &instrument));
+    }
+
+    #[rstest]
+    fn test_trigger_with_buffer_empty_book(equity_aapl: Equity) {
+        let order = sell_stop(&equity_aapl);
+        let book = OrderBook::new(equity_aapl.id, BookType::L2_MBP);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert!(!order.trigger_with_buffer(&book, 2, &instrument));
+    }
+
+    #[rstest]
+    #[case::sell_far(OrderSide::Sell, "110.00", "10.00", "0.090909")]
+    #[case::sell_near(OrderSide::Sell, "100.05", "0.05", "0.000500")]
+    #[case::sell_through(OrderSide::Sell, "99.90", "-0.10", "-0.001001")]
+    #[case::buy_far(OrderSide::Buy, "90.00", "10.00", "0.111111")]
+    #[case::buy_near(OrderSide::Buy, "99.98", "0.02", "0.000200")]
+    #[case::buy_through(OrderSide::Buy, "100.50", "-0.50", "-0.004975")]
+    fn test_trigger_distance(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] last_price: &str,
+        #[case] expected_distance: &str,
+        #[case] expected_fraction: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(side)
+            .price(Price::from("100.00"))
+            .trigger_price(Price::from("100.00"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        let order = TrailingStopLimitOrder::from(order);
+        let last_price = Price::from(last_price);
+
+        assert_eq!(
+            order.trigger_distance(last_price),
+            Decimal::from_str(expected_distance).unwrap()
+        );
+        assert_eq!(
+            order.trigger_distance_fraction(last_price).round_dp(6),
+            Decimal::from_str(expected_fraction).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_trigger_distance_fraction_zero_last_price(equity_aapl: Equity) {
+        let order = sell_stop(&equity_aapl);
+
+        assert_eq!(
+            order.trigger_distance_fraction(Price::from("0.00")),
+            Decimal::ZERO
+        );
+    }
+
+    fn trailing_stop(
+        instrument: &Equity,
+        side: OrderSide,
+        offset_type: TrailingOffsetType,
+        trailing_offset: &str,
+        limit_offset: &str,
+    ) -> TrailingStopLimitOrder {
+        let (price, trigger_price) = match side {
+            OrderSide::Buy => ("101.10", "101.00"),
+            _ => ("98.90", "99.00"),
+        };
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(instrument.id)
+            .side(side)
+            .price(Price::from(price))
+            .trigger_price(Price::from(trigger_price))
+            .trigger_type(TriggerType::LastPrice)
+            .limit_offset(Price::from(limit_offset))
+            .trailing_offset(Price::from(trailing_offset))
+            .trailing_offset_type(offset_type)
+            .quantity(Quantity::from(10))
+            .build();
+        TrailingStopLimitOrder::from(TestOrderStubs::make_accepted_order(&order))
+    }
+
+    #[rstest]
+    #[case::sell_price(
+        OrderSide::Sell,
+        TrailingOffsetType::Price,
+        "1.00",
+        "1.10",
+        "102.00",
+        "101.00",
+        "100.90"
+    )]
+    #[case::sell_bps(
+        OrderSide::Sell,
+        TrailingOffsetType::BasisPoints,
+        "100",
+        "110",
+        "101.00",
+        "99.99",
+        "99.89"
+    )]
+    #[case::sell_ticks(
+        OrderSide::Sell,
+        TrailingOffsetType::Ticks,
+        "100",
+        "110",
+        "102.00",
+        "101.00",
+        "100.90"
+    )]
+    #[case::buy_price(
+        OrderSide::Buy,
+        TrailingOffsetType::Price,
+        "1.00",
+        "1.10",
+        "98.00",
+        "99.00",
+        "99.10"
+    )]
+    #[case::buy_bps(
+        OrderSide::Buy,
+        TrailingOffsetType::BasisPoints,
+        "100",
+        "110",
+        "99.00",
+        "99.99",
+        "100.09"
+    )]
+    #[case::buy_ticks(
+        OrderSide::Buy,
+        TrailingOffsetType::Ticks,
+        "100",
+        "110",
+        "98.00",
+        "99.00",
+        "99.10"
+    )]
+    fn test_update_trailing_moves_favorably(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] offset_type: TrailingOffsetType,
+        #[case] trailing_offset: &str,
+        #[case] limit_offset: &str,
+        #[case] market_price: &str,
+        #[case] expected_trigger: &str,
+        #[case] expected_price: &str,
+    ) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            side,
+            offset_type,
+            trailing_offset,
+            limit_offset,
+        );
+
+        let event = order
+            .update_trailing(
+                Price::from(market_price),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.trigger_price, Some(Price::from(expected_trigger)));
+        assert_eq!(event.price, Some(Price::from(expected_price)));
+        assert_eq!(order.trigger_price, Price::from(expected_trigger));
+        assert_eq!(order.price, Price::from(expected_price));
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.events().len(), 4);
+    }
+
+    #[rstest]
+    #[case::sell(OrderSide::Sell, "99.50")]
+    #[case::buy(OrderSide::Buy, "100.50")]
+    fn test_update_trailing_does_not_move_unfavorably(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] market_price: &str,
+    ) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            side,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+        let trigger_price = order.trigger_price;
+
+        let result = order
+            .update_trailing(
+                Price::from(market_price),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(order.trigger_price, trigger_price);
+        assert_eq!(order.events().len(), 3);
+    }
+
+    #[rstest]
+    fn test_update_trailing_uses_last_trade(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+
+        order
+            .update_trailing(
+                Price::from("101.00"),
+                Some(Price::from("103.00")),
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap();
+
+        assert_eq!(order.trigger_price, Price::from("102.00"));
+        assert_eq!(order.price, Price::from("101.90"));
+    }
+
+    #[rstest]
+    fn test_update_trailing_when_triggered_only_moves_limit(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+        order.is_triggered = true;
+
+        let event = order
+            .update_trailing(
+                Price::from("102.00"),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.trigger_price, None);
+        assert_eq!(order.trigger_price, Price::from("99.00"));
+        assert_eq!(order.price, Price::from("100.90"));
+    }
+
+    #[rstest]
+    fn test_update_trailing_unsupported_offset_type(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::PriceTier,
+            "1.00",
+            "1.10",
+        );
+
+        let result = order.update_trailing(
+            Price::from("102.00"),
+            None,
+            equity_aapl.price_increment,
+            1.into(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnsupportedTrailingOffsetType(
+                TrailingOffsetType::PriceTier
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::buy(OrderSide::Buy, "100.00", Some(0.80))]
+    #[case::sell(OrderSide::Sell, "101.20", Some(0.40))]
+    #[case::buy_favorable(OrderSide::Buy, "101.00", None)]
+    fn test_slippage_from_partial_fills(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: Option<f64>,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(side)
+            .price(Price::from(price))
+            .trigger_price(Price::from(price))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        for (i, (last_px, last_qty)) in [("100.50", 4), ("101.00", 6)].into_iter().enumerate() {
+            let fill = OrderFilled::from(TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(format!("T-{i}"))),
+                None,
+                Some(Price::from(last_px)),
+                Some(Quantity::from(last_qty)),
+                None,
+                None,
+                None,
+                None,
+            ));
+            let event = if i == 0 {
+                OrderEventAny::PartiallyFilled(fill)
+            } else {
+                OrderEventAny::Filled(fill)
+            };
+            order.apply(event).unwrap();
+        }
+        let order = TrailingStopLimitOrder::from(order);
+
+        // Volume-weighted fill price of (4 * 100.50 + 6 * 101.00) / 10
+        assert!((order.avg_px().unwrap() - 100.80).abs() < 1e-9);
+        match expected {
+            Some(expected) => assert!((order.slippage().unwrap() - expected).abs() < 1e-9),
+            None => assert_eq!(order.slippage(), None),
+        }
+        assert_eq!(order.status(), OrderStatus::Filled);
+    }
+
+    #[rstest]
+    fn test_avg_px_from_equal_partial_fills(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("100.50"))
+            .trigger_price(Price::from("100.50"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(9))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        let mut avg_pxs = Vec::new();
+        for (i, last_px) in ["100.00", "101.00", "102.00"].into_iter().enumerate() {
+            let fill = OrderFilled::from(TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(format!("T-{i}"))),
+                None,
+                Some(Price::from(last_px)),
+                Some(Quantity::from(3)),
+                None,
+                None,
+                None,
+                None,
+            ));
+            let event = if i < 2 {
+                OrderEventAny::PartiallyFilled(fill)
+            } else {
+                OrderEventAny::Filled(fill)
+            };
+            order.apply(event).unwrap();
+            avg_pxs.push(
+                TrailingStopLimitOrder::from(order.clone())
+                    .avg_px()
+                    .unwrap(),
+            );
+        }
+        let order = TrailingStopLimitOrder::from(order);
+
+        // The average is weighted over all fills, rather than taken from the last fill
+        assert_eq!(avg_pxs, vec![100.0, 100.5, 101.0]);
+        assert_eq!(order.avg_px(), Some(101.0));
+        assert!((order.slippage().unwrap() - 0.50).abs() < 1e-9);
+        assert_eq!(order.filled_qty(), Quantity::from(9));
+        assert_eq!(order.status(), OrderStatus::Filled);
+    }
+
+    #[rstest]
+    fn test_next_display_slice(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .trigger_price(Price::from("100.00"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(100))
+            .display_qty(Quantity::from(25))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert_eq!(
+            TrailingStopLimitOrder::from(order.clone()).next_display_slice(),
+            Some(Quantity::from(25))
+        );
+
+        // Displayed slice fills and is replenished from the hidden quantity
+        for (i, (last_qty, expected)) in [(25, 25), (65, 10)].into_iter().enumerate() {
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(format!("T-{i}"))),
+                None,
+                Some(Price::from("100.00")),
+                Some(Quantity::from(last_qty)),
+                None,
+                None,
+                None,
+                None,
+            );
+            order.apply(fill).unwrap();
+            assert_eq!(
+                TrailingStopLimitOrder::from(order.clone()).next_display_slice(),
+                Some(Quantity::from(expected))
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_next_display_slice_without_display_qty(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("100.00"))
+            .trigger_price(Price::from("100.00"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(100))
+            .build();
+        let order = TrailingStopLimitOrder::from(order);
+
+        assert_eq!(order.next_display_slice(), None);
+    }
+
+    fn offsets_updated(
+        order: &TrailingStopLimitOrder,
+        limit_offset: Option<&str>,
+        trailing_offset: Option<&str>,
+    ) -> OrderEventAny {
+        OrderEventAny::Updated(OrderUpdated::new(
+            order.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            order.quantity,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            order.venue_order_id,
+            order.account_id,
+            None,
+            None,
+            limit_offset.map(Price::from),
+            trailing_offset.map(Price::from),
+        ))
+    }
+
+    #[rstest]
+    fn test_update_applies_offsets(equity_aapl: Equity) {
+        let mut order = sell_stop(&equity_aapl);
+
+        order
+            .apply(offsets_updated(&order, Some("0.20"), Some("1.50")))
+            .unwrap();
+
+        assert_eq!(order.limit_offset(), Some(Price::from("0.20")));
+        assert_eq!(order.trailing_offset(), Some(Price::from("1.50")));
+        assert_eq!(order.price(), Some(Price::from("99.90")));
+        assert_eq!(order.trigger_price(), Some(Price::from("100.00")));
+    }
+
+    #[rstest]
+    fn test_update_without_offsets_keeps_offsets(equity_aapl: Equity) {
+        let mut order = sell_stop(&equity_aapl);
+
+        order.apply(offsets_updated(&order, None, None)).unwrap();
+
+        assert_eq!(order.limit_offset(), Some(Price::from("0.10")));
+        assert_eq!(order.trailing_offset(), Some(Price::from("1.00")));
+    }
+
+    #[rstest]
+    #[case(Some("-0.10"), None, "limit_offset")]
+    #[case(None, Some("-1.00"), "trailing_offset")]
+    fn test_update_rejects_negative_offsets(
+        equity_aapl: Equity,
+        #[case] limit_offset: Option<&str>,
+        #[case] trailing_offset: Option<&str>,
+        #[case] name: &str,
+    ) {
+        let mut order = sell_stop(&equity_aapl);
+        let event_count = order.event_count();
+
+        let result = order.apply(offsets_updated(&order, limit_offset, trailing_offset));
+
+        assert!(matches!(result, Err(OrderError::InvalidOffset(field, _)) if field == name));
+        assert_eq!(order.limit_offset(), Some(Price::from("0.10")));
+        assert_eq!(order.trailing_offset(), Some(Price::from("1.00")));
+        assert_eq!(order.event_count(), event_count);
+    }
+
+    fn triggered(order: &TrailingStopLimitOrder, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Triggered(OrderTriggered::new(
+            order.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            UUID4::new(),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+            false,
+            order.venue_order_id,
+            order.account_id,
+        ))
+    }
+
+    #[rstest]
+    fn test_apply_triggered(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+        assert!(!order.is_triggered);
+        assert_eq!(order.ts_triggered, None);
+
+        order.apply(triggered(&order, 1_000)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Triggered);
+        assert!(order.is_triggered);
+        assert_eq!(order.ts_triggered, Some(UnixNanos::from(1_000)));
+    }
+
+    #[rstest]
+    fn test_apply_triggered_twice_is_rejected(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+        order.apply(triggered(&order, 1_000)).unwrap();
+        let event_count = order.event_count();
+
+        let result = order.apply(triggered(&order, 2_000));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert!(order.is_triggered);
+        assert_eq!(order.ts_triggered, Some(UnixNanos::from(1_000)));
+        assert_eq!(order.event_count(), event_count);
+    }
+
+    #[rstest]
+    fn test_apply_post_only_rejected(equity_aapl: Equity) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(equity_aapl.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("99.90"))
+            .trigger_price(Price::from("100.00"))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .post_only(true)
+            .quantity(Quantity::from(10))
+            .build();
+        let account_id = AccountId::from("SIM-001");
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id);
+        let mut order = TrailingStopLimitOrder::from(order);
+        order.apply(submitted).unwrap();
+        assert_eq!(order.rejected_reason(), None);
+
+        let rejected = OrderRejected::new(
+            order.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            account_id,
+            Ustr::from("POST_ONLY_WOULD_CROSS"),
+            UUID4::new(),
+            UnixNanos::from(1_000),
+            UnixNanos::from(1_000),
+            false,
+        );
+        order.apply(OrderEventAny::Rejected(rejected)).unwrap();
+
+        assert!(order.is_post_only());
+        assert_eq!(order.status(), OrderStatus::Rejected);
+        assert!(order.is_closed());
+        assert_eq!(
+            order.rejected_reason(),
+            Some(Ustr::from("POST_ONLY_WOULD_CROSS"))
+        );
+    }
+
+    fn validated(
+        instrument: &Equity,
+        side: OrderSide,
+        price: &str,
+        trigger_price: &str,
+    ) -> anyhow::Result<()> {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(instrument.id)
+            .side(side)
+            .price(Price::from(price))
+            .trigger_price(Price::from(trigger_price))
+            .limit_offset(Price::from("0.10"))
+            .trailing_offset(Price::from("1.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        order.validate(&InstrumentAny::Equity(*instrument))
+    }
+
+    #[rstest]
+    #[case::sell(OrderSide::Sell, "99.90", "100.00")]
+    #[case::buy(OrderSide::Buy, "100.10", "100.00")]
+    #[case::equal(OrderSide::Sell, "100.00", "100.00")]
+    fn test_validate(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] trigger_price: &str,
+    ) {
+        assert!(validated(&equity_aapl, side, price, trigger_price).is_ok());
+    }
+
+    #[rstest]
+    #[case::price("99.905", "100.00")]
+    #[case::trigger_price("99.90", "100.001")]
+    fn test_validate_tick_misalignment(
+        equity_aapl: Equity,
+        #[case] price: &str,
+        #[case] trigger_price: &str,
+    ) {
+        let err = validated(&equity_aapl, OrderSide::Sell, price, trigger_price).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("should be a multiple of the price increment 0.01"));
+    }
+
+    #[rstest]
+    #[case::sell(OrderSide::Sell, "100.10", "100.00", "should not be above")]
+    #[case::buy(OrderSide::Buy, "99.90", "100.00", "should not be below")]
+    fn test_validate_inverted_trigger_and_price(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] trigger_price: &str,
+        #[case] expected: &str,
+    ) {
+        let err = validated(&equity_aapl, side, price, trigger_price).unwrap_err();
+
+        assert!(err.to_string().contains(expected));
+    }
+
+    fn new_checked(
+        instrument: &Equity,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: &str,
+        trigger_price: &str,
+    ) -> anyhow::Result<TrailingStopLimitOrder> {
+        TrailingStopLimitOrder::new_checked(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("S-001"),
+            instrument_id,
+            ClientOrderId::from("O-1"),
+            side,
+            Quantity::from(10),
+            Price::from(price),
+            Price::from(trigger_price),
+            TriggerType::Default,
+            Price::from("0.10"),
+            Price::from("1.00"),
+            TrailingOffsetType::Price,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            &InstrumentAny::Equity(*instrument),
+        )
+    }
+
+    #[rstest]
+    fn test_new_checked(equity_aapl: Equity) {
+        let order = new_checked(
+            &equity_aapl,
+            equity_aapl.id,
+            OrderSide::Sell,
+            "99.90",
+            "100.00",
+        )
+        .unwrap();
+
+        assert_eq!(order.price, Price::from("99.90"));
+        assert_eq!(order.trigger_price, Price::from("100.00"));
+    }
+
+    #[rstest]
+    #[case::wrong_instrument("AUD/USD.SIM", OrderSide::Sell, "99.90", "instrument_id")]
+    #[case::tick_misalignment("AAPL.XNAS", OrderSide::Sell, "99.905", "price increment")]
+    #[case::inverted("AAPL.XNAS", OrderSide::Buy, "99.90", "should not be below")]
+    fn test_new_checked_invalid(
+        equity_aapl: Equity,
+        #[case] instrument_id: &str,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let err = new_checked(
+            &equity_aapl,
+            InstrumentId::from(instrument_id),
+            side,
+            price,
+            "100.00",
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains(expected));
+    }
+
+    #[rstest]
+    fn test_snapshot_restore_equals_full_replay(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+            "1.10",
+        );
+        order
+            .update_trailing(
+                Price::from("101.00"),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap();
+
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let mut order = OrderAny::TrailingStopLimit(order);
+        let fills = [
+            ("E-1", "99.95", 4),
+            ("E-2", "99.90", 3),
+            ("E-3", "99.85", 3),
+        ];
+        let mut snapshot = None;
+        for (i, (trade_id, last_px, last_qty)) in fills.into_iter().enumerate() {
+            let fill: OrderFilled = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(trade_id)),
+                None,
+                Some(Price::from(last_px)),
+                Some(Quantity::from(last_qty)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .into();
+            let fill = if i < fills.len() - 1 {
+                OrderEventAny::PartiallyFilled(fill)
+            } else {
+                OrderEventAny::Filled(fill)
+            };
+            order.apply(fill).unwrap();
+            if i == 0 {
+                snapshot = Some(order.snapshot().unwrap());
+            }
+        }
+
+        let events: Vec<OrderEventAny> = order.events().into_iter().cloned().collect();
+        let snapshot_event_count = events.len() - 2;
+        let replayed = OrderAny::from_events(events.clone()).unwrap();
+        let restored =
+            OrderAny::from_snapshot(&snapshot.unwrap(), events[snapshot_event_count..].to_vec())
+                .unwrap();
+        assert_eq!(restored.snapshot().unwrap(), replayed.snapshot().unwrap());
+
+        let restored = TrailingStopLimitOrder::from(restored);
+        let replayed = TrailingStopLimitOrder::from(replayed);
+        assert_eq!(restored.status(), OrderStatus::Filled);
+        assert_eq!(restored.filled_qty(), replayed.filled_qty());
+        assert_eq!(restored.leaves_qty(), replayed.leaves_qty());
+        assert_eq!(restored.avg_px(), replayed.avg_px());
+        assert_eq!(restored.trigger_price, Price::from("100.00"));
+        assert_eq!(restored.price, replayed.price);
+        assert_eq!(restored.trade_ids(), replayed.trade_ids());
+        assert_eq!(restored.last_event(), replayed.last_event());
+        assert_eq!(restored.event_count(), 4);
+        assert_eq!(replayed.event_count(), events.len());
+
+        // The restored state matches the original order, which kept its full history
+        let order = TrailingStopLimitOrder::from(order);
+        assert_eq!(restored.status(), order.status());
+        assert_eq!(restored.filled_qty(), order.filled_qty());
+        assert_eq!(restored.leaves_qty(), order.leaves_qty());
+        assert_eq!(restored.avg_px(), order.avg_px());
+        assert_eq!(restored.trigger_price, order.trigger_price);
+        assert_eq!(restored.price, order.price);
+        assert_eq!(restored.trade_ids(), order.trade_ids());
+        assert_eq!(restored.venue_order_ids(), order.venue_order_ids());
+        assert_eq!(restored.last_event(), order.last_event());
+
+        // Only the events retained by the snapshot and applied after it are kept
+        assert!(restored.event_count() < order.event_count());
+        assert_eq!(restored.events()[0], order.events()[0]);
+        assert_eq!(
+            restored.events()[1..],
+            order.events()[order.event_count() - 3..]
+        );
+    }
+}
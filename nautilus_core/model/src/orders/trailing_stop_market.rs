@@ -25,6 +25,7 @@ use ustr::Ustr;
 use super::{
     any::OrderAny,
     base::{Order, OrderCore},
+    trailing::{calculate_trailing_price, trailing_reference},
 };
 use crate::{
     enums::{
@@ -138,6 +139,66 @@ impl TrailingStopMarketOrder {
             ts_triggered: None,
         }
     }
+
+    /// Recalculates the trigger price from the current market and applies an
+    /// [`OrderUpdated`] event if it moves in the favorable direction.
+    ///
+    /// The `last_trade` price is used as the reference for last price trigger types when
+    /// available, otherwise the `market_price` is used. Once the order is triggered the
+    /// trigger price is fixed.
+    ///
+    /// Returns the applied event, or `None` if the trigger price did not move.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the trailing offset cannot be applied, see [`super::trailing::apply_trailing_offset`].
+    /// - If the update is not a valid transition for the current order status.
+    pub fn update_trailing(
+        &mut self,
+        market_price: Price,
+        last_trade: Option<Price>,
+        price_increment: Price,
+        ts_event: UnixNanos,
+    ) -> Result<Option<OrderUpdated>, OrderError> {
+        if self.is_triggered {
+            return Ok(None);
+        }
+
+        let reference = trailing_reference(self.trigger_type, market_price, last_trade);
+        let Some(trigger_price) = calculate_trailing_price(
+            self.side.as_specified(),
+            self.trailing_offset_type,
+            self.trailing_offset,
+            reference,
+            self.trigger_price,
+            price_increment,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let event = OrderUpdated::new(
+            self.trader_id,
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.quantity,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            false,
+            self.venue_order_id,
+            self.account_id,
+            None,
+            Some(trigger_price),
+            None,
+            None,
+        );
+        self.apply(OrderEventAny::Updated(event))?;
+
+        Ok(Some(event))
+    }
 }
 
 impl Deref for TrailingStopMarketOrder {
@@ -427,3 +488,186 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
         )
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::TrailingStopMarketOrder;
+    use crate::{
+        enums::{OrderSide, OrderStatus, OrderType, TrailingOffsetType, TriggerType},
+        instruments::{stubs::*, Equity},
+        orders::{stubs::TestOrderStubs, Order, OrderAny, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+
+    fn trailing_stop(
+        instrument: &Equity,
+        side: OrderSide,
+        offset_type: TrailingOffsetType,
+        trailing_offset: &str,
+    ) -> TrailingStopMarketOrder {
+        let trigger_price = match side {
+            OrderSide::Buy => "101.00",
+            _ => "99.00",
+        };
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(instrument.id)
+            .side(side)
+            .trigger_price(Price::from(trigger_price))
+            .trigger_type(TriggerType::LastPrice)
+            .trailing_offset(Price::from(trailing_offset))
+            .trailing_offset_type(offset_type)
+            .quantity(Quantity::from(10))
+            .build();
+        TrailingStopMarketOrder::from(TestOrderStubs::make_accepted_order(&order))
+    }
+
+    #[rstest]
+    fn test_initialize(equity_aapl: Equity) {
+        let order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+        );
+
+        assert_eq!(order.order_type(), OrderType::TrailingStopMarket);
+        assert_eq!(order.price(), None);
+        assert_eq!(order.limit_offset(), None);
+        assert_eq!(order.trigger_price(), Some(Price::from("99.00")));
+        assert_eq!(order.trailing_offset(), Some(Price::from("1.00")));
+        assert!(matches!(order.into_any(), OrderAny::TrailingStopMarket(_)));
+    }
+
+    #[rstest]
+    #[case::sell_price(OrderSide::Sell, TrailingOffsetType::Price, "1.00", "102.00", "101.00")]
+    #[case::sell_bps(
+        OrderSide::Sell,
+        TrailingOffsetType::BasisPoints,
+        "100",
+        "101.00",
+        "99.99"
+    )]
+    #[case::sell_ticks(OrderSide::Sell, TrailingOffsetType::Ticks, "50", "100.00", "99.50")]
+    #[case::buy_price(OrderSide::Buy, TrailingOffsetType::Price, "1.00", "98.00", "99.00")]
+    #[case::buy_ticks(OrderSide::Buy, TrailingOffsetType::Ticks, "50", "100.00", "100.50")]
+    fn test_update_trailing_moves_favorably(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] offset_type: TrailingOffsetType,
+        #[case] trailing_offset: &str,
+        #[case] market_price: &str,
+        #[case] expected_trigger: &str,
+    ) {
+        let mut order = trailing_stop(&equity_aapl, side, offset_type, trailing_offset);
+
+        let event = order
+            .update_trailing(
+                Price::from(market_price),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.trigger_price, Some(Price::from(expected_trigger)));
+        assert_eq!(event.price, None);
+        assert_eq!(order.trigger_price, Price::from(expected_trigger));
+        assert_eq!(order.price(), None);
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.events().len(), 4);
+    }
+
+    #[rstest]
+    #[case::sell(OrderSide::Sell, "99.50")]
+    #[case::buy(OrderSide::Buy, "100.50")]
+    fn test_update_trailing_does_not_move_unfavorably(
+        equity_aapl: Equity,
+        #[case] side: OrderSide,
+        #[case] market_price: &str,
+    ) {
+        let mut order = trailing_stop(&equity_aapl, side, TrailingOffsetType::Price, "1.00");
+        let trigger_price = order.trigger_price;
+
+        let result = order
+            .update_trailing(
+                Price::from(market_price),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(order.trigger_price, trigger_price);
+        assert_eq!(order.events().len(), 3);
+    }
+
+    #[rstest]
+    fn test_update_trailing_uses_last_trade(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+        );
+
+        let event = order
+            .update_trailing(
+                Price::from("102.00"),
+                Some(Price::from("101.50")),
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.trigger_price, Some(Price::from("100.50")));
+    }
+
+    #[rstest]
+    fn test_update_trailing_ticks_use_price_increment(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Ticks,
+            "3",
+        );
+
+        let event = order
+            .update_trailing(Price::from("100.00"), None, Price::from("0.05"), 1.into())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.trigger_price, Some(Price::from("99.85")));
+        assert_eq!(order.trigger_price, Price::from("99.85"));
+    }
+
+    #[rstest]
+    fn test_update_trailing_frozen_once_triggered(equity_aapl: Equity) {
+        let mut order = trailing_stop(
+            &equity_aapl,
+            OrderSide::Sell,
+            TrailingOffsetType::Price,
+            "1.00",
+        );
+        order.is_triggered = true;
+
+        let result = order
+            .update_trailing(
+                Price::from("102.00"),
+                None,
+                equity_aapl.price_increment,
+                1.into(),
+            )
+            .unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(order.trigger_price, Price::from("99.00"));
+    }
+}
@@ -32,9 +32,20 @@ use crate::{
         Venue, VenueOrderId,
     },
     instruments::InstrumentAny,
+    orders::OrderAny,
     types::{Currency, Money, Price, Quantity},
 };
 
+#[derive(thiserror::Error, Debug)]
+pub enum PositionError {
+    #[error("Instrument mismatch: position for {0}, fill for {1}")]
+    InstrumentMismatch(InstrumentId, InstrumentId),
+    #[error("Order mismatch: fill for {1}, not order {0}")]
+    OrderMismatch(ClientOrderId, ClientOrderId),
+    #[error("Duplicate trade ID: {0}")]
+    DuplicateTradeId(TradeId),
+}
+
 /// Represents a position in a market.
 ///
 /// The position ID may be assigned at the trading venue, or can be system
@@ -205,6 +216,79 @@ impl Position {
         self.ts_last = fill.ts_event;
     }
 
+    /// Applies the `fill` of the `order` to the position, updating the quantity, average
+    /// entry price and realized PnL.
+    ///
+    /// A fill on the opposite side which exceeds the position quantity flips the position,
+    /// the fill is split into a closing fill and an opening fill for the remainder (with the
+    /// commission split pro rata). As for any position which is closed and reopened, the
+    /// opening fill then starts a new cycle with its own realized PnL.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `order` or `fill` is not for the position instrument.
+    /// - If the `fill` is not for the `order`.
+    /// - If the `fill` trade ID was already applied.
+    pub fn apply_order_fill(
+        &mut self,
+        order: &OrderAny,
+        fill: &OrderFilled,
+    ) -> Result<(), PositionError> {
+        for instrument_id in [order.instrument_id(), fill.instrument_id] {
+            if instrument_id != self.instrument_id {
+                return Err(PositionError::InstrumentMismatch(
+                    self.instrument_id,
+                    instrument_id,
+                ));
+            }
+        }
+        if order.client_order_id() != fill.client_order_id {
+            return Err(PositionError::OrderMismatch(
+                order.client_order_id(),
+                fill.client_order_id,
+            ));
+        }
+        if self.trade_ids.contains(&fill.trade_id) {
+            return Err(PositionError::DuplicateTradeId(fill.trade_id));
+        }
+
+        if self.side == PositionSide::Flat
+            || !self.is_opposite_side(fill.order_side)
+            || fill.last_qty <= self.quantity
+        {
+            self.apply(fill);
+            return Ok(());
+        }
+
+        // Split the fill to close the position then open on the opposite side
+        let close_qty = self.quantity;
+        let open_qty = fill.last_qty - close_qty;
+        let (close_commission, open_commission) = match fill.commission {
+            Some(commission) => {
+                let ratio = close_qty.as_f64() / fill.last_qty.as_f64();
+                let close_commission = Money::new(commission.as_f64() * ratio, commission.currency);
+                (Some(close_commission), Some(commission - close_commission))
+            }
+            None => (None, None),
+        };
+
+        self.apply(&OrderFilled {
+            last_qty: close_qty,
+            commission: close_commission,
+            ..*fill
+        });
+
+        // The flat position is reset by the opening fill, which shares the trade ID
+        self.trade_ids.clear();
+        self.apply(&OrderFilled {
+            last_qty: open_qty,
+            commission: open_commission,
+            ..*fill
+        });
+        Ok(())
+    }
+
     pub fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
         // Handle case where commission could be None or not settlement currency
         let mut realized_pnl = if let Some(commission) = fill.commission {
@@ -345,6 +429,42 @@ impl Position {
         Money::new(pnl_raw, self.settlement_currency)
     }
 
+    /// Returns the mark-to-market PnL of the open quantity at the `last` price, in the
+    /// settlement currency, valued with the `instrument` multiplier (or its inverse for
+    /// inverse instruments).
+    ///
+    /// The PnL is positive for a long position when `last` is above the average open price,
+    /// and for a short position when below. It equals [`Self::unrealized_pnl`] unless the
+    /// instrument multiplier changed since the position was opened.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `instrument` is not the instrument of the position.
+    pub fn calculate_unrealized_pnl(
+        &self,
+        last: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        anyhow::ensure!(
+            instrument.id() == self.instrument_id,
+            "Instrument {} is not the position instrument {}",
+            instrument.id(),
+            self.instrument_id
+        );
+        if self.side == PositionSide::Flat {
+            return Ok(Money::new(0.0, self.settlement_currency));
+        }
+
+        let quantity = self.quantity.as_f64().min(self.signed_qty.abs());
+        let points = if instrument.is_inverse() {
+            self.calculate_points_inverse(self.avg_px_open, last.as_f64())
+        } else {
+            self.calculate_points(self.avg_px_open, last.as_f64())
+        };
+        let pnl = quantity * instrument.multiplier().as_f64() * points;
+        Ok(Money::new(pnl, self.settlement_currency))
+    }
+
     #[must_use]
     pub fn unrealized_pnl(&self, last: Price) -> Money {
         if self.side == PositionSide::Flat {
@@ -532,9 +652,11 @@ mod tests {
         enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
         events::OrderFilled,
         identifiers::{stubs::uuid4, AccountId, PositionId, StrategyId, TradeId, VenueOrderId},
-        instruments::{stubs::*, CryptoPerpetual, CurrencyPair, InstrumentAny},
+        instruments::{
+            stubs::*, CryptoPerpetual, CurrencyPair, Equity, FuturesContract, InstrumentAny,
+        },
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
-        position::Position,
+        position::{Position, PositionError},
         stubs::*,
         types::{Money, Price, Quantity},
     };
@@ -1945,4 +2067,173 @@ mod tests {
         let position = Position::new(&audusd_sim, fill);
         assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
     }
+
+    #[rstest]
+    fn test_apply_order_fill_open_increase_reduce_flip(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let fill_order = |side: OrderSide, qty: i64, px: &str, trade_id: &str| {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(side)
+                .quantity(Quantity::from(qty))
+                .build();
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                Some(TradeId::new(trade_id)),
+                Some(PositionId::new("P-1")),
+                Some(Price::from(px)),
+                None,
+                None,
+                Some(Money::from("0 USD")),
+                None,
+                None,
+            );
+            (order, OrderFilled::from(fill))
+        };
+
+        // Open
+        let (_, fill) = fill_order(OrderSide::Buy, 100, "100.00", "1");
+        let mut position = Position::new(&instrument, fill);
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from(100));
+        assert_eq!(position.avg_px_open, 100.0);
+
+        // Increase
+        let (order, fill) = fill_order(OrderSide::Buy, 50, "106.00", "2");
+        position.apply_order_fill(&order, &fill).unwrap();
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from(150));
+        assert_eq!(position.avg_px_open, 102.0);
+        assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
+
+        // Reduce
+        let (order, fill) = fill_order(OrderSide::Sell, 75, "110.00", "3");
+        position.apply_order_fill(&order, &fill).unwrap();
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from(75));
+        assert_eq!(position.avg_px_open, 102.0);
+        assert_eq!(position.realized_pnl, Some(Money::from("600 USD")));
+
+        // Flip, closing 75 and opening short 50 in a new cycle
+        let (order, fill) = fill_order(OrderSide::Sell, 125, "104.00", "4");
+        position.apply_order_fill(&order, &fill).unwrap();
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.quantity, Quantity::from(50));
+        assert_eq!(position.signed_qty, -50.0);
+        assert_eq!(position.avg_px_open, 104.0);
+        assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
+        assert_eq!(position.trade_ids, vec![TradeId::new("4")]);
+    }
+
+    #[rstest]
+    fn test_apply_order_fill_instrument_mismatch(equity_aapl: Equity, audusd_sim: CurrencyPair) {
+        let equity_aapl = InstrumentAny::Equity(equity_aapl);
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order1 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(equity_aapl.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100))
+            .build();
+        let fill1 = TestOrderEventStubs::order_filled(
+            &order1,
+            &equity_aapl,
+            None,
+            Some(PositionId::new("P-1")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(&equity_aapl, fill1.into());
+        let order2 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill2 = TestOrderEventStubs::order_filled(
+            &order2,
+            &audusd_sim,
+            None,
+            Some(PositionId::new("P-1")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let result = position.apply_order_fill(&order2, &fill2.into());
+
+        assert!(matches!(
+            result,
+            Err(PositionError::InstrumentMismatch(position_instrument_id, fill_instrument_id))
+                if position_instrument_id == equity_aapl.id()
+                    && fill_instrument_id == audusd_sim.id()
+        ));
+        assert_eq!(position.quantity, Quantity::from(100));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "4510.00", "1000.00 USD")]
+    #[case(OrderSide::Buy, "4490.00", "-1000.00 USD")]
+    #[case(OrderSide::Sell, "4490.00", "1000.00 USD")]
+    #[case(OrderSide::Sell, "4510.00", "-1000.00 USD")]
+    fn test_unrealized_pnl_scaled_by_multiplier(
+        #[case] side: OrderSide,
+        #[case] last: &str,
+        #[case] expected: &str,
+    ) {
+        let es = InstrumentAny::FuturesContract(FuturesContract {
+            multiplier: Quantity::from(50),
+            ..futures_contract_es(None, None)
+        });
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(es.id())
+            .side(side)
+            .quantity(Quantity::from(2))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &es,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("4500.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&es, fill.into());
+
+        let pnl = position
+            .calculate_unrealized_pnl(Price::from(last), &es)
+            .unwrap();
+
+        assert_eq!(pnl, Money::from(expected));
+        assert_eq!(pnl, position.unrealized_pnl(Price::from(last)));
+    }
+
+    #[rstest]
+    fn test_calculate_unrealized_pnl_with_other_instrument(
+        stub_position_long: Position,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let result = stub_position_long.calculate_unrealized_pnl(
+            Price::from("10500.0"),
+            &InstrumentAny::CurrencyPair(currency_pair_btcusdt),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Instrument {} is not the position instrument {}",
+                currency_pair_btcusdt.id, stub_position_long.instrument_id
+            )
+        );
+    }
 }
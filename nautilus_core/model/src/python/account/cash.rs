@@ -136,8 +136,8 @@ impl CashAccount {
     }
 
     #[pyo3(name = "apply")]
-    fn py_apply(&mut self, event: AccountState) {
-        self.apply(event);
+    fn py_apply(&mut self, event: AccountState) -> PyResult<()> {
+        self.apply(event).map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "calculate_balance_locked")]
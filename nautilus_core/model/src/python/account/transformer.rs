@@ -37,7 +37,7 @@ pub fn cash_account_from_account_events(
     let init_event = account_events[0].clone();
     let mut cash_account = CashAccount::new(init_event, calculate_account_state);
     for event in account_events.iter().skip(1) {
-        cash_account.apply(event.clone());
+        cash_account.apply(event.clone()).map_err(to_pyvalue_err)?;
     }
     Ok(cash_account)
 }
@@ -58,7 +58,9 @@ pub fn margin_account_from_account_events(
     let init_event = account_events[0].clone();
     let mut margin_account = MarginAccount::new(init_event, calculate_account_state);
     for event in account_events.iter().skip(1) {
-        margin_account.apply(event.clone());
+        margin_account
+            .apply(event.clone())
+            .map_err(to_pyvalue_err)?;
     }
     Ok(margin_account)
 }
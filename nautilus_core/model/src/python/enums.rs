@@ -23,10 +23,10 @@ use pyo3::{prelude::*, types::PyType, PyTypeInfo};
 use crate::{
     enums::{
         AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
-        BookType, ContingencyType, CurrencyType, InstrumentClass, InstrumentCloseType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide,
-        OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce, TradingState,
-        TrailingOffsetType, TriggerType,
+        BookType, CancellationReason, ContingencyType, CurrencyType, InstrumentClass,
+        InstrumentCloseType, LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OptionKind,
+        OrderSide, OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce,
+        TradingState, TrailingOffsetType, TriggerType,
     },
     python::common::EnumIterator,
 };
@@ -651,6 +651,87 @@ impl BookAction {
     }
 }
 
+#[pymethods]
+impl CancellationReason {
+    #[new]
+    fn py_new(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let t = Self::type_object_bound(py);
+        Self::py_from_str(&t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(CancellationReason),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &Bound<'_, PyType>, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &Bound<'_, PyType>, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let data_str: &str = data.extract()?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "USER")]
+    fn py_user() -> Self {
+        Self::User
+    }
+
+    #[classattr]
+    #[pyo3(name = "EXPIRED")]
+    fn py_expired() -> Self {
+        Self::Expired
+    }
+
+    #[classattr]
+    #[pyo3(name = "OCO_TRIGGERED")]
+    fn py_oco_triggered() -> Self {
+        Self::OcoTriggered
+    }
+
+    #[classattr]
+    #[pyo3(name = "RISK_REJECTED")]
+    fn py_risk_rejected() -> Self {
+        Self::RiskRejected
+    }
+
+    #[classattr]
+    #[pyo3(name = "VENUE")]
+    fn py_venue() -> Self {
+        Self::Venue
+    }
+}
+
 #[pymethods]
 impl ContingencyType {
     #[new]
@@ -17,6 +17,7 @@ use nautilus_core::{python::serialization::from_dict_pyo3, uuid::UUID4};
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 
 use crate::{
+    enums::CancellationReason,
     events::OrderCanceled,
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
 };
@@ -25,7 +26,7 @@ use crate::{
 impl OrderCanceled {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, cancellation_reason=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -37,6 +38,7 @@ impl OrderCanceled {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        cancellation_reason: Option<CancellationReason>,
     ) -> Self {
         Self::new(
             trader_id,
@@ -49,6 +51,7 @@ impl OrderCanceled {
             reconciliation,
             venue_order_id,
             account_id,
+            cancellation_reason,
         )
     }
 
@@ -94,6 +97,10 @@ impl OrderCanceled {
             Some(account_id) => dict.set_item("account_id", account_id.to_string())?,
             None => dict.set_item("account_id", py.None())?,
         }
+        match self.cancellation_reason {
+            Some(reason) => dict.set_item("cancellation_reason", reason.to_string())?,
+            None => dict.set_item("cancellation_reason", py.None())?,
+        }
         Ok(dict.into())
     }
 }
@@ -26,7 +26,7 @@ use crate::{
 impl OrderUpdated {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, quantity, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, price=None, trigger_price=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, quantity, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, price=None, trigger_price=None, limit_offset=None, trailing_offset=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -41,6 +41,8 @@ impl OrderUpdated {
         account_id: Option<AccountId>,
         price: Option<Price>,
         trigger_price: Option<Price>,
+        limit_offset: Option<Price>,
+        trailing_offset: Option<Price>,
     ) -> Self {
         Self::new(
             trader_id,
@@ -56,6 +58,8 @@ impl OrderUpdated {
             account_id,
             price,
             trigger_price,
+            limit_offset,
+            trailing_offset,
         )
     }
 
@@ -110,6 +114,16 @@ impl OrderUpdated {
             Some(trigger_price) => dict.set_item("trigger_price", trigger_price.to_string())?,
             None => dict.set_item("trigger_price", py.None())?,
         }
+        match self.limit_offset {
+            Some(limit_offset) => dict.set_item("limit_offset", limit_offset.to_string())?,
+            None => dict.set_item("limit_offset", py.None())?,
+        }
+        match self.trailing_offset {
+            Some(trailing_offset) => {
+                dict.set_item("trailing_offset", trailing_offset.to_string())?
+            }
+            None => dict.set_item("trailing_offset", py.None())?,
+        }
         Ok(dict.into())
     }
 }
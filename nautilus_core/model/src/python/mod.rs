@@ -76,6 +76,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::enums::BarAggregation>()?;
     m.add_class::<crate::enums::BookAction>()?;
     m.add_class::<crate::enums::BookType>()?;
+    m.add_class::<crate::enums::CancellationReason>()?;
     m.add_class::<crate::enums::ContingencyType>()?;
     m.add_class::<crate::enums::CurrencyType>()?;
     m.add_class::<crate::enums::InstrumentCloseType>()?;
@@ -21,7 +21,8 @@ use rust_decimal::prelude::ToPrimitive;
 use crate::{
     data::order::BookOrder,
     enums::{BookType, LiquiditySide, OrderSide, OrderType},
-    identifiers::InstrumentId,
+    events::OrderFilled,
+    identifiers::{InstrumentId, TradeId},
     instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
     orderbook::OrderBook,
     orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
@@ -59,6 +60,48 @@ pub fn calculate_commission(
     }
 }
 
+/// Returns the fill of a market order for `quantity` on `side` at `last_px`, with the
+/// optional `trade_id` to distinguish the fills of a position.
+#[must_use]
+pub fn stub_order_filled(
+    instrument: &InstrumentAny,
+    side: OrderSide,
+    quantity: Quantity,
+    last_px: Price,
+    trade_id: Option<TradeId>,
+) -> OrderFilled {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .side(side)
+        .quantity(quantity)
+        .build();
+    TestOrderEventStubs::order_filled(
+        &order,
+        instrument,
+        trade_id,
+        None,
+        Some(last_px),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .into()
+}
+
+/// Returns a position opened by the fill of a market order for `quantity` on `side` at `last_px`.
+#[must_use]
+pub fn stub_position(
+    instrument: &InstrumentAny,
+    side: OrderSide,
+    quantity: Quantity,
+    last_px: Price,
+) -> Position {
+    let fill = stub_order_filled(instrument, side, quantity, last_px, None);
+    Position::new(instrument, fill)
+}
+
 #[fixture]
 pub fn stub_position_long(audusd_sim: CurrencyPair) -> Position {
     let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
@@ -17,7 +17,7 @@
 
 use std::fmt::{Debug, Display};
 
-use nautilus_core::correctness::{check_predicate_true, FAILED};
+use nautilus_core::correctness::{check_equal, check_predicate_true, FAILED};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -54,19 +54,14 @@ impl AccountBalance {
     ///
     /// PyO3 requires a `Result` type that stacktrace can be printed for errors.
     pub fn new_checked(total: Money, locked: Money, free: Money) -> anyhow::Result<Self> {
-        check_predicate_true(
-            total == locked + free,
-            &format!(
-                "total balance is not equal to the sum of locked and free balances: {} != {} + {}",
-                total, locked, free
-            ),
-        )?;
-        Ok(Self {
+        let balance = Self {
             currency: total.currency,
             total,
             locked,
             free,
-        })
+        };
+        balance.validate()?;
+        Ok(balance)
     }
 
     /// Creates a new [`AccountBalance`] instance.
@@ -78,6 +73,30 @@ impl AccountBalance {
     pub fn new(total: Money, locked: Money, free: Money) -> Self {
         Self::new_checked(total, locked, free).expect(FAILED)
     }
+
+    /// Checks the balance invariants, e.g. for a balance reported by a venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `total`, `locked` or `free` is not denominated in the balance `currency`.
+    /// - If `total` is not the result of `locked` + `free`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (money, param) in [
+            (self.total, stringify!(total)),
+            (self.locked, stringify!(locked)),
+            (self.free, stringify!(free)),
+        ] {
+            check_equal(money.currency, self.currency, param, stringify!(currency))?;
+        }
+        check_predicate_true(
+            self.locked.raw.checked_add(self.free.raw) == Some(self.total.raw),
+            &format!(
+                "total balance is not equal to the sum of locked and free balances: {} != {} + {}",
+                self.total, self.locked, self.free
+            ),
+        )
+    }
 }
 
 impl PartialEq for AccountBalance {
@@ -165,7 +184,7 @@ mod tests {
 
     use crate::types::{
         stubs::{stub_account_balance, stub_margin_balance},
-        AccountBalance, MarginBalance,
+        AccountBalance, Currency, MarginBalance, Money,
     };
 
     #[rstest]
@@ -215,4 +234,40 @@ mod tests {
             display
         );
     }
+
+    #[rstest]
+    fn test_account_balance_validate(stub_account_balance: AccountBalance) {
+        assert!(stub_account_balance.validate().is_ok());
+    }
+
+    #[rstest]
+    #[case(
+        "1000.00 USD",
+        "100.00 USD",
+        "800.00 USD",
+        "total balance is not equal"
+    )]
+    #[case(
+        "1000.00 USD",
+        "100.00 USD",
+        "950.00 USD",
+        "total balance is not equal"
+    )]
+    #[case("1000.00 USD", "100.00 EUR", "900.00 USD", "'locked' value of EUR")]
+    fn test_account_balance_validate_inconsistent(
+        #[case] total: &str,
+        #[case] locked: &str,
+        #[case] free: &str,
+        #[case] expected: &str,
+    ) {
+        let balance = AccountBalance {
+            currency: Currency::USD(),
+            total: Money::from(total),
+            locked: Money::from(locked),
+            free: Money::from(free),
+        };
+
+        let err = balance.validate().unwrap_err();
+        assert!(err.to_string().contains(expected), "{err}");
+    }
 }
@@ -132,6 +132,48 @@ impl Currency {
         map_guard.get(s).copied()
     }
 
+    /// Returns the registered [`Currency`] with the given ISO 4217 numeric `code`
+    /// (e.g. 840 for USD).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no registered currency has the given `code` (0 is never a valid code).
+    /// - If there is a failure acquiring the lock on the currency map.
+    pub fn from_iso_numeric(code: u16) -> anyhow::Result<Self> {
+        let map = CURRENCY_MAP
+            .lock()
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        map.values()
+            .find(|currency| code != 0 && currency.iso4217 == code)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Unknown ISO 4217 numeric currency code: {code}"))
+    }
+
+    /// Returns the ISO 4217 numeric code for the currency, or `None` if it has none
+    /// (e.g. cryptocurrencies).
+    #[must_use]
+    pub fn iso_numeric(&self) -> Option<u16> {
+        (self.iso4217 != 0).then_some(self.iso4217)
+    }
+
+    /// Returns the symbol for the currency (e.g. "$" for USD), or `None` if it has no
+    /// widely recognized symbol.
+    #[must_use]
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self.code.as_str() {
+            "USD" => Some("$"),
+            "EUR" => Some("€"),
+            "GBP" => Some("£"),
+            "JPY" => Some("¥"),
+            "INR" => Some("₹"),
+            "KRW" => Some("₩"),
+            "BTC" => Some("₿"),
+            _ => None,
+        }
+    }
+
     /// Checks if the currency identified by the given `code` is a fiat currency.
     ///
     /// # Errors
@@ -333,4 +375,38 @@ mod tests {
         let deserialized: Currency = serde_json::from_str(&serialized).unwrap();
         assert_eq!(currency, deserialized);
     }
+
+    #[rstest]
+    #[case(Currency::USD(), 840)]
+    #[case(Currency::EUR(), 978)]
+    #[case(Currency::JPY(), 392)]
+    fn test_iso_numeric_round_trip(#[case] currency: Currency, #[case] code: u16) {
+        assert_eq!(currency.iso_numeric(), Some(code));
+        assert_eq!(Currency::from_iso_numeric(code).unwrap(), currency);
+    }
+
+    #[rstest]
+    fn test_iso_numeric_none_for_crypto() {
+        assert_eq!(Currency::BTC().iso_numeric(), None);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    fn test_from_iso_numeric_unknown_code(#[case] code: u16) {
+        let result = Currency::from_iso_numeric(code);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Unknown ISO 4217 numeric currency code: {code}")
+        );
+    }
+
+    #[rstest]
+    #[case(Currency::USD(), Some("$"))]
+    #[case(Currency::JPY(), Some("¥"))]
+    #[case(Currency::BTC(), Some("₿"))]
+    #[case(Currency::AUD(), None)]
+    fn test_symbol(#[case] currency: Currency, #[case] expected: Option<&str>) {
+        assert_eq!(currency.symbol(), expected);
+    }
 }
@@ -116,6 +116,56 @@ impl Money {
         Decimal::from_i128_with_scale(i128::from(rescaled_raw), u32::from(precision))
     }
 
+    /// Adds `other` to this instance, returning the sum.
+    ///
+    /// Unlike the `+` operator, a currency mismatch returns an error rather than
+    /// panicking, e.g. when aggregating PnLs which may be in several currencies.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `other` is in a different currency.
+    /// - If the addition overflows.
+    pub fn try_add(&self, other: Self) -> anyhow::Result<Self> {
+        if self.currency != other.currency {
+            anyhow::bail!(
+                "Currency mismatch: cannot add {} to {}",
+                other.currency.code,
+                self.currency.code
+            );
+        }
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or_else(|| anyhow::anyhow!("Overflow occurred when adding `Money`"))?;
+        Ok(Self::from_raw(raw, self.currency))
+    }
+
+    /// Subtracts `other` from this instance, returning the difference.
+    ///
+    /// Unlike the `-` operator, a currency mismatch returns an error rather than
+    /// panicking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `other` is in a different currency.
+    /// - If the subtraction underflows.
+    pub fn try_sub(&self, other: Self) -> anyhow::Result<Self> {
+        if self.currency != other.currency {
+            anyhow::bail!(
+                "Currency mismatch: cannot subtract {} from {}",
+                other.currency.code,
+                self.currency.code
+            );
+        }
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or_else(|| anyhow::anyhow!("Underflow occurred when subtracting `Money`"))?;
+        Ok(Self::from_raw(raw, self.currency))
+    }
+
     /// Returns a formatted string representation of this instance.
     #[must_use]
     pub fn to_formatted_string(&self) -> String {
@@ -123,6 +173,24 @@ impl Money {
             .separate_with_underscores();
         format!("{} {}", amount_str, self.currency.code)
     }
+
+    /// Returns a string representation for presentation, with the amount at the currency
+    /// precision grouped by commas, prefixed by the currency symbol if it has one (e.g.
+    /// "$1,234.50"), otherwise followed by the currency code (e.g. "1,234.50 AUD").
+    #[must_use]
+    pub fn to_display_string(&self) -> String {
+        let amount_str = format!(
+            "{:.*}",
+            self.currency.precision as usize,
+            self.as_f64().abs()
+        )
+        .separate_with_commas();
+        let sign = if self.raw < 0 { "-" } else { "" };
+        match self.currency.symbol() {
+            Some(symbol) => format!("{sign}{symbol}{amount_str}"),
+            None => format!("{sign}{amount_str} {}", self.currency.code),
+        }
+    }
 }
 
 impl FromStr for Money {
@@ -395,6 +463,50 @@ mod tests {
         let _result = usd + btc; // This should panic since currencies are different
     }
 
+    #[rstest]
+    fn test_try_add_and_try_sub_same_currency() {
+        let a = Money::new(1000.0, Currency::USD());
+        let b = Money::new(250.5, Currency::USD());
+
+        assert_eq!(a.try_add(b).unwrap(), Money::new(1250.5, Currency::USD()));
+        assert_eq!(a.try_sub(b).unwrap(), Money::new(749.5, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_try_add_different_currency() {
+        let usd = Money::new(1000.0, Currency::USD());
+        let eur = Money::new(500.0, Currency::EUR());
+
+        let result = usd.try_add(eur);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Currency mismatch: cannot add EUR to USD"
+        );
+    }
+
+    #[rstest]
+    fn test_try_sub_different_currency() {
+        let usd = Money::new(1000.0, Currency::USD());
+        let eur = Money::new(500.0, Currency::EUR());
+
+        let result = usd.try_sub(eur);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Currency mismatch: cannot subtract EUR from USD"
+        );
+    }
+
+    #[rstest]
+    fn test_try_add_overflow() {
+        let max = Money::new(MONEY_MAX, Currency::USD());
+        let min = Money::new(MONEY_MIN, Currency::USD());
+
+        assert!(max.try_add(max).is_err());
+        assert!(min.try_sub(max).is_err());
+    }
+
     #[rstest]
     fn test_money_min_max_values() {
         let min_money = Money::new(MONEY_MIN, Currency::USD());
@@ -435,6 +547,17 @@ mod tests {
         assert!(approx_eq!(f64, money.as_f64(), 1000.0, epsilon = 0.001));
     }
 
+    #[rstest]
+    #[case("1234567 JPY", "¥1,234,567")]
+    #[case("1234.5 USD", "$1,234.50")]
+    #[case("-1234.5 USD", "-$1,234.50")]
+    #[case("0 USD", "$0.00")]
+    #[case("12345.6789 BTC", "₿12,345.67890000")]
+    #[case("1234.5 AUD", "1,234.50 AUD")]
+    fn test_to_display_string(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(Money::from(value).to_display_string(), expected);
+    }
+
     #[rstest]
     fn test_money_new_btc() {
         let money = Money::new(10.3, Currency::BTC());
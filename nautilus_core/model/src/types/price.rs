@@ -46,6 +46,12 @@ pub const PRICE_MAX: f64 = 9_223_372_036.0;
 /// The minimum valid price value which can be represented.
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
 
+/// The maximum valid raw price value, `PRICE_MAX` at the fixed precision.
+const PRICE_MAX_RAW: i64 = PRICE_MAX as i64 * 10_i64.pow(FIXED_PRECISION as u32);
+
+/// The minimum valid raw price value, `PRICE_MIN` at the fixed precision.
+const PRICE_MIN_RAW: i64 = PRICE_MIN as i64 * 10_i64.pow(FIXED_PRECISION as u32);
+
 /// The sentinel `Price` representing errors (this will be removed when Cython is gone).
 pub const ERROR_PRICE: Price = Price {
     raw: PRICE_ERROR,
@@ -192,6 +198,56 @@ impl Price {
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns the sum of this price and `rhs`, or `None` if the sum is outside the range
+    /// [`PRICE_MIN`, `PRICE_MAX`].
+    ///
+    /// The result has the greater precision of the two operands, so unlike the `+` operator
+    /// a precision mismatch does not panic.
+    #[must_use]
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.raw
+            .checked_add(rhs.raw)
+            .filter(|raw| (PRICE_MIN_RAW..=PRICE_MAX_RAW).contains(raw))
+            .map(|raw| Self {
+                raw,
+                precision: self.precision.max(rhs.precision),
+            })
+    }
+
+    /// Returns the difference of this price and `rhs`, or `None` if the difference is
+    /// outside the range [`PRICE_MIN`, `PRICE_MAX`].
+    ///
+    /// The result has the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(rhs.raw)
+            .filter(|raw| (PRICE_MIN_RAW..=PRICE_MAX_RAW).contains(raw))
+            .map(|raw| Self {
+                raw,
+                precision: self.precision.max(rhs.precision),
+            })
+    }
+
+    /// Returns the product of this price and `rhs`, or `None` if the product is outside
+    /// the range [`PRICE_MIN`, `PRICE_MAX`].
+    ///
+    /// The product is calculated at 128 bits before scaling back to the fixed precision,
+    /// so an intermediate overflow of the raw values is not reported as an overflow.
+    /// The result has the sum of the precisions of the two operands (capped at
+    /// `FIXED_PRECISION`), so an exact product is not rounded.
+    #[must_use]
+    pub fn checked_mul(&self, rhs: Self) -> Option<Self> {
+        let raw = i128::from(self.raw) * i128::from(rhs.raw) / 10_i128.pow(FIXED_PRECISION as u32);
+        i64::try_from(raw)
+            .ok()
+            .filter(|raw| (PRICE_MIN_RAW..=PRICE_MAX_RAW).contains(raw))
+            .map(|raw| Self {
+                raw,
+                precision: (self.precision + rhs.precision).min(FIXED_PRECISION),
+            })
+    }
 }
 
 impl FromStr for Price {
@@ -410,6 +466,23 @@ impl<'de> Deserialize<'de> for Price {
     }
 }
 
+/// Checks the `value` is a multiple of the price `increment`.
+///
+/// # Errors
+///
+/// Returns an error if `increment` is not positive or `value` is not a multiple of it.
+pub fn check_price_increment(value: Price, increment: Price) -> anyhow::Result<()> {
+    if increment.raw <= 0 {
+        anyhow::bail!("{FAILED}: invalid `Price` increment, should be positive and was {increment}")
+    }
+    if value.raw % increment.raw != 0 {
+        anyhow::bail!(
+            "{FAILED}: invalid `Price`, should be a multiple of the price increment {increment} and was {value}"
+        )
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -642,4 +715,84 @@ mod tests {
         let result = format!("{price}");
         assert_eq!(result, "44.12");
     }
+
+    #[rstest]
+    fn test_checked_add() {
+        let price = Price::from("1.5")
+            .checked_add(Price::from("-2.25"))
+            .unwrap();
+        assert_eq!(price, Price::from("-0.75"));
+        assert_eq!(price.precision, 2);
+
+        let max = Price::max(9);
+        assert_eq!(max.raw, PRICE_MAX_RAW);
+        assert_eq!(max.checked_add(Price::from("0")), Some(max));
+        assert_eq!(max.checked_add(Price::from_raw(1, 9)), None);
+        assert_eq!(
+            Price::from_raw(i64::MAX - 1, 0).checked_add(Price::from_raw(2, 0)),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_checked_sub() {
+        let price = Price::from("1.5").checked_sub(Price::from("2.25")).unwrap();
+        assert_eq!(price, Price::from("-0.75"));
+        assert_eq!(price.precision, 2);
+
+        let min = Price::min(9);
+        assert_eq!(min.raw, PRICE_MIN_RAW);
+        assert_eq!(min.checked_sub(Price::from("0")), Some(min));
+        assert_eq!(min.checked_sub(Price::from_raw(1, 9)), None);
+        assert_eq!(Price::max(9).checked_sub(Price::from_raw(-1, 9)), None);
+        assert_eq!(
+            Price::from_raw(i64::MIN + 1, 0).checked_sub(Price::from_raw(2, 0)),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_checked_mul() {
+        // The raw product overflows 64 bits before scaling back
+        let price = Price::from("-5").checked_mul(Price::from("5.5")).unwrap();
+        assert_eq!(price, Price::from("-27.5"));
+        assert_eq!(price.precision, 1);
+
+        let price = Price::from("-1.5")
+            .checked_mul(Price::from("2.25"))
+            .unwrap();
+        assert_eq!(price, Price::from("-3.375"));
+        assert_eq!(price.precision, 3);
+
+        // The precision is capped, truncating the product at the fixed precision
+        let price = Price::from("0.000000001")
+            .checked_mul(Price::from("0.5"))
+            .unwrap();
+        assert_eq!(price.raw, 0);
+        assert_eq!(price.precision, FIXED_PRECISION);
+
+        let max = Price::max(9);
+        assert_eq!(max.checked_mul(Price::from("1")), Some(max));
+        assert_eq!(
+            max.checked_mul(Price::from("-1")).unwrap().raw,
+            -PRICE_MAX_RAW
+        );
+        assert_eq!(max.checked_mul(Price::from("1.000000001")), None);
+        assert_eq!(Price::min(9).checked_mul(Price::from("2")), None);
+        assert_eq!(max.checked_mul(max), None);
+    }
+
+    #[rstest]
+    fn test_check_price_increment() {
+        let increment = Price::from("0.01");
+        assert!(check_price_increment(Price::from("1.23"), increment).is_ok());
+        assert!(check_price_increment(Price::from("-2"), increment).is_ok());
+        assert!(check_price_increment(Price::from("1.00"), Price::from("0.00")).is_err());
+        assert_eq!(
+            check_price_increment(Price::from("1.235"), increment)
+                .unwrap_err()
+                .to_string(),
+            "Condition failed: invalid `Price`, should be a multiple of the price increment 0.01 and was 1.235"
+        );
+    }
 }
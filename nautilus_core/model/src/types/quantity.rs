@@ -43,6 +43,9 @@ pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 /// The minimum valid quantity value which can be represented.
 pub const QUANTITY_MIN: f64 = 0.0;
 
+/// The maximum valid raw quantity value, `QUANTITY_MAX` at the fixed precision.
+const QUANTITY_MAX_RAW: u64 = QUANTITY_MAX as u64 * 10_u64.pow(FIXED_PRECISION as u32);
+
 /// Represents a quantity with a non-negative value.
 ///
 /// Capable of storing either a whole number (no decimal places) of 'contracts'
@@ -154,6 +157,68 @@ impl Quantity {
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns this quantity rounded down to a multiple of the size `increment`, at the
+    /// precision of the `increment` (e.g. 1.2345 with an increment of 0.001 is 1.234).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `increment` is not positive.
+    #[must_use]
+    pub fn round_to_size_increment(&self, increment: Quantity) -> Quantity {
+        check_quantity_positive(increment).expect(FAILED);
+        Self {
+            raw: self.raw - self.raw % increment.raw,
+            precision: increment.precision,
+        }
+    }
+
+    /// Returns the sum of this quantity and `rhs`, or `None` if the sum exceeds `QUANTITY_MAX`.
+    ///
+    /// The result has the greater precision of the two operands, so unlike the `+` operator
+    /// a precision mismatch does not panic.
+    #[must_use]
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.raw
+            .checked_add(rhs.raw)
+            .filter(|raw| *raw <= QUANTITY_MAX_RAW)
+            .map(|raw| Self {
+                raw,
+                precision: self.precision.max(rhs.precision),
+            })
+    }
+
+    /// Returns the difference of this quantity and `rhs`, or `None` if `rhs` is greater,
+    /// as a negative quantity cannot be represented.
+    ///
+    /// The result has the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the product of this quantity and `rhs`, or `None` if the product exceeds
+    /// `QUANTITY_MAX`.
+    ///
+    /// The product is calculated at 128 bits before scaling back to the fixed precision,
+    /// so an intermediate overflow of the raw values is not reported as an overflow.
+    /// The result has the sum of the precisions of the two operands (capped at
+    /// `FIXED_PRECISION`), so an exact product is not rounded.
+    #[must_use]
+    pub fn checked_mul(&self, rhs: Self) -> Option<Self> {
+        let raw = u128::from(self.raw) * u128::from(rhs.raw) / 10_u128.pow(FIXED_PRECISION as u32);
+        u64::try_from(raw)
+            .ok()
+            .filter(|raw| *raw <= QUANTITY_MAX_RAW)
+            .map(|raw| Self {
+                raw,
+                precision: (self.precision + rhs.precision).min(FIXED_PRECISION),
+            })
+    }
 }
 
 impl From<Quantity> for f64 {
@@ -418,6 +483,21 @@ pub fn check_quantity_positive(value: Quantity) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Checks the `value` is a multiple of the size `increment`.
+///
+/// # Errors
+///
+/// Returns an error if `increment` is not positive or `value` is not a multiple of it.
+pub fn check_quantity_increment(value: Quantity, increment: Quantity) -> anyhow::Result<()> {
+    check_quantity_positive(increment)?;
+    if value.raw % increment.raw != 0 {
+        anyhow::bail!(
+            "{FAILED}: invalid `Quantity`, should be a multiple of the size increment {increment} and was {value}"
+        )
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -438,6 +518,42 @@ mod tests {
         check_quantity_positive(qty).unwrap();
     }
 
+    #[rstest]
+    #[case("1.2345", "0.001", "1.234")]
+    #[case("1.2349", "0.001", "1.234")]
+    #[case("1.234", "0.001", "1.234")]
+    #[case("0.0005", "0.001", "0.000")]
+    #[case("7", "0.5", "7.0")]
+    #[case("105", "10", "100")]
+    fn test_round_to_size_increment(
+        #[case] value: &str,
+        #[case] increment: &str,
+        #[case] expected: &str,
+    ) {
+        let qty = Quantity::from(value).round_to_size_increment(Quantity::from(increment));
+        assert_eq!(qty, Quantity::from(expected));
+        assert_eq!(qty.to_string(), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Condition failed: invalid `Quantity`, should be positive and was 0")]
+    fn test_round_to_size_increment_zero_increment() {
+        let _ = Quantity::from("1.2345").round_to_size_increment(Quantity::from("0.000"));
+    }
+
+    #[rstest]
+    fn test_check_quantity_increment() {
+        let increment = Quantity::from("0.001");
+        assert!(check_quantity_increment(Quantity::from("1.234"), increment).is_ok());
+        assert!(check_quantity_increment(Quantity::from("2"), increment).is_ok());
+        assert_eq!(
+            check_quantity_increment(Quantity::from("1.2345"), increment)
+                .unwrap_err()
+                .to_string(),
+            "Condition failed: invalid `Quantity`, should be a multiple of the size increment 0.001 and was 1.2345"
+        );
+    }
+
     #[rstest]
     #[should_panic(expected = "Condition failed: `precision` was greater than the maximum ")]
     fn test_invalid_precision_new() {
@@ -636,4 +752,66 @@ mod tests {
         let result = format!("{quantity}");
         assert_eq!(result, "44.12");
     }
+
+    #[rstest]
+    fn test_checked_add() {
+        let qty = Quantity::from("1.5")
+            .checked_add(Quantity::from("2.25"))
+            .unwrap();
+        assert_eq!(qty, Quantity::from("3.75"));
+        assert_eq!(qty.precision, 2);
+
+        let max = Quantity::from_raw(QUANTITY_MAX_RAW, 0);
+        assert_eq!(max.checked_add(Quantity::from(0)), Some(max));
+        assert_eq!(max.checked_add(Quantity::from_raw(1, 9)), None);
+        assert_eq!(
+            Quantity::from_raw(u64::MAX - 1, 0).checked_add(Quantity::from_raw(2, 0)),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_checked_sub() {
+        let qty = Quantity::from("2.25")
+            .checked_sub(Quantity::from("1.5"))
+            .unwrap();
+        assert_eq!(qty, Quantity::from("0.75"));
+        assert_eq!(qty.precision, 2);
+        assert_eq!(
+            Quantity::from(1).checked_sub(Quantity::from(1)),
+            Some(Quantity::from(0))
+        );
+        assert_eq!(
+            Quantity::from(1).checked_sub(Quantity::from("1.000000001")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_checked_mul() {
+        // The raw product overflows 64 bits before scaling back
+        let qty = Quantity::from(5)
+            .checked_mul(Quantity::from("5.5"))
+            .unwrap();
+        assert_eq!(qty, Quantity::from("27.5"));
+        assert_eq!(qty.precision, 1);
+
+        let qty = Quantity::from("1.5")
+            .checked_mul(Quantity::from("2.25"))
+            .unwrap();
+        assert_eq!(qty, Quantity::from("3.375"));
+        assert_eq!(qty.precision, 3);
+
+        // The precision is capped, truncating the product at the fixed precision
+        let qty = Quantity::from("0.000000001")
+            .checked_mul(Quantity::from("0.5"))
+            .unwrap();
+        assert_eq!(qty.raw, 0);
+        assert_eq!(qty.precision, FIXED_PRECISION);
+
+        let max = Quantity::from_raw(QUANTITY_MAX_RAW, 0);
+        assert_eq!(max.checked_mul(Quantity::from(1)), Some(max));
+        assert_eq!(max.checked_mul(Quantity::from("1.000000001")), None);
+        assert_eq!(max.checked_mul(max), None);
+    }
 }
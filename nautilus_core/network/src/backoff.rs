@@ -0,0 +1,294 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Backoff strategies for retrying failed operations.
+
+use std::time::Duration;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Represents the strategy for the delays between successive retries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum BackoffStrategy {
+    /// The delay doubles after each retry, up to the max delay.
+    EXPONENTIAL,
+    /// The delay is drawn uniformly between the initial delay and three times the
+    /// previous delay, up to the max delay ("decorrelated jitter"), which spreads out
+    /// retries from many clients under contention.
+    DECORRELATED_JITTER,
+    /// The delay is always the initial delay.
+    FIXED,
+}
+
+impl BackoffStrategy {
+    /// Creates a [`Backoff`] for this strategy from the initial and max delays.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `delay_initial` is greater than `delay_max`.
+    #[must_use]
+    pub fn backoff(self, delay_initial: Duration, delay_max: Duration) -> Backoff {
+        match self {
+            Self::EXPONENTIAL => {
+                Backoff::Exponential(ExponentialBackoff::new(delay_initial, delay_max, 2.0))
+            }
+            Self::DECORRELATED_JITTER => Backoff::DecorrelatedJitter(
+                DecorrelatedJitterBackoff::new(delay_initial, delay_max),
+            ),
+            Self::FIXED => Backoff::Fixed(delay_initial),
+        }
+    }
+}
+
+/// Provides the delays between successive retries for a [`BackoffStrategy`].
+#[derive(Clone, Debug)]
+pub enum Backoff {
+    Exponential(ExponentialBackoff),
+    DecorrelatedJitter(DecorrelatedJitterBackoff),
+    Fixed(Duration),
+}
+
+impl Backoff {
+    /// Returns the delay to wait before the next retry.
+    pub fn next_duration(&mut self) -> Duration {
+        match self {
+            Self::Exponential(backoff) => backoff.next_duration(),
+            Self::DecorrelatedJitter(backoff) => backoff.next_duration(),
+            Self::Fixed(delay) => *delay,
+        }
+    }
+
+    /// Resets the backoff to its initial delay, e.g. after the operation succeeds.
+    pub fn reset(&mut self) {
+        match self {
+            Self::Exponential(backoff) => backoff.reset(),
+            Self::DecorrelatedJitter(backoff) => backoff.reset(),
+            Self::Fixed(_) => {}
+        }
+    }
+}
+
+/// Provides the delays between successive retries of a failed operation.
+///
+/// The delay starts at `delay_initial` and is multiplied by `factor` after each
+/// retry, up to `delay_max`, until it is [`reset`](Self::reset) on success.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    delay_initial: Duration,
+    delay_max: Duration,
+    delay_current: Duration,
+    factor: f64,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new [`ExponentialBackoff`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `factor` is less than 1.0 or not finite.
+    /// - If `delay_initial` is greater than `delay_max`.
+    #[must_use]
+    pub fn new(delay_initial: Duration, delay_max: Duration, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor >= 1.0,
+            "Backoff factor must be at least 1.0, was {factor}"
+        );
+        assert!(
+            delay_initial <= delay_max,
+            "Initial delay {delay_initial:?} exceeds max delay {delay_max:?}"
+        );
+        Self {
+            delay_initial,
+            delay_max,
+            delay_current: delay_initial,
+            factor,
+        }
+    }
+
+    /// Returns the delay to wait before the next retry, and increases the delay
+    /// for the following retry.
+    pub fn next_duration(&mut self) -> Duration {
+        let delay = self.delay_current;
+        self.delay_current = self.delay_current.mul_f64(self.factor).min(self.delay_max);
+        delay
+    }
+
+    /// Resets the delay to the initial delay, e.g. after the operation succeeds.
+    pub fn reset(&mut self) {
+        self.delay_current = self.delay_initial;
+    }
+
+    /// Returns the delay which will be returned by the next call to
+    /// [`next_duration`](Self::next_duration).
+    #[must_use]
+    pub const fn current_delay(&self) -> Duration {
+        self.delay_current
+    }
+}
+
+/// Provides decorrelated jitter delays between successive retries of a failed operation.
+///
+/// Each delay is drawn uniformly from `delay_initial` to three times the previous delay,
+/// capped at `delay_max`, until it is [`reset`](Self::reset) on success.
+#[derive(Clone, Debug)]
+pub struct DecorrelatedJitterBackoff {
+    delay_initial: Duration,
+    delay_max: Duration,
+    delay_previous: Duration,
+    rng: StdRng,
+}
+
+impl DecorrelatedJitterBackoff {
+    /// Creates a new [`DecorrelatedJitterBackoff`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `delay_initial` is greater than `delay_max`.
+    #[must_use]
+    pub fn new(delay_initial: Duration, delay_max: Duration) -> Self {
+        assert!(
+            delay_initial <= delay_max,
+            "Initial delay {delay_initial:?} exceeds max delay {delay_max:?}"
+        );
+        Self {
+            delay_initial,
+            delay_max,
+            delay_previous: delay_initial,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Returns the delay to wait before the next retry.
+    pub fn next_duration(&mut self) -> Duration {
+        let upper = (self.delay_previous * 3).max(self.delay_initial);
+        let delay = self
+            .rng
+            .gen_range(self.delay_initial..=upper)
+            .min(self.delay_max);
+        self.delay_previous = delay;
+        delay
+    }
+
+    /// Resets the delay to the initial delay, e.g. after the operation succeeds.
+    pub fn reset(&mut self) {
+        self.delay_previous = self.delay_initial;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{BackoffStrategy, DecorrelatedJitterBackoff, ExponentialBackoff};
+
+    #[rstest]
+    fn test_next_duration_grows_until_max() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::from_millis(500), 2.0);
+
+        let delays: Vec<Duration> = (0..5).map(|_| backoff.next_duration()).collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(500),
+                Duration::from_millis(500),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::from_millis(500), 2.0);
+        backoff.next_duration();
+        backoff.next_duration();
+
+        backoff.reset();
+
+        assert_eq!(backoff.current_delay(), Duration::from_millis(100));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Backoff factor must be at least 1.0")]
+    fn test_invalid_factor() {
+        let _ =
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::from_millis(500), 0.5);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "exceeds max delay")]
+    fn test_initial_delay_exceeds_max() {
+        let _ = ExponentialBackoff::new(Duration::from_secs(2), Duration::from_secs(1), 2.0);
+    }
+
+    #[rstest]
+    fn test_decorrelated_jitter_stays_within_bounds() {
+        let delay_initial = Duration::from_millis(100);
+        let delay_max = Duration::from_secs(5);
+        let mut backoff = DecorrelatedJitterBackoff::new(delay_initial, delay_max);
+
+        let mut previous = delay_initial;
+        for _ in 0..10_000 {
+            let delay = backoff.next_duration();
+            assert!(delay >= delay_initial);
+            assert!(delay <= delay_max);
+            assert!(delay <= previous * 3);
+            previous = delay;
+        }
+    }
+
+    #[rstest]
+    fn test_decorrelated_jitter_reset() {
+        let delay_initial = Duration::from_millis(100);
+        let mut backoff = DecorrelatedJitterBackoff::new(delay_initial, Duration::from_secs(5));
+        for _ in 0..10 {
+            backoff.next_duration();
+        }
+
+        backoff.reset();
+
+        // The first delay after a reset is at most three times the initial delay
+        assert!(backoff.next_duration() <= delay_initial * 3);
+    }
+
+    #[rstest]
+    #[case(BackoffStrategy::EXPONENTIAL, vec![100, 200, 400, 500])]
+    #[case(BackoffStrategy::FIXED, vec![100, 100, 100, 100])]
+    fn test_strategy_backoff(#[case] strategy: BackoffStrategy, #[case] expected_ms: Vec<u64>) {
+        let mut backoff = strategy.backoff(Duration::from_millis(100), Duration::from_millis(500));
+
+        let delays: Vec<Duration> = (0..4).map(|_| backoff.next_duration()).collect();
+
+        let expected: Vec<Duration> = expected_ms.into_iter().map(Duration::from_millis).collect();
+        assert_eq!(delays, expected);
+    }
+}
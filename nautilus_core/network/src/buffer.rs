@@ -0,0 +1,185 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A budget for the bytes held in client receive buffers, shared across clients.
+//!
+//! Each client buffers received data until a complete message can be passed to its
+//! handler. A budget shared by several clients caps the bytes held across all of their
+//! buffers: a client reserves budget for the bytes it reads and releases it as messages
+//! are drained. While the budget is exhausted a client stops reading, so its peer waits
+//! on TCP flow control rather than the connection being dropped.
+//!
+//! The budget should be large enough to hold a complete message from every client
+//! sharing it, as clients waiting on each other's partial messages stall until one of
+//! them disconnects.
+
+use std::{num::NonZeroUsize, sync::Arc};
+
+use tokio::sync::Semaphore;
+
+/// A budget of bytes shared by the receive buffers of the clients configured with it.
+///
+/// Cloning the budget shares it, rather than creating a new budget.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct BufferBudget {
+    semaphore: Arc<Semaphore>,
+    capacity: usize,
+}
+
+impl BufferBudget {
+    /// Creates a new [`BufferBudget`] instance of `capacity` bytes.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` exceeds [`Semaphore::MAX_PERMITS`].
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(capacity.get())),
+            capacity: capacity.get(),
+        }
+    }
+
+    /// Returns the total bytes of the budget.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the bytes of the budget not currently held by any buffer.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+}
+
+/// The bytes of a [`BufferBudget`] held by a single buffer, released when dropped.
+#[derive(Debug)]
+pub(crate) struct BufferLease {
+    budget: BufferBudget,
+    held: usize,
+}
+
+impl BufferLease {
+    pub(crate) const fn new(budget: BufferBudget) -> Self {
+        Self { budget, held: 0 }
+    }
+
+    pub(crate) const fn capacity(&self) -> usize {
+        self.budget.capacity
+    }
+
+    /// Waits until the budget is available, then reserves as much of it as is free, up to
+    /// `max` bytes, returning the bytes reserved.
+    pub(crate) async fn reserve(&mut self, max: usize) -> usize {
+        let semaphore = &self.budget.semaphore;
+        semaphore
+            .acquire()
+            .await
+            .expect("Buffer budget semaphore closed")
+            .forget();
+        let mut reserved = 1;
+
+        let extra = semaphore
+            .available_permits()
+            .min(max.saturating_sub(1))
+            .min(u32::MAX as usize);
+        if extra > 0 {
+            // Another buffer may have taken the budget since it was checked
+            if let Ok(permits) = semaphore.try_acquire_many(extra as u32) {
+                permits.forget();
+                reserved += extra;
+            }
+        }
+
+        self.held += reserved;
+        reserved
+    }
+
+    /// Releases any bytes held beyond the `len` of the buffer.
+    pub(crate) fn shrink_to(&mut self, len: usize) {
+        if self.held > len {
+            self.budget.semaphore.add_permits(self.held - len);
+            self.held = len;
+        }
+    }
+}
+
+impl Drop for BufferLease {
+    fn drop(&mut self) {
+        self.shrink_to(0);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use nonzero_ext::nonzero;
+    use rstest::rstest;
+
+    use super::{BufferBudget, BufferLease};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_lease_reserves_up_to_available() {
+        let budget = BufferBudget::new(nonzero!(16usize));
+        let mut first = BufferLease::new(budget.clone());
+        let mut second = BufferLease::new(budget.clone());
+
+        assert_eq!(first.reserve(10).await, 10);
+        assert_eq!(second.reserve(10).await, 6);
+        assert_eq!(budget.available(), 0);
+
+        first.shrink_to(4);
+        assert_eq!(budget.available(), 6);
+
+        drop(second);
+        assert_eq!(budget.available(), 12);
+        assert_eq!(budget.capacity(), 16);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_lease_waits_for_released_budget() {
+        let budget = BufferBudget::new(nonzero!(8usize));
+        let mut first = BufferLease::new(budget.clone());
+        let mut second = BufferLease::new(budget.clone());
+        assert_eq!(first.reserve(8).await, 8);
+
+        let waiting = tokio::spawn(async move {
+            let reserved = second.reserve(8).await;
+            (reserved, second)
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting.is_finished());
+
+        first.shrink_to(5);
+        let (reserved, second) = waiting.await.unwrap();
+        assert_eq!(reserved, 3);
+        assert_eq!(budget.available(), 0);
+
+        drop(second);
+        assert_eq!(budget.available(), 3);
+    }
+}
@@ -15,16 +15,90 @@
 
 //! A high-performance HTTP client implementation.
 
-use std::{collections::HashMap, hash::Hash, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
 use http::HeaderValue;
+use nautilus_core::correctness::{check_positive_u64, check_predicate_true};
 use reqwest::{
     header::{HeaderMap, HeaderName},
-    Method, Response, Url,
+    ClientBuilder, Method, Request, Response, Url,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{
+    backoff::{Backoff, BackoffStrategy},
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
 };
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+/// The hook called before each request is sent, with the method, URL and request headers.
+pub type RequestHook = Arc<dyn Fn(&Method, &Url, &HeaderMap) + Send + Sync>;
+
+/// The hook called after each response is received, with the status code, the latency
+/// from sending the request until the body was received, and the body size in bytes.
+pub type ResponseHook = Arc<dyn Fn(u16, Duration, usize) + Send + Sync>;
+
+/// The hook called when a request fails without a complete response (e.g. on a
+/// connection error or timeout), with the error and the latency from sending the request
+/// until it failed.
+pub type ErrorHook = Arc<dyn Fn(&HttpClientError, Duration) + Send + Sync>;
+
+/// Hooks observing the requests and responses of an `HttpClient`, e.g. for logging.
+///
+/// Each attempt of a retried request and each streamed GET request is passed to the
+/// hooks, with `on_response` called for a streamed response once its body has been
+/// received in full. Every request passed to `on_request` is followed by a call to either
+/// `on_response` or `on_error`, unless a streamed response is dropped before its end.
+/// An unset hook is a no-op.
+#[derive(Clone, Default)]
+pub struct HttpHooks {
+    /// The optional hook called before each request is sent.
+    pub on_request: Option<RequestHook>,
+    /// The optional hook called after each response is received.
+    pub on_response: Option<ResponseHook>,
+    /// The optional hook called when a request fails without a complete response.
+    pub on_error: Option<ErrorHook>,
+}
+
+impl HttpHooks {
+    fn request(&self, request: &Request) {
+        if let Some(on_request) = &self.on_request {
+            on_request(request.method(), request.url(), request.headers());
+        }
+    }
+
+    fn response(&self, status: u16, latency: Duration, body_size: usize) {
+        if let Some(on_response) = &self.on_response {
+            on_response(status, latency, body_size);
+        }
+    }
+
+    fn error(&self, error: &HttpClientError, latency: Duration) {
+        if let Some(on_error) = &self.on_error {
+            on_error(error, latency);
+        }
+    }
+}
+
+impl std::fmt::Debug for HttpHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(HttpHooks))
+            .field("on_request", &self.on_request.is_some())
+            .field("on_response", &self.on_response.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .finish()
+    }
+}
 
 /// Represents the HTTP methods supported by the `HttpClient`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -38,6 +112,7 @@ pub enum HttpMethod {
     PUT,
     DELETE,
     PATCH,
+    HEAD,
 }
 
 #[allow(clippy::from_over_into)]
@@ -49,10 +124,171 @@ impl Into<Method> for HttpMethod {
             Self::PUT => Method::PUT,
             Self::DELETE => Method::DELETE,
             Self::PATCH => Method::PATCH,
+            Self::HEAD => Method::HEAD,
+        }
+    }
+}
+
+/// Configuration for retrying HTTP requests which fail transiently.
+///
+/// Only requests with a method in `methods` are retried, which by default are the
+/// idempotent methods. Connection errors, timeouts and 502, 503 or 504 responses are
+/// retried with the `backoff_strategy` delays until `max_attempts` is reached.
+///
+/// Each attempt awaits the rate limit quota and an in-flight permit like a new request,
+/// and the in-flight permit is released while waiting to retry.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpRetryConfig {
+    /// The maximum number of attempts for a request, including the first.
+    pub max_attempts: u32,
+    /// The methods for which requests are retried.
+    pub methods: Vec<HttpMethod>,
+    /// The delay before the first retry in milliseconds.
+    pub delay_initial_ms: u64,
+    /// The maximum delay between retries in milliseconds.
+    pub delay_max_ms: u64,
+    /// The strategy for the delays between retries, from `delay_initial_ms` up to
+    /// `delay_max_ms`.
+    pub backoff_strategy: BackoffStrategy,
+}
+
+impl HttpRetryConfig {
+    /// Creates a new [`HttpRetryConfig`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `max_attempts` is zero.
+    /// - If `delay_initial_ms` is greater than `delay_max_ms`.
+    pub fn new(
+        max_attempts: u32,
+        methods: Vec<HttpMethod>,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_strategy: BackoffStrategy,
+    ) -> anyhow::Result<Self> {
+        let config = Self {
+            max_attempts,
+            methods,
+            delay_initial_ms,
+            delay_max_ms,
+            backoff_strategy,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validates the configuration, as the fields may be set directly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `max_attempts` is zero.
+    /// - If `delay_initial_ms` is greater than `delay_max_ms`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        check_positive_u64(u64::from(self.max_attempts), "max_attempts")?;
+        check_predicate_true(
+            self.delay_initial_ms <= self.delay_max_ms,
+            &format!(
+                "`delay_initial_ms` {} exceeds `delay_max_ms` {}",
+                self.delay_initial_ms, self.delay_max_ms
+            ),
+        )
+    }
+
+    fn allows(&self, method: &Method) -> bool {
+        self.methods
+            .iter()
+            .any(|allowed| Into::<Method>::into(*allowed) == *method)
+    }
+
+    fn backoff(&self) -> Backoff {
+        self.backoff_strategy.backoff(
+            Duration::from_millis(self.delay_initial_ms),
+            Duration::from_millis(self.delay_max_ms),
+        )
+    }
+}
+
+impl Default for HttpRetryConfig {
+    /// Creates a new default [`HttpRetryConfig`] instance.
+    ///
+    /// Requests are attempted up to 3 times for the GET, HEAD, PUT and DELETE methods,
+    /// with exponential backoff.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            methods: vec![
+                HttpMethod::GET,
+                HttpMethod::HEAD,
+                HttpMethod::PUT,
+                HttpMethod::DELETE,
+            ],
+            delay_initial_ms: 500,
+            delay_max_ms: 5_000,
+            backoff_strategy: BackoffStrategy::EXPONENTIAL,
+        }
+    }
+}
+
+/// Configuration for the pool of idle connections kept open for reuse.
+///
+/// Venues with strict connection limits may ban clients which open too many connections,
+/// so the pool can be capped and idle connections closed sooner. Unset values use the
+/// `reqwest` defaults (no cap, and a 90 second idle timeout).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpPoolConfig {
+    /// The maximum number of idle connections kept open per host.
+    pub max_idle_per_host: Option<usize>,
+    /// The duration in milliseconds after which an idle connection is closed.
+    pub idle_timeout_ms: Option<u64>,
+}
+
+impl HttpPoolConfig {
+    fn apply(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(max_idle_per_host) = self.max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle_per_host);
+        }
+        if let Some(idle_timeout_ms) = self.idle_timeout_ms {
+            builder = builder.pool_idle_timeout(Duration::from_millis(idle_timeout_ms));
         }
+        builder
     }
 }
 
+/// Configuration for an [`HttpClient`].
+///
+/// Rate limiting can be configured on a per-endpoint basis with `keyed_quotas`, pairing
+/// each key with its quota, while the `default_quota` applies to any request (no rate
+/// limit if `None`).
+#[derive(Clone, Debug, Default)]
+pub struct HttpClientConfig {
+    /// The default headers sent with every request.
+    pub headers: HashMap<String, String>,
+    /// The headers retained from each response.
+    pub header_keys: Vec<String>,
+    /// The rate limiting quotas for specific keys.
+    pub keyed_quotas: Vec<(String, Quota)>,
+    /// The rate limiting quota for any request.
+    pub default_quota: Option<Quota>,
+    /// The maximum number of concurrent in-flight requests (no cap if `None`).
+    pub max_inflight: Option<usize>,
+    /// The configuration for retrying transient failures (no retries if `None`).
+    pub retry_config: Option<HttpRetryConfig>,
+    /// The configuration for the pool of idle connections.
+    pub pool_config: HttpPoolConfig,
+    /// The hooks observing requests and responses.
+    pub hooks: HttpHooks,
+}
+
 /// Represents the response from an HTTP request.
 ///
 /// This struct encapsulates the status, headers, and body of an HTTP response,
@@ -73,21 +309,34 @@ pub struct HttpResponse {
 
 /// Represents errors that can occur when using the `HttpClient`.
 ///
-/// This enum provides variants for general HTTP errors and timeout errors,
-/// allowing for more granular error handling.
+/// This enum provides variants for general HTTP errors, connection errors and
+/// timeout errors, allowing for more granular error handling.
 #[derive(thiserror::Error, Debug)]
 pub enum HttpClientError {
     #[error("HTTP error occurred: {0}")]
     Error(String),
 
+    #[error("HTTP connection error: {0}")]
+    ConnectionError(String),
+
     #[error("HTTP request timed out: {0}")]
     TimeoutError(String),
 }
 
+impl HttpClientError {
+    /// Returns whether the error is transient, so the request may succeed if retried.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::ConnectionError(_) | Self::TimeoutError(_))
+    }
+}
+
 impl From<reqwest::Error> for HttpClientError {
     fn from(source: reqwest::Error) -> Self {
         if source.is_timeout() {
             Self::TimeoutError(source.to_string())
+        } else if source.is_connect() || source.is_request() {
+            Self::ConnectionError(source.to_string())
         } else {
             Self::Error(source.to_string())
         }
@@ -116,40 +365,88 @@ pub struct HttpClient {
     pub(crate) client: InnerHttpClient,
     /// The rate limiter to control the request rate.
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    /// The limiter to cap the number of concurrent in-flight requests.
+    pub(crate) inflight_limiter: InflightLimiter,
+    /// The configuration for retrying transient failures (no retries if `None`).
+    pub(crate) retry_config: Option<HttpRetryConfig>,
 }
 
 impl HttpClient {
-    /// Creates a new [`HttpClient`] instance.
-    #[must_use]
-    pub fn new(
-        headers: HashMap<String, String>,
-        header_keys: Vec<String>,
-        keyed_quotas: Vec<(String, Quota)>,
-        default_quota: Option<Quota>,
-    ) -> Self {
+    /// Creates a new [`HttpClient`] instance from the `config`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `max_inflight` is zero.
+    /// - If the `retry_config` is invalid.
+    /// - If a default header name or value is invalid.
+    /// - If the underlying `reqwest` client cannot be built.
+    pub fn new(config: HttpClientConfig) -> anyhow::Result<Self> {
+        let HttpClientConfig {
+            headers,
+            header_keys,
+            keyed_quotas,
+            default_quota,
+            max_inflight,
+            retry_config,
+            pool_config,
+            hooks,
+        } = config;
+
+        if let Some(max_inflight) = max_inflight {
+            check_positive_u64(max_inflight as u64, "max_inflight")?;
+        }
+        if let Some(retry_config) = &retry_config {
+            retry_config.validate()?;
+        }
+
         // Build default headers
         let mut header_map = HeaderMap::new();
         for (key, value) in headers {
-            let header_name = HeaderName::from_str(&key).expect("Invalid header name");
-            let header_value = HeaderValue::from_str(&value).expect("Invalid header value");
+            let header_name = HeaderName::from_str(&key)?;
+            let header_value = HeaderValue::from_str(&value)?;
             header_map.insert(header_name, header_value);
         }
 
-        let client = reqwest::Client::builder()
-            .default_headers(header_map)
-            .build()
-            .expect("Failed to build reqwest client");
+        let client = pool_config
+            .apply(reqwest::Client::builder().default_headers(header_map))
+            .build()?;
 
         let client = InnerHttpClient {
             client,
             header_keys: Arc::new(header_keys),
+            hooks,
         };
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
-        Self {
+        Ok(Self {
             client,
             rate_limiter,
-        }
+            inflight_limiter: InflightLimiter::new(max_inflight),
+            retry_config,
+        })
+    }
+
+    /// Returns the number of requests currently in-flight.
+    ///
+    /// Requests awaiting a rate limit or an in-flight permit are not counted.
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inflight_limiter.count()
+    }
+
+    /// Returns the number of requests which can currently be made against the rate limit `key`
+    /// without waiting, or `None` if no quota applies to the key.
+    #[must_use]
+    pub fn remaining(&self, key: &str) -> Option<u32> {
+        self.rate_limiter.remaining(&key.to_string())
+    }
+
+    /// Returns the time until the full quota for the rate limit `key` is restored, or `None`
+    /// if no quota applies to the key.
+    #[must_use]
+    pub fn reset_after(&self, key: &str) -> Option<Duration> {
+        self.rate_limiter.reset_after(&key.to_string())
     }
 
     /// Send an HTTP request.
@@ -159,6 +456,11 @@ impl HttpClient {
     /// `headers`: The header key value pairs in the request.
     /// `body`: The bytes sent in the body of request.
     /// `keys`: The keys used for rate limiting the request.
+    /// `idempotent`: If the request may be retried, overriding the retry method allowlist.
+    ///
+    /// The request is retried if its method is in the `retry_config` methods, unless
+    /// `idempotent` overrides this (e.g. to retry a POST to an idempotent endpoint). Once
+    /// `max_attempts` is reached the final error or response is returned.
     ///
     /// # Example
     ///
@@ -174,14 +476,241 @@ impl HttpClient {
         body: Option<Vec<u8>>,
         keys: Option<Vec<String>>,
         timeout_secs: Option<u64>,
+        idempotent: Option<bool>,
     ) -> Result<HttpResponse, HttpClientError> {
-        let rate_limiter = self.rate_limiter.clone();
+        let Some(config) = self
+            .retry_config
+            .as_ref()
+            .filter(|config| idempotent.unwrap_or_else(|| config.allows(&method)))
+        else {
+            return self
+                .send_attempt(method, url, headers, body, keys, timeout_secs)
+                .await;
+        };
 
-        rate_limiter.await_keys_ready(keys).await;
+        let mut backoff = config.backoff();
+        let mut attempt = 1;
+        loop {
+            let result = self
+                .send_attempt(
+                    method.clone(),
+                    url.clone(),
+                    headers.clone(),
+                    body.clone(),
+                    keys.clone(),
+                    timeout_secs,
+                )
+                .await;
+
+            let transient = match &result {
+                Ok(response) => matches!(response.status, 502..=504),
+                Err(e) => e.is_transient(),
+            };
+            if !transient || attempt >= config.max_attempts {
+                return result;
+            }
+
+            let delay = backoff.next_duration();
+            tracing::warn!(
+                "Transient failure for {method} {url}, retrying in {delay:?} (attempt {attempt}/{})",
+                config.max_attempts
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a single attempt of a request once the rate limit `keys` are ready and an
+    /// in-flight permit is acquired, releasing the permit when the attempt completes.
+    async fn send_attempt(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        keys: Option<Vec<String>>,
+        timeout_secs: Option<u64>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.rate_limiter.await_keys_ready(keys).await;
+        let _guard = self.inflight_limiter.acquire().await;
         self.client
             .send_request(method, url, headers, body, timeout_secs)
             .await
     }
+
+    /// Send an HTTP request to the URL rendered from a path template.
+    ///
+    /// `base_url`: The base URL the rendered path is appended to.
+    /// `template`: The path template, e.g. "/orders/{id}".
+    /// `path_params`: The values substituted for the template placeholders.
+    /// `query`: The query parameters appended to the URL.
+    ///
+    /// See [`render_url`] for how the URL is rendered and encoded.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_templated(
+        &self,
+        method: Method,
+        base_url: &str,
+        template: &str,
+        path_params: &HashMap<String, String>,
+        query: &HashMap<String, String>,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        keys: Option<Vec<String>>,
+        timeout_secs: Option<u64>,
+        idempotent: Option<bool>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        let url = render_url(base_url, template, path_params, query)?;
+        self.request(method, url, headers, body, keys, timeout_secs, idempotent)
+            .await
+    }
+
+    /// Send an HTTP GET request, returning the response body as a stream of chunks as
+    /// they arrive rather than buffered in memory, e.g. for large downloads.
+    ///
+    /// `url`: The request is sent to this url.
+    /// `headers`: The header key value pairs in the request.
+    /// `keys`: The keys used for rate limiting the request.
+    ///
+    /// The request is not retried, and counts as in-flight until the stream is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response status is not a success.
+    pub async fn get_stream(
+        &self,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        keys: Option<Vec<String>>,
+    ) -> Result<impl Stream<Item = Result<Bytes, HttpClientError>>, HttpClientError> {
+        self.rate_limiter.await_keys_ready(keys).await;
+        let guard = self.inflight_limiter.acquire().await;
+        let stream = self.client.send_get_stream(url, headers).await?;
+
+        Ok(stream.map(move |chunk| {
+            let _guard = &guard;
+            chunk
+        }))
+    }
+}
+
+/// Caps the number of concurrent in-flight requests of an [`HttpClient`].
+///
+/// Requests await a permit from the semaphore when `max_inflight` is set, and are
+/// tracked in the shared in-flight count until their [`InflightGuard`] is dropped.
+#[derive(Clone, Debug)]
+pub(crate) struct InflightLimiter {
+    semaphore: Option<Arc<Semaphore>>,
+    count: Arc<AtomicUsize>,
+}
+
+impl InflightLimiter {
+    pub(crate) fn new(max_inflight: Option<usize>) -> Self {
+        Self {
+            semaphore: max_inflight.map(|max| Arc::new(Semaphore::new(max))),
+            count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Waits for an in-flight permit (if capped) and counts the request as in-flight.
+    pub(crate) async fn acquire(&self) -> InflightGuard {
+        let permit = match &self.semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("In-flight semaphore should never be closed"),
+            ),
+            None => None,
+        };
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        InflightGuard {
+            _permit: permit,
+            count: self.count.clone(),
+        }
+    }
+}
+
+/// Releases the in-flight permit and count of a request when dropped.
+pub(crate) struct InflightGuard {
+    _permit: Option<OwnedSemaphorePermit>,
+    count: Arc<AtomicUsize>,
+}
+
+impl Drop for InflightGuard {
+    fn drop(&mut self) {
+        self.count.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Renders the URL for the path `template` appended to the `base_url`.
+///
+/// Each `{name}` placeholder in the template is substituted with the value of
+/// `path_params[name]`, and every path segment is percent-encoded so values may
+/// contain reserved characters such as `/`, `?` or `#`. The `query` parameters are
+/// form-urlencoded and appended sorted by key, so the URL is deterministic.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `base_url` is not a valid base URL.
+/// - If a placeholder is unclosed or has no value in `path_params`.
+pub fn render_url(
+    base_url: &str,
+    template: &str,
+    path_params: &HashMap<String, String>,
+    query: &HashMap<String, String>,
+) -> Result<String, HttpClientError> {
+    let mut url =
+        Url::parse(base_url).map_err(|e| HttpClientError::from(format!("URL parse error: {e}")))?;
+
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|()| HttpClientError::from(format!("Invalid base URL: {base_url}")))?;
+        segments.pop_if_empty();
+        for segment in template.split('/').filter(|segment| !segment.is_empty()) {
+            segments.push(&render_segment(segment, path_params)?);
+        }
+    }
+
+    if !query.is_empty() {
+        let mut pairs: Vec<(&String, &String)> = query.iter().collect();
+        pairs.sort();
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+
+    Ok(url.into())
+}
+
+fn render_segment(
+    segment: &str,
+    path_params: &HashMap<String, String>,
+) -> Result<String, HttpClientError> {
+    let mut rendered = String::new();
+    let mut rest = segment;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..].find('}').ok_or_else(|| {
+            HttpClientError::from(format!("Unclosed placeholder in path segment: {segment}"))
+        })? + start;
+        let name = &rest[start + 1..end];
+        let value = path_params
+            .get(name)
+            .ok_or_else(|| HttpClientError::from(format!("Missing path parameter: {name}")))?;
+        rendered.push_str(value);
+        rest = &rest[end + 1..];
+    }
+    rendered.push_str(rest);
+
+    Ok(rendered)
 }
 
 /// A high-performance `HttpClient` for HTTP requests.
@@ -196,6 +725,7 @@ impl HttpClient {
 pub struct InnerHttpClient {
     pub(crate) client: reqwest::Client,
     pub(crate) header_keys: Arc<Vec<String>>,
+    pub(crate) hooks: HttpHooks,
 }
 
 impl InnerHttpClient {
@@ -214,9 +744,101 @@ impl InnerHttpClient {
         body: Option<Vec<u8>>,
         timeout_secs: Option<u64>,
     ) -> Result<HttpResponse, HttpClientError> {
+        let request = self.build_request(method, &url, headers, body, timeout_secs)?;
+
+        tracing::trace!("{request:?}");
+
+        self.hooks.request(&request);
+        let start = Instant::now();
+
+        let result = match self.client.execute(request).await {
+            Ok(response) => self.to_response(response).await,
+            Err(e) => Err(HttpClientError::from(e)),
+        };
+        match &result {
+            Ok(response) => {
+                self.hooks
+                    .response(response.status, start.elapsed(), response.body.len());
+            }
+            Err(e) => self.hooks.error(e, start.elapsed()),
+        }
+
+        result
+    }
+
+    /// Sends an HTTP GET request to the `url`, returning the response body as a stream of
+    /// chunks as they arrive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response status is not a success.
+    pub async fn send_get_stream(
+        &self,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<impl Stream<Item = Result<Bytes, HttpClientError>>, HttpClientError> {
+        let request = self.build_request(Method::GET, &url, headers, None, None)?;
+
+        tracing::trace!("{request:?}");
+
+        self.hooks.request(&request);
+        let start = Instant::now();
+
+        let response = match self.client.execute(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                let e = HttpClientError::from(e);
+                self.hooks.error(&e, start.elapsed());
+                return Err(e);
+            }
+        };
+
+        tracing::trace!("{response:?}");
+
+        let status = response.status().as_u16();
+        let status_error = response.error_for_status_ref().err();
+        if let Some(e) = status_error {
+            let body_size = response.bytes().await.map_or(0, |body| body.len());
+            self.hooks.response(status, start.elapsed(), body_size);
+            return Err(HttpClientError::from(e));
+        }
+
+        // Passes the response to the hooks once the body ends, or the error if it fails
+        let state = (Box::pin(response.bytes_stream()), 0, self.hooks.clone());
+        Ok(futures_util::stream::unfold(
+            Some(state),
+            move |state| async move {
+                let (mut body, body_size, hooks) = state?;
+                match body.next().await {
+                    Some(Ok(chunk)) => {
+                        let body_size = body_size + chunk.len();
+                        Some((Ok(chunk), Some((body, body_size, hooks))))
+                    }
+                    Some(Err(e)) => {
+                        let e = HttpClientError::from(e);
+                        hooks.error(&e, start.elapsed());
+                        Some((Err(e), None))
+                    }
+                    None => {
+                        hooks.response(status, start.elapsed(), body_size);
+                        None
+                    }
+                }
+            },
+        ))
+    }
+
+    fn build_request(
+        &self,
+        method: Method,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+    ) -> Result<Request, HttpClientError> {
         let headers = headers.unwrap_or_default();
-        let reqwest_url = Url::parse(url.as_str())
-            .map_err(|e| HttpClientError::from(format!("URL parse error: {e}")))?;
+        let reqwest_url =
+            Url::parse(url).map_err(|e| HttpClientError::from(format!("URL parse error: {e}")))?;
 
         let mut header_map = HeaderMap::new();
         for (header_key, header_value) in &headers {
@@ -236,23 +858,11 @@ impl InnerHttpClient {
             request_builder = request_builder.timeout(Duration::new(timeout_secs, 0));
         }
 
-        let request = match body {
-            Some(b) => request_builder
-                .body(b)
-                .build()
-                .map_err(HttpClientError::from)?,
-            None => request_builder.build().map_err(HttpClientError::from)?,
-        };
-
-        tracing::trace!("{request:?}");
-
-        let response = self
-            .client
-            .execute(request)
-            .await
-            .map_err(HttpClientError::from)?;
-
-        self.to_response(response).await
+        match body {
+            Some(b) => request_builder.body(b).build(),
+            None => request_builder.build(),
+        }
+        .map_err(HttpClientError::from)
     }
 
     /// Converts a `reqwest::Response` into an `HttpResponse`.
@@ -286,6 +896,7 @@ impl Default for InnerHttpClient {
         Self {
             client,
             header_keys: Default::default(),
+            hooks: HttpHooks::default(),
         }
     }
 }
@@ -295,13 +906,19 @@ impl Default for InnerHttpClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::net::{SocketAddr, TcpListener};
+    use std::{
+        net::{SocketAddr, TcpListener},
+        num::NonZeroU32,
+    };
 
     use axum::{
+        body::Body,
+        extract::ConnectInfo,
         routing::{delete, get, patch, post},
         serve, Router,
     };
     use http::status::StatusCode;
+    use rstest::rstest;
 
     use super::*;
 
@@ -323,6 +940,25 @@ mod tests {
             .route("/post", post(|| async { StatusCode::OK }))
             .route("/patch", patch(|| async { StatusCode::OK }))
             .route("/delete", delete(|| async { StatusCode::OK }))
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    StatusCode::OK
+                }),
+            )
+            .route("/stream", get(|| async { Body::from_stream(slow_body()) }))
+    }
+
+    const STREAM_CHUNKS: usize = 8;
+    const STREAM_CHUNK_SIZE: usize = 512 * 1024;
+
+    /// Returns a multi-megabyte body sent in chunks with a delay before each.
+    fn slow_body() -> impl Stream<Item = Result<Bytes, std::io::Error>> {
+        futures_util::stream::iter(0..STREAM_CHUNKS).then(|_| async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(Bytes::from(vec![7u8; STREAM_CHUNK_SIZE]))
+        })
     }
 
     async fn start_test_server() -> Result<SocketAddr, Box<dyn std::error::Error + Send + Sync>> {
@@ -447,4 +1083,697 @@ mod tests {
 
         assert_eq!(response.status, StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_request_templated() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}");
+
+        let client = HttpClient::new(HttpClientConfig::default()).unwrap();
+        let path_params = HashMap::from([("endpoint".to_string(), "get".to_string())]);
+        let response = client
+            .request_templated(
+                reqwest::Method::GET,
+                &url,
+                "/{endpoint}",
+                &path_params,
+                &HashMap::new(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(String::from_utf8_lossy(&response.body), "hello-world!");
+    }
+
+    #[tokio::test]
+    async fn test_max_inflight() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}/slow");
+
+        let max_inflight = 3;
+        let client = HttpClient::new(HttpClientConfig {
+            max_inflight: Some(max_inflight),
+            ..Default::default()
+        })
+        .unwrap();
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let client = client.clone();
+                let url = url.clone();
+                tokio::spawn(async move {
+                    client
+                        .request(reqwest::Method::GET, url, None, None, None, None, None)
+                        .await
+                })
+            })
+            .collect();
+
+        let mut max_observed = 0;
+        while !handles.iter().all(tokio::task::JoinHandle::is_finished) {
+            max_observed = max_observed.max(client.inflight_count());
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap().status, StatusCode::OK);
+        }
+        assert_eq!(max_observed, max_inflight);
+        assert_eq!(client.inflight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_remaining_quota_decrements_per_request() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}/get");
+
+        let key = "orders".to_string();
+        let quota = Quota::per_minute(NonZeroU32::new(3).unwrap());
+        let client = HttpClient::new(HttpClientConfig {
+            keyed_quotas: vec![(key.clone(), quota)],
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(client.remaining(&key), Some(3));
+        assert_eq!(client.reset_after(&key), Some(Duration::ZERO));
+
+        for expected in [2, 1, 0] {
+            client
+                .request(
+                    reqwest::Method::GET,
+                    url.clone(),
+                    None,
+                    None,
+                    Some(vec![key.clone()]),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            assert_eq!(client.remaining(&key), Some(expected));
+        }
+
+        // Each request takes 20s of the quota to replenish
+        assert!(client.reset_after(&key).unwrap() > Duration::from_secs(59));
+        assert_eq!(client.remaining("unknown"), None);
+    }
+
+    #[tokio::test]
+    async fn test_hooks() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}/get");
+
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let responses = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let on_request_requests = requests.clone();
+        let on_response_responses = responses.clone();
+        let hooks = HttpHooks {
+            on_request: Some(Arc::new(
+                move |method: &Method, url: &Url, headers: &HeaderMap| {
+                    let api_key = headers.get("api-key").cloned();
+                    on_request_requests.lock().unwrap().push((
+                        method.clone(),
+                        url.to_string(),
+                        api_key,
+                    ));
+                },
+            )),
+            on_response: Some(Arc::new(
+                move |status: u16, latency: Duration, body_size: usize| {
+                    on_response_responses
+                        .lock()
+                        .unwrap()
+                        .push((status, latency, body_size));
+                },
+            )),
+            on_error: None,
+        };
+        let client = HttpClient::new(HttpClientConfig {
+            hooks,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let headers = HashMap::from([("api-key".to_string(), "secret".to_string())]);
+        client
+            .request(
+                reqwest::Method::GET,
+                url.clone(),
+                Some(headers),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![(Method::GET, url, Some(HeaderValue::from_static("secret")))]
+        );
+        let responses = responses.lock().unwrap();
+        assert_eq!(responses.len(), 1);
+        let (status, latency, body_size) = responses[0];
+        assert_eq!(status, 200);
+        assert!(latency > Duration::ZERO);
+        assert_eq!(body_size, "hello-world!".len());
+    }
+
+    /// Hook events recorded by [`recording_hooks`].
+    type HookEvents = Arc<std::sync::Mutex<Vec<String>>>;
+
+    /// Returns hooks recording each request URL path, response status and body size, and
+    /// error, in the order the hooks are called.
+    fn recording_hooks() -> (HttpHooks, HookEvents) {
+        let events = HookEvents::default();
+        let (on_request, on_response, on_error) = (events.clone(), events.clone(), events.clone());
+        let hooks = HttpHooks {
+            on_request: Some(Arc::new(move |_: &Method, url: &Url, _: &HeaderMap| {
+                on_request
+                    .lock()
+                    .unwrap()
+                    .push(format!("request {}", url.path()));
+            })),
+            on_response: Some(Arc::new(
+                move |status: u16, _: Duration, body_size: usize| {
+                    on_response
+                        .lock()
+                        .unwrap()
+                        .push(format!("response {status} {body_size}"));
+                },
+            )),
+            on_error: Some(Arc::new(move |error: &HttpClientError, _: Duration| {
+                let kind = match error {
+                    HttpClientError::Error(_) => "error",
+                    HttpClientError::ConnectionError(_) => "connection",
+                    HttpClientError::TimeoutError(_) => "timeout",
+                };
+                on_error.lock().unwrap().push(format!("error {kind}"));
+            })),
+        };
+        (hooks, events)
+    }
+
+    #[tokio::test]
+    async fn test_hooks_on_connection_error() {
+        let port = get_unique_port();
+        let (hooks, events) = recording_hooks();
+        let client = HttpClient::new(HttpClientConfig {
+            hooks,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let url = format!("http://127.0.0.1:{port}/get");
+        let result = client
+            .request(
+                reqwest::Method::GET,
+                url.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert!(result.is_err());
+        let result = client.get_stream(url, None, None).await;
+        assert!(result.is_err());
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "request /get",
+                "error connection",
+                "request /get",
+                "error connection",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_hooks() {
+        let addr = start_test_server().await.unwrap();
+        let (hooks, events) = recording_hooks();
+        let client = HttpClient::new(HttpClientConfig {
+            hooks,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let stream = client
+            .get_stream(format!("http://{addr}/stream"), None, None)
+            .await
+            .unwrap();
+        assert_eq!(*events.lock().unwrap(), vec!["request /stream"]);
+
+        // The response is passed to the hooks once the body has been received in full
+        let chunks: Vec<_> = stream.collect().await;
+        assert!(chunks.iter().all(Result::is_ok));
+        let result = client
+            .get_stream(format!("http://{addr}/missing"), None, None)
+            .await;
+        assert!(result.is_err());
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "request /stream".to_string(),
+                format!("response 200 {}", STREAM_CHUNKS * STREAM_CHUNK_SIZE),
+                "request /missing".to_string(),
+                "response 404 0".to_string(),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("BTCUSDT", "https://api.example.com/v3/orders/BTCUSDT")]
+    #[case("BTC/USDT", "https://api.example.com/v3/orders/BTC%2FUSDT")]
+    #[case("a b?c#d%e", "https://api.example.com/v3/orders/a%20b%3Fc%23d%25e")]
+    #[case("caf\u{e9}", "https://api.example.com/v3/orders/caf%C3%A9")]
+    fn test_render_url_encodes_path_params(#[case] id: &str, #[case] expected: &str) {
+        let path_params = HashMap::from([("id".to_string(), id.to_string())]);
+
+        let url = render_url(
+            "https://api.example.com/v3/",
+            "/orders/{id}",
+            &path_params,
+            &HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(url, expected);
+    }
+
+    #[rstest]
+    fn test_render_url_multiple_placeholders_and_sorted_query() {
+        let path_params = HashMap::from([
+            ("symbol".to_string(), "ETH/USD".to_string()),
+            ("format".to_string(), "json".to_string()),
+        ]);
+        let query = HashMap::from([
+            ("symbol".to_string(), "BTC/USDT".to_string()),
+            ("limit".to_string(), "10".to_string()),
+            ("note".to_string(), "a b&c=d+e".to_string()),
+        ]);
+
+        let url = render_url(
+            "https://api.example.com",
+            "/markets/{symbol}/trades.{format}",
+            &path_params,
+            &query,
+        )
+        .unwrap();
+
+        assert_eq!(
+            url,
+            "https://api.example.com/markets/ETH%2FUSD/trades.json?limit=10&note=a+b%26c%3Dd%2Be&symbol=BTC%2FUSDT"
+        );
+    }
+
+    #[rstest]
+    #[case("/orders/{id}", "Missing path parameter: id")]
+    #[case("/orders/{id", "Unclosed placeholder in path segment: {id")]
+    fn test_render_url_invalid_template(#[case] template: &str, #[case] expected: &str) {
+        let result = render_url(
+            "https://api.example.com",
+            template,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("HTTP error occurred: {expected}")
+        );
+    }
+
+    /// Starts a server whose `/flaky` endpoint responds 503 to the first `failures`
+    /// requests and 200 thereafter, returning the address and the request count.
+    async fn start_flaky_server(failures: usize) -> (SocketAddr, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let handler = {
+            let count = count.clone();
+            move || {
+                let count = count.clone();
+                async move {
+                    if count.fetch_add(1, Ordering::SeqCst) < failures {
+                        StatusCode::SERVICE_UNAVAILABLE
+                    } else {
+                        StatusCode::OK
+                    }
+                }
+            }
+        };
+        let router = Router::new().route("/flaky", get(handler.clone()).post(handler));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(listener, router).await.unwrap();
+        });
+
+        (addr, count)
+    }
+
+    async fn request_flaky(
+        method: reqwest::Method,
+        max_attempts: u32,
+        idempotent: Option<bool>,
+    ) -> (u16, usize) {
+        let (addr, count) = start_flaky_server(2).await;
+        let client = HttpClient::new(HttpClientConfig {
+            retry_config: Some(HttpRetryConfig {
+                max_attempts,
+                delay_initial_ms: 10,
+                delay_max_ms: 50,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let response = client
+            .request(
+                method,
+                format!("http://{addr}/flaky"),
+                None,
+                None,
+                None,
+                None,
+                idempotent,
+            )
+            .await
+            .unwrap();
+
+        (response.status, count.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_request_retries_idempotent_method() {
+        let (status, count) = request_flaky(reqwest::Method::GET, 3, None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_request_returns_final_response_when_attempts_exhausted() {
+        let (status, count) = request_flaky(reqwest::Method::GET, 2, None).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_request_does_not_retry_post_by_default() {
+        let (status, count) = request_flaky(reqwest::Method::POST, 3, None).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_retries_post_when_idempotent() {
+        let (status, count) = request_flaky(reqwest::Method::POST, 3, Some(true)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_request_surfaces_final_connection_error() {
+        let port = get_unique_port();
+        let client = HttpClient::new(HttpClientConfig {
+            retry_config: Some(HttpRetryConfig {
+                max_attempts: 3,
+                delay_initial_ms: 10,
+                delay_max_ms: 50,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = client
+            .request(
+                reqwest::Method::GET,
+                format!("http://127.0.0.1:{port}/flaky"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(HttpClientError::ConnectionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retry_acquires_rate_limit_quota_per_attempt() {
+        let (addr, count) = start_flaky_server(2).await;
+        let key = "flaky".to_string();
+        let client = HttpClient::new(HttpClientConfig {
+            keyed_quotas: vec![(key.clone(), Quota::per_minute(NonZeroU32::new(10).unwrap()))],
+            retry_config: Some(
+                HttpRetryConfig::new(3, vec![HttpMethod::GET], 10, 50, BackoffStrategy::FIXED)
+                    .unwrap(),
+            ),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let response = client
+            .request(
+                reqwest::Method::GET,
+                format!("http://{addr}/flaky"),
+                None,
+                None,
+                Some(vec![key.clone()]),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+        assert_eq!(client.remaining(&key), Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_retry_releases_inflight_permit_during_backoff() {
+        let (addr, count) = start_flaky_server(1).await;
+        let url = format!("http://{addr}/flaky");
+        let client = HttpClient::new(HttpClientConfig {
+            max_inflight: Some(1),
+            retry_config: Some(
+                HttpRetryConfig::new(
+                    2,
+                    vec![HttpMethod::GET],
+                    1_000,
+                    1_000,
+                    BackoffStrategy::FIXED,
+                )
+                .unwrap(),
+            ),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let retried = {
+            let client = client.clone();
+            let url = url.clone();
+            tokio::spawn(async move {
+                client
+                    .request(reqwest::Method::GET, url, None, None, None, None, None)
+                    .await
+            })
+        };
+        while count.load(Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        // Another request completes while the first waits to retry
+        let response = tokio::time::timeout(
+            Duration::from_millis(500),
+            client.request(reqwest::Method::GET, url, None, None, None, None, None),
+        )
+        .await
+        .expect("request should not wait for the retry backoff")
+        .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(!retried.is_finished());
+        assert_eq!(client.inflight_count(), 0);
+
+        assert_eq!(retried.await.unwrap().unwrap().status, StatusCode::OK);
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[rstest]
+    #[case(0, 10, 50, "max_attempts")]
+    #[case(3, 100, 50, "exceeds `delay_max_ms`")]
+    fn test_retry_config_invalid(
+        #[case] max_attempts: u32,
+        #[case] delay_initial_ms: u64,
+        #[case] delay_max_ms: u64,
+        #[case] expected: &str,
+    ) {
+        let result = HttpRetryConfig::new(
+            max_attempts,
+            vec![HttpMethod::GET],
+            delay_initial_ms,
+            delay_max_ms,
+            BackoffStrategy::EXPONENTIAL,
+        );
+
+        assert!(result.unwrap_err().to_string().contains(expected));
+    }
+
+    #[rstest]
+    fn test_new_rejects_invalid_retry_config() {
+        let retry_config = HttpRetryConfig {
+            max_attempts: 0,
+            ..Default::default()
+        };
+
+        let result = HttpClient::new(HttpClientConfig {
+            retry_config: Some(retry_config),
+            ..Default::default()
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_rejects_zero_max_inflight() {
+        let result = HttpClient::new(HttpClientConfig {
+            max_inflight: Some(0),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid u64 for 'max_inflight' not positive, was 0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_yields_chunks_incrementally() {
+        let addr = start_test_server().await.unwrap();
+        let client = HttpClient::new(HttpClientConfig::default()).unwrap();
+
+        let start = tokio::time::Instant::now();
+        let mut stream = Box::pin(
+            client
+                .get_stream(format!("http://{addr}/stream"), None, None)
+                .await
+                .unwrap(),
+        );
+
+        let mut chunks = 0;
+        let mut total = 0;
+        let mut first_chunk_at = None;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            first_chunk_at.get_or_insert_with(|| start.elapsed());
+            assert!(chunk.iter().all(|byte| *byte == 7));
+            assert_eq!(client.inflight_count(), 1);
+            chunks += 1;
+            total += chunk.len();
+        }
+
+        assert_eq!(total, STREAM_CHUNKS * STREAM_CHUNK_SIZE);
+        assert!(chunks > 1);
+        // The first chunk arrived well before the server finished sending the body
+        assert!(first_chunk_at.unwrap() < start.elapsed() / 2);
+
+        drop(stream);
+        assert_eq!(client.inflight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_error_status() {
+        let addr = start_test_server().await.unwrap();
+        let client = HttpClient::new(HttpClientConfig::default()).unwrap();
+
+        let result = client
+            .get_stream(format!("http://{addr}/missing"), None, None)
+            .await;
+
+        assert!(matches!(result, Err(HttpClientError::Error(_))));
+        assert_eq!(client.inflight_count(), 0);
+    }
+
+    /// Starts a server whose `/peer` endpoint responds with the client's port, which is
+    /// the same for requests sent over a reused connection.
+    async fn start_peer_server() -> SocketAddr {
+        let router = Router::new().route(
+            "/peer",
+            get(
+                |ConnectInfo(peer): ConnectInfo<SocketAddr>| async move { peer.port().to_string() },
+            ),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        });
+
+        addr
+    }
+
+    #[rstest]
+    #[case(1, true)]
+    #[case(0, false)]
+    #[tokio::test]
+    async fn test_pool_config_connection_reuse(
+        #[case] max_idle_per_host: usize,
+        #[case] reused: bool,
+    ) {
+        let addr = start_peer_server().await;
+        let pool_config = HttpPoolConfig {
+            max_idle_per_host: Some(max_idle_per_host),
+            idle_timeout_ms: Some(10_000),
+        };
+        let client = HttpClient::new(HttpClientConfig {
+            pool_config,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let mut peers = Vec::new();
+        for _ in 0..2 {
+            let response = client
+                .request(
+                    reqwest::Method::GET,
+                    format!("http://{addr}/peer"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status, StatusCode::OK);
+            peers.push(String::from_utf8(response.body.to_vec()).unwrap());
+        }
+
+        assert_eq!(peers[0] == peers[1], reused);
+    }
 }
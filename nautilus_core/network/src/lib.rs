@@ -26,7 +26,11 @@
 //!
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod backoff;
+pub mod buffer;
 pub mod http;
+pub mod reconnect;
+pub mod sequence;
 pub mod socket;
 pub mod websocket;
 
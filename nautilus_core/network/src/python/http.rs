@@ -19,10 +19,15 @@ use std::{
 };
 
 use bytes::Bytes;
+use nautilus_core::python::to_pyvalue_err;
 use pyo3::{create_exception, exceptions::PyException, prelude::*};
 
 use crate::{
-    http::{HttpClient, HttpClientError, HttpMethod, HttpResponse},
+    backoff::BackoffStrategy,
+    http::{
+        render_url, HttpClient, HttpClientConfig, HttpClientError, HttpMethod, HttpPoolConfig,
+        HttpResponse, HttpRetryConfig,
+    },
     ratelimiter::quota::Quota,
 };
 
@@ -36,7 +41,7 @@ impl HttpClientError {
     #[must_use]
     pub fn into_py_err(self) -> PyErr {
         match self {
-            Self::Error(e) => PyErr::new::<HttpError, _>(e),
+            Self::Error(e) | Self::ConnectionError(e) => PyErr::new::<HttpError, _>(e),
             Self::TimeoutError(e) => PyErr::new::<HttpTimeoutError, _>(e),
         }
     }
@@ -51,6 +56,40 @@ impl HttpMethod {
     }
 }
 
+#[pymethods]
+impl HttpRetryConfig {
+    #[new]
+    #[pyo3(signature = (max_attempts=3, methods=None, delay_initial_ms=500, delay_max_ms=5_000, backoff_strategy=BackoffStrategy::EXPONENTIAL))]
+    fn py_new(
+        max_attempts: u32,
+        methods: Option<Vec<HttpMethod>>,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_strategy: BackoffStrategy,
+    ) -> PyResult<Self> {
+        Self::new(
+            max_attempts,
+            methods.unwrap_or_else(|| Self::default().methods),
+            delay_initial_ms,
+            delay_max_ms,
+            backoff_strategy,
+        )
+        .map_err(to_pyvalue_err)
+    }
+}
+
+#[pymethods]
+impl HttpPoolConfig {
+    #[new]
+    #[pyo3(signature = (max_idle_per_host=None, idle_timeout_ms=None))]
+    const fn py_new(max_idle_per_host: Option<usize>, idle_timeout_ms: Option<u64>) -> Self {
+        Self {
+            max_idle_per_host,
+            idle_timeout_ms,
+        }
+    }
+}
+
 #[pymethods]
 impl HttpResponse {
     #[new]
@@ -91,6 +130,9 @@ impl HttpClient {
     /// `keyed_quota`: A list of string quota pairs that gives quota for specific key values.
     /// `default_quota`: The default rate limiting quota for any request.
     /// Default quota is optional and no quota is passthrough.
+    /// `max_inflight`: The maximum number of concurrent in-flight requests, which must be positive (no cap if `None`).
+    /// `retry_config`: The configuration for retrying transient failures (no retries if `None`).
+    /// `pool_config`: The configuration for the pool of idle connections (defaults if `None`).
     ///
     /// Rate limiting can be configured on a per-endpoint basis by passing
     /// key-value pairs of endpoint URLs and their respective quotas.
@@ -107,15 +149,48 @@ impl HttpClient {
     ///
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
     #[new]
-    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
-    #[must_use]
+    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None, max_inflight = None, retry_config = None, pool_config = None))]
     pub fn py_new(
         default_headers: HashMap<String, String>,
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
-    ) -> Self {
-        Self::new(default_headers, header_keys, keyed_quotas, default_quota)
+        max_inflight: Option<usize>,
+        retry_config: Option<HttpRetryConfig>,
+        pool_config: Option<HttpPoolConfig>,
+    ) -> PyResult<Self> {
+        Self::new(HttpClientConfig {
+            headers: default_headers,
+            header_keys,
+            keyed_quotas,
+            default_quota,
+            max_inflight,
+            retry_config,
+            pool_config: pool_config.unwrap_or_default(),
+            ..Default::default()
+        })
+        .map_err(to_pyvalue_err)
+    }
+
+    /// Returns the number of requests currently in-flight.
+    #[getter]
+    #[pyo3(name = "inflight_count")]
+    fn py_inflight_count(&self) -> usize {
+        self.inflight_count()
+    }
+
+    /// Returns the number of requests which can currently be made against the rate limit `key`
+    /// without waiting, or `None` if no quota applies to the key.
+    #[pyo3(name = "remaining")]
+    fn py_remaining(&self, key: &str) -> Option<u32> {
+        self.remaining(key)
+    }
+
+    /// Returns the seconds until the full quota for the rate limit `key` is restored, or `None`
+    /// if no quota applies to the key.
+    #[pyo3(name = "reset_after")]
+    fn py_reset_after(&self, key: &str) -> Option<f64> {
+        self.reset_after(key).map(|duration| duration.as_secs_f64())
     }
 
     /// Send an HTTP request.
@@ -125,6 +200,7 @@ impl HttpClient {
     /// `headers`: The header key value pairs in the request.
     /// `body`: The bytes sent in the body of request.
     /// `keys`: The keys used for rate limiting the request.
+    /// `idempotent`: If the request may be retried, overriding the retry method allowlist.
     ///
     /// # Example
     ///
@@ -133,7 +209,7 @@ impl HttpClient {
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
     #[pyo3(name = "request")]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, timeout_secs=None))]
+    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, timeout_secs=None, idempotent=None))]
     fn py_request<'py>(
         &self,
         method: HttpMethod,
@@ -142,17 +218,72 @@ impl HttpClient {
         body: Option<Vec<u8>>,
         keys: Option<Vec<String>>,
         timeout_secs: Option<u64>,
+        idempotent: Option<bool>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
-        let rate_limiter = self.rate_limiter.clone();
+        let client = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            rate_limiter.await_keys_ready(keys).await;
             client
-                .send_request(method.into(), url, headers, body, timeout_secs)
+                .request(
+                    method.into(),
+                    url,
+                    headers,
+                    body,
+                    keys,
+                    timeout_secs,
+                    idempotent,
+                )
                 .await
                 .map_err(HttpClientError::into_py_err)
         })
     }
+
+    /// Send an HTTP request to the URL rendered from a path template.
+    ///
+    /// `method`: The HTTP method to call.
+    /// `url`: The base URL the rendered path is appended to.
+    /// `template`: The path template, e.g. "/orders/{id}".
+    /// `path_params`: The values substituted (percent-encoded) for the template placeholders.
+    /// `query`: The query parameters appended to the URL sorted by key.
+    /// `headers`: The header key value pairs in the request.
+    /// `body`: The bytes sent in the body of request.
+    /// `keys`: The keys used for rate limiting the request.
+    /// `idempotent`: If the request may be retried, overriding the retry method allowlist.
+    #[pyo3(name = "request_templated")]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (method, url, template, path_params=None, query=None, headers=None, body=None, keys=None, timeout_secs=None, idempotent=None))]
+    fn py_request_templated<'py>(
+        &self,
+        method: HttpMethod,
+        url: String,
+        template: String,
+        path_params: Option<HashMap<String, String>>,
+        query: Option<HashMap<String, String>>,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        keys: Option<Vec<String>>,
+        timeout_secs: Option<u64>,
+        idempotent: Option<bool>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let url = render_url(
+            &url,
+            &template,
+            &path_params.unwrap_or_default(),
+            &query.unwrap_or_default(),
+        )
+        .map_err(HttpClientError::into_py_err)?;
+
+        self.py_request(
+            method,
+            url,
+            headers,
+            body,
+            keys,
+            timeout_secs,
+            idempotent,
+            py,
+        )
+    }
 }
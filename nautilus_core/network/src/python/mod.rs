@@ -23,18 +23,25 @@ use pyo3::{prelude::*, PyTypeCheck};
 
 use crate::python::{
     http::{HttpError, HttpTimeoutError},
-    websocket::WebSocketClientError,
+    socket::SocketSendTimeoutError,
+    websocket::{WebSocketClientError, WebSocketFatalError, WebSocketRetryableError},
 };
 
 /// Loaded as nautilus_pyo3.network
 #[pymodule]
 pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<crate::backoff::BackoffStrategy>()?;
+    m.add_class::<crate::buffer::BufferBudget>()?;
     m.add_class::<crate::http::HttpClient>()?;
     m.add_class::<crate::http::HttpMethod>()?;
     m.add_class::<crate::http::HttpResponse>()?;
+    m.add_class::<crate::http::HttpRetryConfig>()?;
+    m.add_class::<crate::http::HttpPoolConfig>()?;
     m.add_class::<crate::ratelimiter::quota::Quota>()?;
+    m.add_class::<crate::websocket::ConnectionState>()?;
     m.add_class::<crate::websocket::WebSocketClient>()?;
     m.add_class::<crate::websocket::WebSocketConfig>()?;
+    m.add_class::<crate::websocket::WsMessageType>()?;
     m.add_class::<crate::socket::SocketClient>()?;
     m.add_class::<crate::socket::SocketConfig>()?;
 
@@ -43,6 +50,14 @@ pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         <WebSocketClientError as PyTypeCheck>::NAME,
         m.py().get_type_bound::<WebSocketClientError>(),
     )?;
+    m.add(
+        <WebSocketRetryableError as PyTypeCheck>::NAME,
+        m.py().get_type_bound::<WebSocketRetryableError>(),
+    )?;
+    m.add(
+        <WebSocketFatalError as PyTypeCheck>::NAME,
+        m.py().get_type_bound::<WebSocketFatalError>(),
+    )?;
     m.add(
         <HttpError as PyTypeCheck>::NAME,
         m.py().get_type_bound::<HttpError>(),
@@ -51,6 +66,10 @@ pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         <HttpTimeoutError as PyTypeCheck>::NAME,
         m.py().get_type_bound::<HttpTimeoutError>(),
     )?;
+    m.add(
+        <SocketSendTimeoutError as PyTypeCheck>::NAME,
+        m.py().get_type_bound::<SocketSendTimeoutError>(),
+    )?;
 
     Ok(())
 }
@@ -13,25 +13,77 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::{atomic::Ordering, Arc},
+};
 
 use nautilus_core::python::to_pyruntime_err;
-use pyo3::prelude::*;
-use tokio::io::AsyncWriteExt;
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
 use tokio_tungstenite::tungstenite::stream::Mode;
 
-use crate::socket::{SocketClient, SocketConfig};
+use crate::{
+    backoff::BackoffStrategy,
+    buffer::BufferBudget,
+    socket::{flush_data, SocketClient, SocketConfig},
+};
+
+// Python exception class for socket sends which time out.
+create_exception!(network, SocketSendTimeoutError, PyException);
+
+fn to_send_err(e: std::io::Error) -> PyErr {
+    match e.kind() {
+        std::io::ErrorKind::TimedOut => PyErr::new::<SocketSendTimeoutError, _>(e.to_string()),
+        _ => e.into(),
+    }
+}
+
+#[pymethods]
+impl BufferBudget {
+    #[new]
+    fn py_new(capacity: NonZeroUsize) -> Self {
+        Self::new(capacity)
+    }
+
+    /// Return the total bytes of the budget.
+    #[getter]
+    #[pyo3(name = "capacity")]
+    fn py_capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    /// Return the bytes of the budget not currently held by any buffer.
+    #[pyo3(name = "available")]
+    fn py_available(&self) -> usize {
+        self.available()
+    }
+}
 
 #[pymethods]
 impl SocketConfig {
     #[new]
-    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None, certs_dir=None, cert_expiry_lead_secs=None, buffer_budget=None, max_reconnect_attempts=None, send_channel_capacity=None, heartbeat_timeout_ms=None, reconnect_backoff=None, nodelay=true, recv_buffer_size=None, send_buffer_size=None, auto_flush=false, reconnect_delay_initial_ms=None, reconnect_delay_max_ms=None))]
     fn py_new(
         url: String,
         ssl: bool,
         suffix: Vec<u8>,
         handler: PyObject,
         heartbeat: Option<(u64, Vec<u8>)>,
+        certs_dir: Option<PathBuf>,
+        cert_expiry_lead_secs: Option<u64>,
+        buffer_budget: Option<BufferBudget>,
+        max_reconnect_attempts: Option<u32>,
+        send_channel_capacity: Option<NonZeroUsize>,
+        heartbeat_timeout_ms: Option<u64>,
+        reconnect_backoff: Option<BackoffStrategy>,
+        nodelay: bool,
+        recv_buffer_size: Option<usize>,
+        send_buffer_size: Option<usize>,
+        auto_flush: bool,
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
     ) -> Self {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
         Self {
@@ -40,6 +92,19 @@ impl SocketConfig {
             suffix,
             handler: Arc::new(handler),
             heartbeat,
+            heartbeat_timeout_ms,
+            certs_dir,
+            cert_expiry_lead_secs,
+            buffer_budget,
+            max_reconnect_attempts,
+            reconnect_backoff: reconnect_backoff.unwrap_or(BackoffStrategy::FIXED),
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            send_channel_capacity,
+            nodelay,
+            recv_buffer_size,
+            send_buffer_size,
+            auto_flush,
         }
     }
 }
@@ -48,17 +113,21 @@ impl SocketConfig {
 impl SocketClient {
     /// Create a socket client.
     ///
+    /// The optional `on_reconnect_attempt` handler is called with `(attempt, delay_ms)`
+    /// before each reconnect attempt.
+    ///
     /// # Errors
     ///
     /// - Throws an Exception if it is unable to make socket connection.
     #[staticmethod]
     #[pyo3(name = "connect")]
-    #[pyo3(signature = (config, post_connection=None, post_reconnection=None, post_disconnection=None))]
+    #[pyo3(signature = (config, post_connection=None, post_reconnection=None, post_disconnection=None, on_reconnect_attempt=None))]
     fn py_connect(
         config: SocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        on_reconnect_attempt: Option<PyObject>,
         py: Python<'_>,
     ) -> PyResult<Bound<PyAny>> {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
@@ -67,6 +136,7 @@ impl SocketClient {
                 post_connection,
                 post_reconnection,
                 post_disconnection,
+                on_reconnect_attempt,
             )
             .await
             .map_err(to_pyruntime_err)
@@ -106,8 +176,57 @@ impl SocketClient {
         !slf.controller_task.is_finished()
     }
 
+    /// Return the total bytes currently held in the client's receive buffer.
+    #[pyo3(name = "buffered_bytes")]
+    fn py_buffered_bytes(slf: PyRef<'_, Self>) -> usize {
+        slf.buffered_bytes()
+    }
+
+    /// Return the number of times the client has successfully reconnected, which is
+    /// reset to zero once the client is closed.
+    #[pyo3(name = "reconnect_count")]
+    fn py_reconnect_count(slf: PyRef<'_, Self>) -> u64 {
+        slf.reconnect_count()
+    }
+
+    /// Return the UNIX nanoseconds the current connection was established, or `None`
+    /// while the client is reconnecting or closed.
+    #[pyo3(name = "connected_since")]
+    fn py_connected_since(slf: PyRef<'_, Self>) -> Option<u64> {
+        slf.connected_since().map(|ts| ts.as_u64())
+    }
+
+    /// Return the delay (milliseconds) before the next reconnect attempt, or `None` while
+    /// not reconnecting.
+    #[pyo3(name = "current_reconnect_delay_ms")]
+    fn py_current_reconnect_delay_ms(slf: PyRef<'_, Self>) -> Option<u64> {
+        slf.current_reconnect_delay_ms()
+    }
+
+    /// Return the total bytes read from the socket, across reconnects.
+    #[pyo3(name = "bytes_received")]
+    fn py_bytes_received(slf: PyRef<'_, Self>) -> u64 {
+        slf.bytes_received()
+    }
+
+    /// Return the number of messages passed to the handler, across reconnects.
+    #[pyo3(name = "messages_received")]
+    fn py_messages_received(slf: PyRef<'_, Self>) -> u64 {
+        slf.messages_received()
+    }
+
+    /// Return the UNIX nanoseconds the last message was passed to the handler, or `None`
+    /// if no message has been received.
+    #[pyo3(name = "last_message_ns")]
+    fn py_last_message_ns(slf: PyRef<'_, Self>) -> Option<u64> {
+        slf.last_message_ns().map(|ts| ts.as_u64())
+    }
+
     /// Send bytes data to the connection.
     ///
+    /// With a `send_channel_capacity` configured the data is queued for the writer task in call
+    /// order, without waiting on the writer lock.
+    ///
     /// # Errors
     ///
     /// - Throws an Exception if it is not able to send data.
@@ -117,15 +236,79 @@ impl SocketClient {
         mut data: Vec<u8>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let writer = slf.writer.clone();
         data.extend(&slf.suffix);
+        let send = slf.send_frame(data, None);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            send.await?;
+            Ok(())
+        })
+    }
+
+    /// Send UTF-8 text data to the connection.
+    ///
+    /// # Errors
+    ///
+    /// - Throws an Exception if the client is not active or it is not able to send data.
+    #[pyo3(name = "send_text")]
+    fn py_send_text<'py>(
+        slf: PyRef<'_, Self>,
+        data: String,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if !slf.is_active() {
+            return Err(to_pyruntime_err("Client is not active"));
+        }
+
+        let mut data = data.into_bytes();
+        data.extend(&slf.suffix);
+        let send = slf.send_frame(data, None);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            send.await?;
+            Ok(())
+        })
+    }
 
+    /// Flush the writer, so any data buffered by the stream (e.g. by TLS) is written
+    /// to the socket.
+    ///
+    /// # Errors
+    ///
+    /// - Throws an Exception if it is not able to flush.
+    #[pyo3(name = "flush")]
+    fn py_flush<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let writer = slf.writer.clone();
+        let writer_poisoned = slf.writer_poisoned.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let mut writer = writer.lock().await;
-            writer.write_all(&data).await?;
+            flush_data(&mut *writer.lock().await, &writer_poisoned).await?;
             Ok(())
         })
     }
+
+    /// Send bytes data to the connection, waiting at most `timeout_ms` for the send
+    /// to complete.
+    ///
+    /// A send which times out may or may not have been delivered. The client stops
+    /// writing to the connection and reconnects, dropping any data still queued, so
+    /// only sends which are safe to repeat should be retried.
+    ///
+    /// # Errors
+    ///
+    /// - Throws a `SocketSendTimeoutError` if the send does not complete within `timeout_ms`.
+    /// - Throws an Exception if it is not able to send data.
+    #[pyo3(name = "send_with_timeout")]
+    fn py_send_with_timeout<'py>(
+        slf: PyRef<'_, Self>,
+        mut data: Vec<u8>,
+        timeout_ms: u64,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        data.extend(&slf.suffix);
+        let send = slf.send_frame(data, Some(timeout_ms));
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            async move { send.await.map_err(to_send_err) },
+        )
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -134,19 +317,50 @@ impl SocketClient {
 #[cfg(test)]
 mod tests {
 
-    use std::sync::Arc;
+    use std::{
+        num::NonZeroUsize,
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::SystemTime,
+    };
 
+    use nautilus_core::uuid::UUID4;
+    use nautilus_cryptography::{providers::install_cryptographic_provider, tls::load_client_cert};
+    use nonzero_ext::nonzero;
     use pyo3::{prelude::*, prepare_freethreaded_python};
+    use rcgen::{
+        BasicConstraints, Certificate, CertificateParams, ExtendedKeyUsagePurpose, IsCa, KeyPair,
+    };
+    use rstest::rstest;
+    use rustls::{
+        pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer},
+        server::WebPkiClientVerifier,
+        RootCertStore, ServerConfig,
+    };
+    use socket2::SockRef;
+    use time::OffsetDateTime;
     use tokio::{
         io::{AsyncReadExt, AsyncWriteExt},
-        net::TcpListener,
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
         task::{self, JoinHandle},
-        time::{sleep, Duration},
+        time::{sleep, timeout, Duration, Instant},
     };
+    use tokio_rustls::TlsAcceptor;
     use tokio_tungstenite::tungstenite::stream::Mode;
     use tracing_test::traced_test;
 
-    use crate::socket::{SocketClient, SocketConfig};
+    use crate::{
+        backoff::{Backoff, BackoffStrategy, ExponentialBackoff},
+        buffer::BufferBudget,
+        socket::{
+            set_socket_options, ConnectionStats, RestartPolicy, SocketClient, SocketConfig,
+            SocketSupervisor,
+        },
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -168,48 +382,233 @@ mod tests {
             let handle = task::spawn(async move {
                 // Keep listening for new connections
                 loop {
-                    let (mut stream, _) = server.accept().await.unwrap();
+                    let (stream, _) = server.accept().await.unwrap();
                     tracing::debug!("socket:test Server accepted connection");
+                    task::spawn(Self::echo(stream));
+                }
+            });
+
+            Self { task: handle, port }
+        }
+
+        /// Echoes connections as for [`TestServer::basic_client_test`], notifying each
+        /// connection accepted so tests can wait on (re)connects rather than sleeping.
+        async fn notifying() -> (Self, mpsc::UnboundedReceiver<()>) {
+            let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = TcpListener::local_addr(&server).unwrap().port();
+            let (tx, rx) = mpsc::unbounded_channel();
+
+            let handle = task::spawn(async move {
+                loop {
+                    let (stream, _) = server.accept().await.unwrap();
+                    let _ = tx.send(());
+                    task::spawn(Self::echo(stream));
+                }
+            });
+
+            (Self { task: handle, port }, rx)
+        }
+
+        /// Keeps receiving messages from the connection and sending them back as is,
+        /// until a `close` message is received, which drops the connection.
+        async fn echo(mut stream: TcpStream) {
+            let mut buf = Vec::new();
+            loop {
+                let bytes = stream.read_buf(&mut buf).await.unwrap();
+                tracing::debug!("socket:test Server received {bytes} bytes");
+
+                // Terminate if 0 bytes have been read
+                // Connection has been terminated or vector buffer is completely
+                if bytes == 0 {
+                    break;
+                } else {
+                    // if received data has a line break
+                    // extract and write it to the stream
+                    while let Some((i, _)) =
+                        &buf.windows(2).enumerate().find(|(_, pair)| pair == b"\r\n")
+                    {
+                        let close_message = b"close".as_slice();
+                        if &buf[0..*i] == close_message {
+                            tracing::debug!("socket:test Client sent closing message");
+                            return;
+                        } else {
+                            tracing::debug!("socket:test Server sending message");
+                            stream
+                                .write_all(buf.drain(0..i + 2).as_slice())
+                                .await
+                                .unwrap();
+                        }
+                    }
+                }
+            }
+        }
+
+        /// Accepts connections and holds them open without reading, counting
+        /// the number of connections accepted.
+        async fn holding() -> (Self, Arc<AtomicUsize>) {
+            let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = TcpListener::local_addr(&server).unwrap().port();
+            let connections = Arc::new(AtomicUsize::new(0));
+            let counter = connections.clone();
+
+            let handle = task::spawn(async move {
+                let mut streams = Vec::new();
+                loop {
+                    let (stream, _) = server.accept().await.unwrap();
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    streams.push(stream);
+                }
+            });
+
+            (Self { task: handle, port }, connections)
+        }
+    }
+
+    /// A test certificate authority issuing server and client certificates.
+    struct TestPki {
+        ca: Certificate,
+        ca_key: KeyPair,
+    }
+
+    impl TestPki {
+        fn new() -> Self {
+            let mut params = CertificateParams::default();
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            let ca_key = KeyPair::generate().unwrap();
+            let ca = params.self_signed(&ca_key).unwrap();
+            Self { ca, ca_key }
+        }
+
+        fn issue(
+            &self,
+            name: &str,
+            usage: ExtendedKeyUsagePurpose,
+            valid_for: Duration,
+        ) -> (Certificate, KeyPair) {
+            let mut params = CertificateParams::new(vec![name.to_string()]).unwrap();
+            params.extended_key_usages = vec![usage];
+            params.not_after = OffsetDateTime::now_utc() + valid_for;
+            let key = KeyPair::generate().unwrap();
+            let cert = params.signed_by(&key, &self.ca, &self.ca_key).unwrap();
+            (cert, key)
+        }
+
+        /// Writes a client certificate valid for `valid_for` and the CA certificate
+        /// to `certs_dir`, returning the DER encoded client certificate.
+        fn write_client_cert(&self, certs_dir: &Path, valid_for: Duration) -> Vec<u8> {
+            let (cert, key) = self.issue("client", ExtendedKeyUsagePurpose::ClientAuth, valid_for);
+            std::fs::create_dir_all(certs_dir).unwrap();
+            std::fs::write(certs_dir.join("ca.crt"), self.ca.pem()).unwrap();
+            std::fs::write(certs_dir.join("client.crt"), cert.pem()).unwrap();
+            std::fs::write(certs_dir.join("client.key"), key.serialize_pem()).unwrap();
+            cert.der().to_vec()
+        }
+
+        /// Accepts TLS connections requiring a client certificate issued by this CA,
+        /// recording the certificate presented on each connection. Each line received
+        /// is echoed back until the client sends `close`.
+        async fn server(&self) -> (TestServer, Arc<Mutex<Vec<Vec<u8>>>>) {
+            install_cryptographic_provider();
+            let (cert, key) = self.issue(
+                "127.0.0.1",
+                ExtendedKeyUsagePurpose::ServerAuth,
+                Duration::from_secs(3600),
+            );
+            let mut roots = RootCertStore::empty();
+            roots.add(self.ca.der().clone()).unwrap();
+            let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .unwrap();
+            let config = ServerConfig::builder()
+                .with_client_cert_verifier(verifier)
+                .with_single_cert(
+                    vec![cert.der().clone()],
+                    PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.serialize_der())),
+                )
+                .unwrap();
+            let acceptor = TlsAcceptor::from(Arc::new(config));
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let presented = Arc::new(Mutex::new(Vec::new()));
+            let recorder = presented.clone();
+
+            let handle = task::spawn(async move {
+                loop {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    let Ok(mut stream) = acceptor.accept(stream).await else {
+                        continue;
+                    };
+                    let cert = stream.get_ref().1.peer_certificates().unwrap()[0].to_vec();
+                    recorder.lock().unwrap().push(cert);
 
-                    // Keep receiving messages from connection and sending them back as it is
-                    // if the message contains a close stop receiving messages
-                    // and drop the connection.
                     task::spawn(async move {
                         let mut buf = Vec::new();
-                        loop {
-                            let bytes = stream.read_buf(&mut buf).await.unwrap();
-                            tracing::debug!("socket:test Server received {bytes} bytes");
-
-                            // Terminate if 0 bytes have been read
-                            // Connection has been terminated or vector buffer is completely
-                            if bytes == 0 {
-                                break;
-                            } else {
-                                // if received data has a line break
-                                // extract and write it to the stream
-                                while let Some((i, _)) =
-                                    &buf.windows(2).enumerate().find(|(_, pair)| pair == b"\r\n")
-                                {
-                                    let close_message = b"close".as_slice();
-                                    if &buf[0..*i] == close_message {
-                                        tracing::debug!("socket:test Client sent closing message");
-                                        return;
-                                    } else {
-                                        tracing::debug!("socket:test Server sending message");
-                                        stream
-                                            .write_all(buf.drain(0..i + 2).as_slice())
-                                            .await
-                                            .unwrap();
-                                    }
+                        while stream.read_buf(&mut buf).await.unwrap_or(0) > 0 {
+                            while let Some(i) = buf.windows(2).position(|pair| pair == b"\r\n") {
+                                let line: Vec<u8> = buf.drain(..i + 2).collect();
+                                if line.starts_with(b"close") {
+                                    return;
                                 }
+                                stream.write_all(&line).await.unwrap();
+                                stream.flush().await.unwrap();
                             }
                         }
                     });
                 }
             });
 
-            Self { task: handle, port }
+            (TestServer { task: handle, port }, presented)
+        }
+    }
+
+    fn temp_certs_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("nautilus-certs-{}", UUID4::new()))
+    }
+
+    /// Returns the config for a plain client of the test server on `port` passing
+    /// `\r\n` terminated lines to `handler`, which tests override with the fields under test.
+    fn test_config(port: u16, handler: PyObject) -> SocketConfig {
+        SocketConfig {
+            url: format!("127.0.0.1:{port}"),
+            handler: Arc::new(handler),
+            mode: Mode::Plain,
+            suffix: b"\r\n".to_vec(),
+            heartbeat: None,
+            heartbeat_timeout_ms: None,
+            certs_dir: None,
+            cert_expiry_lead_secs: None,
+            buffer_budget: None,
+            max_reconnect_attempts: None,
+            reconnect_backoff: BackoffStrategy::FIXED,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            send_channel_capacity: None,
+            nodelay: true,
+            recv_buffer_size: None,
+            send_buffer_size: None,
+            auto_flush: false,
+        }
+    }
+
+    /// Waits for the next connection accepted by a [`TestServer::notifying`] server.
+    async fn next_accepted(accepted: &mut mpsc::UnboundedReceiver<()>) {
+        timeout(Duration::from_secs(5), accepted.recv())
+            .await
+            .expect("Timed out waiting for a connection")
+            .unwrap();
+    }
+
+    /// Polls `condition` until it holds, returning whether it held before `timeout`.
+    async fn wait_until(condition: impl Fn() -> bool, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while !condition() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            sleep(Duration::from_millis(10)).await;
         }
+        true
     }
 
     #[tokio::test]
@@ -250,14 +649,8 @@ counter = Counter()",
             (counter, handler)
         });
 
-        let config = SocketConfig {
-            url: format!("127.0.0.1:{}", server.port),
-            handler: Arc::new(handler),
-            mode: Mode::Plain,
-            suffix: b"\r\n".to_vec(),
-            heartbeat: None,
-        };
-        let client: SocketClient = SocketClient::connect(config, None, None, None)
+        let config = test_config(server.port, handler);
+        let client: SocketClient = SocketClient::connect(config, None, None, None, None)
             .await
             .unwrap();
 
@@ -312,4 +705,1280 @@ counter = Counter()",
         client.disconnect().await;
         assert!(client.is_disconnected());
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_supervisor_restarts_closed_client() {
+        prepare_freethreaded_python();
+
+        let (server, mut accepted) = TestServer::notifying().await;
+        let (_, handler) = recording_handler();
+
+        // The client closes as soon as the connection drops, rather than reconnecting
+        let config = SocketConfig {
+            max_reconnect_attempts: Some(0),
+            ..test_config(server.port, handler)
+        };
+
+        let policy = RestartPolicy::new(1, Duration::from_secs(60));
+        let mut supervisor = SocketSupervisor::new(policy);
+        let index = supervisor
+            .connect(config, None, None, None, None)
+            .await
+            .unwrap();
+        next_accepted(&mut accepted).await;
+
+        // Server drops the connection, so the client closes
+        supervisor
+            .client(index)
+            .unwrap()
+            .send_bytes(b"close")
+            .await
+            .unwrap();
+        let closed = wait_until(
+            || supervisor.client(index).unwrap().is_disconnected(),
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(closed);
+
+        // Supervisor recreates the client
+        assert_eq!(supervisor.check().await, 1);
+        next_accepted(&mut accepted).await;
+        assert_eq!(supervisor.restart_count(index), 1);
+        assert!(!supervisor.client(index).unwrap().is_disconnected());
+
+        // Close the client again, policy is now exhausted so no further restarts
+        supervisor
+            .client(index)
+            .unwrap()
+            .send_bytes(b"close")
+            .await
+            .unwrap();
+        let closed = wait_until(
+            || supervisor.client(index).unwrap().is_disconnected(),
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(closed);
+        assert_eq!(supervisor.check().await, 0);
+        assert!(supervisor.client(index).unwrap().is_disconnected());
+        assert!(accepted.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_supervisor_passes_handlers_to_restarted_client() {
+        prepare_freethreaded_python();
+
+        let (server, mut accepted) = TestServer::notifying().await;
+        let (_, handler) = recording_handler();
+        let (recorder, event_handler) = recording_handler();
+        let (post_connection, post_disconnection) = Python::with_gil(|py| {
+            let make = PyModule::from_code_bound(
+                py,
+                r"
+def make(handler, event):
+    return lambda: handler(event.encode())",
+                "",
+                "",
+            )
+            .unwrap()
+            .getattr("make")
+            .unwrap();
+            let make = |event: &str| make.call1((&event_handler, event)).unwrap().into_py(py);
+            (make("connected"), make("disconnected"))
+        });
+
+        let config = SocketConfig {
+            max_reconnect_attempts: Some(0),
+            ..test_config(server.port, handler)
+        };
+        let mut supervisor = SocketSupervisor::new(RestartPolicy::new(1, Duration::from_secs(60)));
+        let index = supervisor
+            .connect(
+                config,
+                Some(post_connection),
+                None,
+                Some(post_disconnection),
+                None,
+            )
+            .await
+            .unwrap();
+        next_accepted(&mut accepted).await;
+
+        // Server drops the connection, so the client closes and is restarted
+        supervisor
+            .client(index)
+            .unwrap()
+            .send_bytes(b"close")
+            .await
+            .unwrap();
+        let closed = wait_until(
+            || supervisor.client(index).unwrap().is_disconnected(),
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(closed);
+        assert_eq!(supervisor.check().await, 1);
+        next_accepted(&mut accepted).await;
+
+        assert_eq!(
+            received(&recorder),
+            vec!["connected", "disconnected", "connected"]
+        );
+        supervisor.disconnect_all().await;
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_rejects_unbounded_reconnect_attempts() {
+        prepare_freethreaded_python();
+
+        let (_, handler) = recording_handler();
+        let mut supervisor = SocketSupervisor::new(RestartPolicy::new(1, Duration::from_secs(60)));
+
+        // The config is validated before connecting, so no server is needed
+        let err = supervisor
+            .connect(test_config(1, handler), None, None, None, None)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("max_reconnect_attempts"));
+        assert!(supervisor.client(0).is_none());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_reconnect_count_and_connected_since() {
+        prepare_freethreaded_python();
+
+        const CYCLES: u64 = 3;
+
+        let (server, mut accepted) = TestServer::notifying().await;
+        let (_, handler) = recording_handler();
+        let client =
+            SocketClient::connect(test_config(server.port, handler), None, None, None, None)
+                .await
+                .unwrap();
+        next_accepted(&mut accepted).await;
+
+        assert_eq!(client.reconnect_count(), 0);
+        let mut connected_since = client.connected_since().unwrap();
+
+        for cycle in 1..=CYCLES {
+            // Server drops the connection, then accepts the reconnect
+            client.send_bytes(b"close").await.unwrap();
+            next_accepted(&mut accepted).await;
+            let reconnected =
+                wait_until(|| client.reconnect_count() == cycle, Duration::from_secs(5)).await;
+            assert!(reconnected);
+            let reconnected_since = client.connected_since().unwrap();
+            assert!(reconnected_since > connected_since);
+            connected_since = reconnected_since;
+        }
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        assert_eq!(client.connected_since(), None);
+        assert_eq!(client.reconnect_count(), 0);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_heartbeat_send_failure_triggers_reconnect() {
+        prepare_freethreaded_python();
+
+        let (server, connections) = TestServer::holding().await;
+        let port = server.port;
+
+        let handler = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+def handler(bytes):
+    pass",
+                "",
+                "",
+            )
+            .unwrap();
+            pymod.getattr("handler").unwrap().into_py(py)
+        });
+
+        let config = SocketConfig {
+            heartbeat: Some((1, b"heartbeat".to_vec())),
+            ..test_config(port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        // Shutdown the write end so the next heartbeat write fails while the
+        // read end stays open
+        client.writer.lock().await.shutdown().await.unwrap();
+        sleep(Duration::from_secs(2)).await;
+
+        // Client reconnected on a new connection
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_heartbeat_timeout_triggers_reconnect() {
+        prepare_freethreaded_python();
+
+        // The server accepts connections but never sends any data
+        let (server, connections) = TestServer::holding().await;
+        let port = server.port;
+
+        let handler = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+def handler(bytes):
+    pass",
+                "",
+                "",
+            )
+            .unwrap();
+            pymod.getattr("handler").unwrap().into_py(py)
+        });
+
+        let config = SocketConfig {
+            heartbeat_timeout_ms: Some(300),
+            ..test_config(port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        // Client reconnected once no data arrived within the timeout
+        sleep(Duration::from_millis(700)).await;
+        assert!(connections.load(Ordering::SeqCst) >= 2);
+        assert!(logs_contain("No data received within 300ms"));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_proactive_reconnect_before_cert_expiry() {
+        prepare_freethreaded_python();
+
+        let pki = TestPki::new();
+        let (server, presented) = pki.server().await;
+        let (_, handler) = recording_handler();
+
+        let certs_dir = temp_certs_dir();
+        let expiring = pki.write_client_cert(&certs_dir, Duration::from_secs(4));
+        let not_after = load_client_cert(&certs_dir).unwrap().not_after;
+
+        let config = SocketConfig {
+            mode: Mode::Tls,
+            certs_dir: Some(certs_dir.clone()),
+            cert_expiry_lead_secs: Some(2),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+        assert!(
+            wait_until(
+                || presented.lock().unwrap().len() == 1,
+                Duration::from_secs(1)
+            )
+            .await
+        );
+        assert_eq!(presented.lock().unwrap()[0], expiring);
+
+        // Renew the certificate on disk, the client picks it up when it proactively
+        // reconnects within the lead time of the expiring certificate
+        let renewed = pki.write_client_cert(&certs_dir, Duration::from_secs(3600));
+        assert!(
+            wait_until(
+                || presented.lock().unwrap().len() == 2,
+                Duration::from_secs(4)
+            )
+            .await
+        );
+        assert!(SystemTime::now() < not_after);
+        assert_eq!(presented.lock().unwrap()[1], renewed);
+        assert_eq!(client.client_cert(), Some(renewed));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+        std::fs::remove_dir_all(certs_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_reconnect_reloads_rotated_client_cert() {
+        prepare_freethreaded_python();
+
+        let pki = TestPki::new();
+        let (server, presented) = pki.server().await;
+        let (_, handler) = recording_handler();
+
+        let certs_dir = temp_certs_dir();
+        let original = pki.write_client_cert(&certs_dir, Duration::from_secs(3600));
+
+        let config = SocketConfig {
+            mode: Mode::Tls,
+            certs_dir: Some(certs_dir.clone()),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(client.client_cert(), Some(original.clone()));
+        assert!(
+            wait_until(
+                || presented.lock().unwrap().len() == 1,
+                Duration::from_secs(1)
+            )
+            .await
+        );
+        assert_eq!(presented.lock().unwrap()[0], original);
+
+        // Rotate the certificate files on disk, then have the server drop the connection
+        let rotated = pki.write_client_cert(&certs_dir, Duration::from_secs(3600));
+        assert_ne!(rotated, original);
+        client.send_bytes(b"close".as_slice()).await.unwrap();
+
+        // Client reconnected presenting the rotated certificate to the server
+        assert!(
+            wait_until(
+                || presented.lock().unwrap().len() == 2,
+                Duration::from_secs(3)
+            )
+            .await
+        );
+        assert_eq!(presented.lock().unwrap()[1], rotated);
+        assert_eq!(client.client_cert(), Some(rotated));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+        std::fs::remove_dir_all(certs_dir).unwrap();
+    }
+
+    fn recording_handler() -> (PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class Recorder:
+    def __init__(self):
+        self.received = []
+
+    def handler(self, bytes):
+        self.received.append(bytes.decode())
+
+    def get_received(self):
+        return self.received
+
+recorder = Recorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+            (recorder, handler)
+        })
+    }
+
+    fn received(recorder: &PyObject) -> Vec<String> {
+        Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_buffered_bytes() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+
+        // The server echoes lines ending with "\r\n" which the client only
+        // splits on its own "|" suffix, so partial messages stay buffered
+        let config = SocketConfig {
+            suffix: b"|".to_vec(),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        client
+            .writer
+            .lock()
+            .await
+            .write_all(b"partial\r\n")
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(client.buffered_bytes(), 9);
+        assert!(received(&recorder).is_empty());
+
+        client
+            .writer
+            .lock()
+            .await
+            .write_all(b"tail|\r\n")
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(client.buffered_bytes(), 2);
+        assert_eq!(received(&recorder), vec!["partial\r\ntail"]);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_receive_stats() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+
+        // The server echoes each line back with its "\r\n" suffix
+        let config = test_config(server.port, handler);
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(client.bytes_received(), 0);
+        assert_eq!(client.messages_received(), 0);
+        assert!(client.last_message_ns().is_none());
+
+        client.send_text("hello").await.unwrap();
+        client.send_text("world!").await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(received(&recorder), vec!["hello", "world!"]);
+        assert_eq!(client.bytes_received(), 15);
+        assert_eq!(client.messages_received(), 2);
+        let last_message_ns = client.last_message_ns().unwrap();
+        assert!(last_message_ns >= client.connected_since().unwrap());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_buffer_budget() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+        let budget = BufferBudget::new(nonzero!(16usize));
+
+        let config = SocketConfig {
+            suffix: b"|".to_vec(),
+            buffer_budget: Some(budget.clone()),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        // Messages within the budget are received
+        client
+            .writer
+            .lock()
+            .await
+            .write_all(b"ok|\r\n")
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(client.buffered_bytes(), 2);
+        assert_eq!(budget.available(), 14);
+        assert_eq!(received(&recorder), vec!["ok"]);
+
+        // A message exceeding the whole budget can never be buffered, the client
+        // drops the connection and reconnects with an empty buffer
+        let oversized = [b"a".repeat(20), b"\r\n".to_vec()].concat();
+        client
+            .writer
+            .lock()
+            .await
+            .write_all(&oversized)
+            .await
+            .unwrap();
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(client.buffered_bytes(), 0);
+        assert!(wait_until(|| budget.available() == 16, Duration::from_secs(2)).await);
+        assert_eq!(received(&recorder), vec!["ok"]);
+        assert!(logs_contain("Message exceeds buffer budget of 16 bytes"));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_shared_buffer_budget_waits_for_capacity() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler_a) = recording_handler();
+        let (recorder_b, handler_b) = recording_handler();
+        let budget = BufferBudget::new(nonzero!(16usize));
+
+        let client_a = SocketClient::connect(
+            SocketConfig {
+                suffix: b"|".to_vec(),
+                buffer_budget: Some(budget.clone()),
+                ..test_config(server.port, handler_a)
+            },
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let client_b = SocketClient::connect(
+            SocketConfig {
+                suffix: b"|".to_vec(),
+                buffer_budget: Some(budget.clone()),
+                ..test_config(server.port, handler_b)
+            },
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // A partial message holds most of the budget
+        client_a
+            .writer
+            .lock()
+            .await
+            .write_all(b"aaaaaaaaaa\r\n")
+            .await
+            .unwrap();
+        assert!(wait_until(|| client_a.buffered_bytes() == 12, Duration::from_secs(2)).await);
+
+        // The second client reads what is left of the budget, then waits rather than
+        // dropping the connection
+        client_b
+            .writer
+            .lock()
+            .await
+            .write_all(b"bbbbbbbb|\r\n")
+            .await
+            .unwrap();
+        assert!(wait_until(|| client_b.buffered_bytes() == 4, Duration::from_secs(2)).await);
+        sleep(Duration::from_millis(300)).await;
+        assert_eq!(client_b.buffered_bytes(), 4);
+        assert_eq!(budget.available(), 0);
+        assert!(received(&recorder_b).is_empty());
+        assert_eq!(client_b.reconnect_count(), 0);
+        assert!(!logs_contain("Message exceeds buffer budget"));
+
+        // Releasing the budget lets the second client read the rest of its message
+        client_a.disconnect().await;
+        assert!(
+            wait_until(
+                || received(&recorder_b) == vec!["bbbbbbbb"],
+                Duration::from_secs(2)
+            )
+            .await
+        );
+        assert_eq!(client_b.buffered_bytes(), 2);
+        assert_eq!(budget.available(), 14);
+        assert_eq!(client_b.reconnect_count(), 0);
+
+        // Disconnecting releases the budget held by the buffer
+        client_b.disconnect().await;
+        assert!(wait_until(|| budget.available() == 16, Duration::from_secs(2)).await);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_send_text() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+
+        let config = test_config(server.port, handler);
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+        assert!(client.is_active());
+
+        client
+            .send_text(r#"{"jsonrpc":"2.0","method":"héllo"}"#)
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(
+            received(&recorder),
+            vec![r#"{"jsonrpc":"2.0","method":"héllo"}"#]
+        );
+
+        client.disconnect().await;
+        assert!(!client.is_active());
+        let err = client.send_text("ping").await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotConnected);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_max_reconnect_attempts() {
+        prepare_freethreaded_python();
+
+        const MAX_ATTEMPTS: u32 = 2;
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let (disconnections, post_disconnection) = recording_handler();
+
+        let config = SocketConfig {
+            max_reconnect_attempts: Some(MAX_ATTEMPTS),
+            ..test_config(server.port, handler)
+        };
+        let post_disconnection = Python::with_gil(|py| {
+            PyModule::from_code_bound(
+                py,
+                r"
+def make(handler):
+    return lambda: handler(b'disconnected')",
+                "",
+                "",
+            )
+            .unwrap()
+            .getattr("make")
+            .unwrap()
+            .call1((post_disconnection,))
+            .unwrap()
+            .into_py(py)
+        });
+        let client = SocketClient::connect(config, None, None, Some(post_disconnection), None)
+            .await
+            .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        let _ = client.send_bytes(b"close".as_slice()).await;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !client.is_disconnected() {
+                sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(logs_contain("Attempt 2/2"));
+        assert!(!logs_contain("Attempt 3/2"));
+        assert!(logs_contain("Max reconnect attempts 2 exceeded"));
+        assert_eq!(received(&disconnections), vec!["disconnected"]);
+    }
+
+    /// Returns a recorder and an `on_reconnect_attempt` handler recording each attempt
+    /// as `attempt:delay_ms`.
+    fn attempt_recorder() -> (PyObject, PyObject) {
+        let (recorder, handler) = recording_handler();
+        let on_reconnect_attempt = Python::with_gil(|py| {
+            PyModule::from_code_bound(
+                py,
+                r"
+def make(handler):
+    return lambda attempt, delay_ms: handler(f'{attempt}:{delay_ms}'.encode())",
+                "",
+                "",
+            )
+            .unwrap()
+            .getattr("make")
+            .unwrap()
+            .call1((handler,))
+            .unwrap()
+            .into_py(py)
+        });
+        (recorder, on_reconnect_attempt)
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_on_reconnect_attempt() {
+        prepare_freethreaded_python();
+
+        const MAX_ATTEMPTS: u32 = 3;
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let (attempts, on_reconnect_attempt) = attempt_recorder();
+
+        let config = SocketConfig {
+            max_reconnect_attempts: Some(MAX_ATTEMPTS),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, Some(on_reconnect_attempt))
+            .await
+            .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        let _ = client.send_bytes(b"close".as_slice()).await;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !client.is_disconnected() {
+                sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // The first attempt is immediate, then each waits the fixed backoff delay
+        assert_eq!(received(&attempts), vec!["1:0", "2:1000", "3:1000"]);
+        assert_eq!(client.reconnect_count(), 0);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_reconnect_delays_are_configurable() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let (attempts, on_reconnect_attempt) = attempt_recorder();
+
+        let config = SocketConfig {
+            max_reconnect_attempts: Some(3),
+            reconnect_delay_initial_ms: Some(200),
+            reconnect_delay_max_ms: Some(200),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, Some(on_reconnect_attempt))
+            .await
+            .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        let _ = client.send_bytes(b"close".as_slice()).await;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !client.is_disconnected() {
+                sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(received(&attempts), vec!["1:0", "2:200", "3:200"]);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_current_reconnect_delay_grows_until_max_while_reconnecting() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let (attempts, on_reconnect_attempt) = attempt_recorder();
+
+        let config = SocketConfig {
+            reconnect_backoff: BackoffStrategy::EXPONENTIAL,
+            reconnect_delay_initial_ms: Some(50),
+            reconnect_delay_max_ms: Some(150),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, Some(on_reconnect_attempt))
+            .await
+            .unwrap();
+        assert_eq!(client.current_reconnect_delay_ms(), None);
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        let _ = client.send_bytes(b"close".as_slice()).await;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while received(&attempts).len() < 5 {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // The delay doubles after each failed attempt until capped at the max
+        assert_eq!(
+            &received(&attempts)[..5],
+            &["1:0", "2:50", "3:100", "4:150", "5:150"]
+        );
+        assert_eq!(client.current_reconnect_delay_ms(), Some(150));
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        assert_eq!(client.current_reconnect_delay_ms(), None);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_disconnect_cancels_reconnect_backoff() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+
+        let config = SocketConfig {
+            reconnect_delay_initial_ms: Some(30_000),
+            reconnect_delay_max_ms: Some(30_000),
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        let _ = client.send_bytes(b"close".as_slice()).await;
+        assert!(wait_until(|| logs_contain("Reconnect failed"), Duration::from_secs(2)).await);
+
+        // Disconnecting does not wait out the 30 second backoff
+        let start = Instant::now();
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(logs_contain("Reconnect backoff cancelled"));
+    }
+
+    #[rstest]
+    #[case(Some(0), None)]
+    #[case(Some(200), Some(100))]
+    #[case(None, Some(500))]
+    #[tokio::test]
+    async fn test_invalid_reconnect_delays(
+        #[case] reconnect_delay_initial_ms: Option<u64>,
+        #[case] reconnect_delay_max_ms: Option<u64>,
+    ) {
+        prepare_freethreaded_python();
+
+        let (_, handler) = recording_handler();
+
+        // The delays are validated before connecting, so no server is needed
+        let config = SocketConfig {
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            ..test_config(1, handler)
+        };
+        let err = SocketClient::connect(config, None, None, None, None)
+            .await
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().contains("Invalid reconnect delays"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_send_channel_concurrent_senders() {
+        prepare_freethreaded_python();
+
+        const SENDERS: usize = 8;
+        const MESSAGES: usize = 50;
+        const HOLD: Duration = Duration::from_millis(300);
+
+        // Mutex path: senders wait on the writer lock while it is held (e.g. by a slow write)
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let client = Arc::new(
+            SocketClient::connect(test_config(server.port, handler), None, None, None, None)
+                .await
+                .unwrap(),
+        );
+        let guard = client.writer.lock().await;
+        let start = Instant::now();
+        let mutex_senders: Vec<_> = (0..SENDERS)
+            .map(|sender| {
+                let client = client.clone();
+                task::spawn(async move {
+                    for seq in 0..MESSAGES {
+                        let data = format!("{sender}-{seq}");
+                        client.send_bytes(data.as_bytes()).await.unwrap();
+                    }
+                    start.elapsed()
+                })
+            })
+            .collect();
+        sleep(HOLD).await;
+        drop(guard);
+        let mut mutex_elapsed = Vec::new();
+        for handle in mutex_senders {
+            mutex_elapsed.push(handle.await.unwrap());
+        }
+        client.disconnect().await;
+
+        // Channel path: senders only enqueue, so they are never blocked by the writer lock
+        let (recorder, handler) = recording_handler();
+        let client = Arc::new(
+            SocketClient::connect(
+                SocketConfig {
+                    send_channel_capacity: Some(nonzero!(1024usize)),
+                    ..test_config(server.port, handler)
+                },
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap(),
+        );
+        let guard = client.writer.lock().await;
+        let start = Instant::now();
+        let channel_senders: Vec<_> = (0..SENDERS)
+            .map(|sender| {
+                let client = client.clone();
+                task::spawn(async move {
+                    let results: Vec<_> = (0..MESSAGES)
+                        .map(|seq| {
+                            client.send_frame(format!("{sender}-{seq}\r\n").into_bytes(), None)
+                        })
+                        .collect();
+                    let enqueued = start.elapsed();
+                    for result in results {
+                        result.await.unwrap();
+                    }
+                    enqueued
+                })
+            })
+            .collect();
+        sleep(HOLD).await;
+        drop(guard);
+        let mut enqueue_elapsed = Vec::new();
+        for handle in channel_senders {
+            enqueue_elapsed.push(handle.await.unwrap());
+        }
+        sleep(Duration::from_millis(500)).await;
+
+        assert!(mutex_elapsed.iter().all(|elapsed| *elapsed >= HOLD));
+        assert!(enqueue_elapsed.iter().all(|elapsed| *elapsed < HOLD));
+
+        // Every sender's messages were written in the order sent
+        let received = received(&recorder);
+        assert_eq!(received.len(), SENDERS * MESSAGES);
+        for sender in 0..SENDERS {
+            let sequence: Vec<usize> = received
+                .iter()
+                .filter_map(|message| message.split_once('-'))
+                .filter(|(from, _)| *from == sender.to_string())
+                .map(|(_, seq)| seq.parse().unwrap())
+                .collect();
+            assert_eq!(sequence, (0..MESSAGES).collect::<Vec<_>>());
+        }
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_flush_poisoned_writer() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (_, handler) = recording_handler();
+        let client =
+            SocketClient::connect(test_config(server.port, handler), None, None, None, None)
+                .await
+                .unwrap();
+        client.flush().await.unwrap();
+
+        // As left by a send cut off mid-write, before the client reconnects
+        client.writer_poisoned.store(true, Ordering::SeqCst);
+        let err = client.flush().await.unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+        assert_eq!(
+            err.to_string(),
+            "Writer poisoned by an incomplete send, awaiting reconnect"
+        );
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_with_timeout_when_peer_stops_reading() {
+        prepare_freethreaded_python();
+
+        // The server accepts the connection but never reads, so the send buffers fill up
+        let (server, connections) = TestServer::holding().await;
+        let (_, handler) = recording_handler();
+        let client =
+            SocketClient::connect(test_config(server.port, handler), None, None, None, None)
+                .await
+                .unwrap();
+
+        let chunk = vec![0u8; 1024 * 1024];
+        let mut result = Ok(());
+        for _ in 0..256 {
+            result = client.send_with_timeout(&chunk, Some(200)).await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert_eq!(err.to_string(), "Send timed out after 200ms");
+
+        // The frame was cut off mid-write, so the writer is poisoned and the client
+        // reconnects rather than writing another frame after the partial one
+        assert!(client.writer_poisoned.load(Ordering::SeqCst));
+        let start = Instant::now();
+        while client.reconnect_count() == 0 && start.elapsed() < Duration::from_secs(5) {
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(client.reconnect_count(), 1);
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+        assert!(!client.writer_poisoned.load(Ordering::SeqCst));
+
+        // A stalled writer also times out senders waiting on the writer lock
+        let guard = client.writer.lock().await;
+        let start = Instant::now();
+        let err = client
+            .send_with_timeout(b"ping", Some(100))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        drop(guard);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_write_keeps_client_alive() {
+        prepare_freethreaded_python();
+
+        // The server never reads, so the large write stays in flight until it times out
+        let (server, _connections) = TestServer::holding().await;
+        let (_, handler) = recording_handler();
+        let client =
+            SocketClient::connect(test_config(server.port, handler), None, None, None, None)
+                .await
+                .unwrap();
+
+        let chunk = vec![0u8; 64 * 1024 * 1024];
+        let send = client.send_with_timeout(&chunk, Some(1_000));
+        let check = async {
+            // Spans several controller ticks while the write is blocked
+            sleep(Duration::from_millis(500)).await;
+            (
+                client.writer_poisoned.load(Ordering::SeqCst),
+                client.reconnect_count(),
+            )
+        };
+        let (result, (poisoned, reconnect_count)) = tokio::join!(send, check);
+
+        assert!(!poisoned);
+        assert_eq!(reconnect_count, 0);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_channel_drops_timed_out_send() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+        let client = SocketClient::connect(
+            SocketConfig {
+                send_channel_capacity: Some(nonzero!(1024usize)),
+                ..test_config(server.port, handler)
+            },
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // The send times out while still queued behind the held writer lock
+        let guard = client.writer.lock().await;
+        let err = client
+            .send_with_timeout(b"timed-out", Some(100))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        drop(guard);
+
+        // The timeout forces a reconnect, after which the queued send is never written
+        let start = Instant::now();
+        while client.reconnect_count() == 0 && start.elapsed() < Duration::from_secs(5) {
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(client.reconnect_count(), 1);
+        client.send_bytes(b"retried").await.unwrap();
+        let start = Instant::now();
+        while received(&recorder).is_empty() && start.elapsed() < Duration::from_secs(5) {
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(received(&recorder), vec!["retried"]);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_channel_waits_for_capacity() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::basic_client_test().await;
+        let (recorder, handler) = recording_handler();
+        let client = Arc::new(
+            SocketClient::connect(
+                SocketConfig {
+                    send_channel_capacity: Some(nonzero!(2usize)),
+                    ..test_config(server.port, handler)
+                },
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap(),
+        );
+
+        // Hold the writer lock so the writer task blocks with the channel full behind it
+        let guard = client.writer.lock().await;
+        let sends: Vec<_> = (0..4)
+            .map(|i| client.send_frame(format!("msg-{i}\r\n").into_bytes(), None))
+            .collect();
+        let sends: Vec<_> = sends.into_iter().map(task::spawn).collect();
+        sleep(Duration::from_millis(100)).await;
+        assert!(sends.iter().all(|send| !send.is_finished()));
+
+        // Once the writer is released every send is written and arrives at the server
+        drop(guard);
+        for send in sends {
+            send.await.unwrap().unwrap();
+        }
+        let expected: Vec<String> = (0..4).map(|i| format!("msg-{i}")).collect();
+        let start = Instant::now();
+        while received(&recorder).len() < expected.len() && start.elapsed() < Duration::from_secs(1)
+        {
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(received(&recorder), expected);
+
+        // Disconnecting stops the writer task, so later sends fail rather than queue
+        client.disconnect().await;
+        let err = client.send_bytes(b"late").await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotConnected);
+    }
+
+    #[rstest]
+    #[case::mutex(None)]
+    #[case::channel(Some(nonzero!(16usize)))]
+    #[tokio::test]
+    async fn test_auto_flush_delivers_tls_sends_without_explicit_flush(
+        #[case] send_channel_capacity: Option<NonZeroUsize>,
+    ) {
+        prepare_freethreaded_python();
+
+        const MESSAGES: usize = 20;
+
+        let pki = TestPki::new();
+        let (server, _) = pki.server().await;
+        let (recorder, handler) = recording_handler();
+        let certs_dir = temp_certs_dir();
+        pki.write_client_cert(&certs_dir, Duration::from_secs(3600));
+
+        let config = SocketConfig {
+            mode: Mode::Tls,
+            certs_dir: Some(certs_dir.clone()),
+            send_channel_capacity,
+            auto_flush: true,
+            ..test_config(server.port, handler)
+        };
+        let client = SocketClient::connect(config, None, None, None, None)
+            .await
+            .unwrap();
+
+        // Each send is flushed through the TLS session without the caller flushing
+        for i in 0..MESSAGES {
+            client
+                .send_bytes(format!("msg-{i}").as_bytes())
+                .await
+                .unwrap();
+        }
+        let expected: Vec<String> = (0..MESSAGES).map(|i| format!("msg-{i}")).collect();
+        assert!(
+            wait_until(
+                || received(&recorder).len() == MESSAGES,
+                Duration::from_secs(1)
+            )
+            .await
+        );
+        assert_eq!(received(&recorder), expected);
+
+        client.disconnect().await;
+        std::fs::remove_dir_all(certs_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_socket_options() {
+        let server = TestServer::basic_client_test().await;
+        let stream = TcpStream::connect(format!("127.0.0.1:{}", server.port))
+            .await
+            .unwrap();
+
+        set_socket_options(&stream, true, Some(64 * 1024), Some(32 * 1024)).unwrap();
+
+        // The kernel may round buffer sizes up (Linux doubles them for bookkeeping)
+        let socket = SockRef::from(&stream);
+        assert!(socket.nodelay().unwrap());
+        assert!(socket.recv_buffer_size().unwrap() >= 64 * 1024);
+        assert!(socket.send_buffer_size().unwrap() >= 32 * 1024);
+
+        set_socket_options(&stream, false, None, None).unwrap();
+        assert!(!socket.nodelay().unwrap());
+    }
+
+    #[rstest]
+    fn test_next_reconnect_delay_grows_until_max() {
+        let stats = ConnectionStats::default();
+        let mut backoff = Backoff::Exponential(ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+        ));
+        assert_eq!(stats.reconnect_delay_ms(), None);
+
+        let delays: Vec<Option<u64>> = (0..5)
+            .map(|_| {
+                let delay = stats.next_reconnect_delay(&mut backoff);
+                assert_eq!(stats.reconnect_delay_ms(), Some(delay.as_millis() as u64));
+                stats.reconnect_delay_ms()
+            })
+            .collect();
+
+        assert_eq!(
+            delays,
+            vec![Some(100), Some(200), Some(400), Some(500), Some(500)]
+        );
+    }
 }
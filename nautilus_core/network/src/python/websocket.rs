@@ -13,7 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::{atomic::Ordering, Arc},
+};
 
 use futures::SinkExt;
 use nautilus_core::python::to_pyvalue_err;
@@ -21,21 +25,61 @@ use pyo3::{create_exception, exceptions::PyException, prelude::*};
 use tokio_tungstenite::tungstenite::Message;
 
 use crate::{
+    backoff::BackoffStrategy,
     ratelimiter::quota::Quota,
-    websocket::{WebSocketClient, WebSocketConfig},
+    sequence::SequenceExtractor,
+    websocket::{
+        close_frame, request_subscription, ConnectionState, SendError, WebSocketClient,
+        WebSocketConfig, WsMessageType, DISCONNECT_TIMEOUT,
+    },
 };
 
 // Python exception class for websocket errors
 create_exception!(network, WebSocketClientError, PyException);
 
+// Python exception class for websocket send errors where a retry may succeed.
+create_exception!(network, WebSocketRetryableError, WebSocketClientError);
+
+// Python exception class for websocket send errors where a retry cannot succeed.
+create_exception!(network, WebSocketFatalError, WebSocketClientError);
+
 fn to_websocket_pyerr(e: tokio_tungstenite::tungstenite::Error) -> PyErr {
     PyErr::new::<WebSocketClientError, _>(e.to_string())
 }
 
+impl SendError {
+    #[must_use]
+    pub fn into_py_err(self) -> PyErr {
+        match self {
+            Self::Retryable(e) => PyErr::new::<WebSocketRetryableError, _>(e),
+            Self::Fatal(e) => PyErr::new::<WebSocketFatalError, _>(e),
+        }
+    }
+}
+
+#[pymethods]
+impl ConnectionState {
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+}
+
+#[pymethods]
+impl WsMessageType {
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+}
+
 #[pymethods]
 impl WebSocketConfig {
     #[new]
-    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, max_reconnection_tries=3))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, max_reconnection_tries=3, sequence_json_pointer=None, sequence_gap_handler=None, reconnect_on_sequence_gap=false, snapshot_predicate=None, heartbeat_pause_windows=None, auth_flow=None, subscription_debounce_ms=None, subscription_error_handler=None, ping_interval_ms=None, max_message_size=None, include_message_type=false, reconnect_backoff=None, reconnect_delay_initial_ms=None, reconnect_delay_max_ms=None, resubscribe_messages=None))]
     fn py_new(
         url: String,
         handler: PyObject,
@@ -44,6 +88,21 @@ impl WebSocketConfig {
         heartbeat_msg: Option<String>,
         ping_handler: Option<PyObject>,
         max_reconnection_tries: Option<u64>,
+        sequence_json_pointer: Option<String>,
+        sequence_gap_handler: Option<PyObject>,
+        reconnect_on_sequence_gap: bool,
+        snapshot_predicate: Option<PyObject>,
+        heartbeat_pause_windows: Option<Vec<(u64, u64)>>,
+        auth_flow: Option<PyObject>,
+        subscription_debounce_ms: Option<u64>,
+        subscription_error_handler: Option<PyObject>,
+        ping_interval_ms: Option<u64>,
+        max_message_size: Option<usize>,
+        include_message_type: bool,
+        reconnect_backoff: Option<BackoffStrategy>,
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        resubscribe_messages: Option<Vec<Vec<u8>>>,
     ) -> Self {
         Self {
             url,
@@ -53,6 +112,21 @@ impl WebSocketConfig {
             heartbeat_msg,
             ping_handler: ping_handler.map(Arc::new),
             max_reconnection_tries,
+            sequence_extractor: sequence_json_pointer.map(SequenceExtractor::JsonPointer),
+            sequence_gap_handler: sequence_gap_handler.map(Arc::new),
+            reconnect_on_sequence_gap,
+            snapshot_predicate: snapshot_predicate.map(Arc::new),
+            heartbeat_pause_windows: heartbeat_pause_windows.unwrap_or_default(),
+            auth_flow: auth_flow.map(Arc::new),
+            subscription_debounce_ms,
+            subscription_error_handler: subscription_error_handler.map(Arc::new),
+            ping_interval_ms,
+            max_message_size,
+            include_message_type,
+            reconnect_backoff,
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            resubscribe_messages: resubscribe_messages.unwrap_or_default(),
         }
     }
 }
@@ -65,7 +139,8 @@ impl WebSocketClient {
     ///
     /// - Throws an Exception if it is unable to make websocket connection.
     #[staticmethod]
-    #[pyo3(name = "connect", signature = (config, post_connection= None, post_reconnection= None, post_disconnection= None, keyed_quotas = Vec::new(), default_quota = None))]
+    #[pyo3(name = "connect", signature = (config, post_connection= None, post_reconnection= None, post_disconnection= None, keyed_quotas = Vec::new(), default_quota = None, on_state_change = None))]
+    #[allow(clippy::too_many_arguments)]
     fn py_connect(
         config: WebSocketConfig,
         post_connection: Option<PyObject>,
@@ -73,6 +148,7 @@ impl WebSocketClient {
         post_disconnection: Option<PyObject>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        on_state_change: Option<PyObject>,
         py: Python<'_>,
     ) -> PyResult<Bound<PyAny>> {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
@@ -83,6 +159,7 @@ impl WebSocketClient {
                 post_disconnection,
                 keyed_quotas,
                 default_quota,
+                on_state_change,
             )
             .await
             .map_err(to_websocket_pyerr)
@@ -101,8 +178,51 @@ impl WebSocketClient {
     #[pyo3(name = "disconnect")]
     fn py_disconnect<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let disconnect_mode = slf.disconnect_mode.clone();
+        let cancellation_token = slf.cancellation_token.clone();
+        let subscriptions = slf.subscriptions.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            disconnect_mode.store(true, Ordering::SeqCst);
+            cancellation_token.cancel();
+            subscriptions.lock().unwrap().cancel_pending();
+            Ok(())
+        })
+    }
+
+    /// Close the connection with the close `code` and optional `reason`.
+    ///
+    /// The `Close` frame is sent as the client disconnects, and the awaitable completes
+    /// once the server's `Close` frame in reply is received (or after 2 seconds) and the
+    /// client has transitioned to `CLOSED`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValueError` if the `code` is outside 1000-4999 or is one of the
+    /// reserved codes 1005, 1006 and 1015, in which case the client is left connected.
+    #[pyo3(name = "close_with_code", signature = (code, reason = None))]
+    fn py_close_with_code<'py>(
+        slf: PyRef<'_, Self>,
+        code: u16,
+        reason: Option<String>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let frame = close_frame(code, reason).map_err(to_pyvalue_err)?;
+        let pending_close_frame = slf.pending_close_frame.clone();
+        let disconnect_mode = slf.disconnect_mode.clone();
+        let cancellation_token = slf.cancellation_token.clone();
+        let closed_token = slf.closed_token.clone();
+        let subscriptions = slf.subscriptions.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            subscriptions.lock().unwrap().cancel_pending();
+            *pending_close_frame.lock().unwrap() = Some(frame);
             disconnect_mode.store(true, Ordering::SeqCst);
+            cancellation_token.cancel();
+
+            if tokio::time::timeout(DISCONNECT_TIMEOUT, closed_token.cancelled())
+                .await
+                .is_err()
+            {
+                tracing::error!("Timeout waiting for controller task to finish");
+            }
             Ok(())
         })
     }
@@ -122,11 +242,94 @@ impl WebSocketClient {
         !slf.controller_task.is_finished()
     }
 
+    /// Suspend sending heartbeats, e.g. during a venue maintenance window.
+    ///
+    /// The connection state is not affected.
+    #[pyo3(name = "suspend_heartbeat")]
+    fn py_suspend_heartbeat(slf: PyRef<'_, Self>) {
+        slf.suspend_heartbeat();
+    }
+
+    /// Resume sending heartbeats.
+    #[pyo3(name = "resume_heartbeat")]
+    fn py_resume_heartbeat(slf: PyRef<'_, Self>) {
+        slf.resume_heartbeat();
+    }
+
+    /// Attach an additional handler for received messages.
+    ///
+    /// If a snapshot predicate is configured the last snapshot received is first
+    /// replayed to the handler, so it starts from a consistent state.
+    #[pyo3(name = "attach_handler")]
+    fn py_attach_handler(slf: PyRef<'_, Self>, handler: PyObject) {
+        slf.attach_handler(handler);
+    }
+
+    /// Request a subscription to the topic by sending the UTF-8 encoded `message`.
+    ///
+    /// Requests for the same topic within the configured `subscription_debounce_ms` window
+    /// are coalesced, and requests which would not change the subscription state are not sent.
+    /// The `message` is resent after each reconnect while the topic is subscribed.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `WebSocketRetryableError` if not able to send data, but a retry may succeed.
+    /// - Raises `WebSocketFatalError` if not able to send data and a retry cannot succeed.
+    #[pyo3(name = "subscribe")]
+    fn py_subscribe<'py>(
+        slf: PyRef<'_, Self>,
+        topic: String,
+        message: Vec<u8>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::py_request_subscription(slf, topic, true, message, py)
+    }
+
+    /// Request to unsubscribe from the topic by sending the UTF-8 encoded `message`.
+    ///
+    /// Requests for the same topic within the configured `subscription_debounce_ms` window
+    /// are coalesced, and requests which would not change the subscription state are not sent.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `WebSocketRetryableError` if not able to send data, but a retry may succeed.
+    /// - Raises `WebSocketFatalError` if not able to send data and a retry cannot succeed.
+    #[pyo3(name = "unsubscribe")]
+    fn py_unsubscribe<'py>(
+        slf: PyRef<'_, Self>,
+        topic: String,
+        message: Vec<u8>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::py_request_subscription(slf, topic, false, message, py)
+    }
+
+    /// Replace the messages resent after each reconnect, once the `post_reconnection`
+    /// handler has been called.
+    #[pyo3(name = "set_resubscribe_messages")]
+    fn py_set_resubscribe_messages(slf: PyRef<'_, Self>, messages: Vec<Vec<u8>>) {
+        slf.set_resubscribe_messages(messages);
+    }
+
+    /// Check if the topic is subscribed, as last sent to the server.
+    #[pyo3(name = "is_subscribed")]
+    fn py_is_subscribed(slf: PyRef<'_, Self>, topic: &str) -> bool {
+        slf.is_subscribed(topic)
+    }
+
+    /// Return the `(code, reason)` of the last close frame received from the server.
+    #[pyo3(name = "last_close_frame")]
+    fn py_last_close_frame(slf: PyRef<'_, Self>) -> Option<(u16, String)> {
+        slf.last_close_frame()
+            .map(|frame| (u16::from(frame.code), frame.reason.into_owned()))
+    }
+
     /// Send bytes data to the server.
     ///
     /// # Errors
     ///
-    /// - Raises PyRuntimeError if not able to send data.
+    /// - Raises `WebSocketRetryableError` if not able to send data, but a retry may succeed.
+    /// - Raises `WebSocketFatalError` if not able to send data and a retry cannot succeed.
     #[pyo3(name = "send")]
     #[pyo3(signature = (data, keys=None))]
     fn py_send<'py>(
@@ -137,16 +340,18 @@ impl WebSocketClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         let writer = slf.writer.clone();
         let rate_limiter = slf.rate_limiter.clone();
+        let disconnect_mode = slf.disconnect_mode.clone();
+        let closed = slf.is_disconnected();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             rate_limiter.await_keys_ready(keys).await;
             tracing::trace!("Sending binary: {data:?}");
 
             let mut guard = writer.lock().await;
-            guard
-                .send(Message::Binary(data))
-                .await
-                .map_err(to_websocket_pyerr)
+            guard.send(Message::Binary(data)).await.map_err(|e| {
+                let client_closed = closed || disconnect_mode.load(Ordering::SeqCst);
+                SendError::classify(e, client_closed).into_py_err()
+            })
         })
     }
 
@@ -156,7 +361,8 @@ impl WebSocketClient {
     /// `keys`: Optional list of rate limit keys. If provided, the function will wait for rate limits to be met for each key before sending the data.
     ///
     /// # Errors
-    /// - Raises `PyRuntimeError` if unable to send the data.
+    /// - Raises `WebSocketRetryableError` if unable to send the data, but a retry may succeed.
+    /// - Raises `WebSocketFatalError` if unable to send the data and a retry cannot succeed.
     ///
     /// # Example
     ///
@@ -174,16 +380,18 @@ impl WebSocketClient {
         let data = String::from_utf8(data).map_err(to_pyvalue_err)?;
         let writer = slf.writer.clone();
         let rate_limiter = slf.rate_limiter.clone();
+        let disconnect_mode = slf.disconnect_mode.clone();
+        let closed = slf.is_disconnected();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             rate_limiter.await_keys_ready(keys).await;
             tracing::trace!("Sending text: {data}");
 
             let mut guard = writer.lock().await;
-            guard
-                .send(Message::Text(data))
-                .await
-                .map_err(to_websocket_pyerr)
+            guard.send(Message::Text(data)).await.map_err(|e| {
+                let client_closed = closed || disconnect_mode.load(Ordering::SeqCst);
+                SendError::classify(e, client_closed).into_py_err()
+            })
         })
     }
 
@@ -191,7 +399,8 @@ impl WebSocketClient {
     ///
     /// # Errors
     ///
-    /// - Raises PyRuntimeError if not able to send data.
+    /// - Raises `WebSocketRetryableError` if not able to send data, but a retry may succeed.
+    /// - Raises `WebSocketFatalError` if not able to send data and a retry cannot succeed.
     #[pyo3(name = "send_pong")]
     fn py_send_pong<'py>(
         slf: PyRef<'_, Self>,
@@ -200,14 +409,41 @@ impl WebSocketClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         let data_str = String::from_utf8(data.clone()).map_err(to_pyvalue_err)?;
         let writer = slf.writer.clone();
+        let disconnect_mode = slf.disconnect_mode.clone();
+        let closed = slf.is_disconnected();
         tracing::trace!("Sending pong: {data_str}");
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let mut guard = writer.lock().await;
-            guard
-                .send(Message::Pong(data))
+            guard.send(Message::Pong(data)).await.map_err(|e| {
+                let client_closed = closed || disconnect_mode.load(Ordering::SeqCst);
+                SendError::classify(e, client_closed).into_py_err()
+            })
+        })
+    }
+}
+
+impl WebSocketClient {
+    fn py_request_subscription<'py>(
+        slf: PyRef<'_, Self>,
+        topic: String,
+        subscribe: bool,
+        message: Vec<u8>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let message = String::from_utf8(message).map_err(to_pyvalue_err)?;
+        let subscriptions = slf.subscriptions.clone();
+        let writer = slf.writer.clone();
+        let disconnect_mode = slf.disconnect_mode.clone();
+        let closed = slf.is_disconnected();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            request_subscription(subscriptions, writer, topic, subscribe, message)
                 .await
-                .map_err(to_websocket_pyerr)
+                .map_err(|e| {
+                    let client_closed = closed || disconnect_mode.load(Ordering::SeqCst);
+                    SendError::classify(e, client_closed).into_py_err()
+                })
         })
     }
 }
@@ -217,23 +453,47 @@ impl WebSocketClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
     use futures_util::{SinkExt, StreamExt};
+    use nautilus_core::time::get_atomic_clock_realtime;
     use pyo3::{prelude::*, prepare_freethreaded_python};
+    use rstest::rstest;
     use tokio::{
         net::TcpListener,
         task::{self, JoinHandle},
-        time::{sleep, Duration},
+        time::{sleep, Duration, Instant},
     };
     use tokio_tungstenite::{
-        accept_hdr_async,
+        accept_async, accept_hdr_async, connect_async,
         tungstenite::{
+            error::{CapacityError, ProtocolError},
             handshake::server::{self, Callback},
             http::HeaderValue,
+            protocol::{
+                frame::{
+                    coding::{CloseCode, Data, OpCode},
+                    Frame,
+                },
+                CloseFrame,
+            },
+            Error, Message,
         },
     };
     use tracing_test::traced_test;
 
-    use crate::websocket::{WebSocketClient, WebSocketConfig};
+    use super::{WebSocketClientError, WebSocketFatalError, WebSocketRetryableError};
+    use crate::{
+        backoff::BackoffStrategy,
+        sequence::SequenceExtractor,
+        websocket::{
+            request_subscription, ConnectionState, SendError, SubscriptionDebouncer,
+            WebSocketClient, WebSocketConfig, WsMessageType,
+        },
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -310,6 +570,16 @@ mod tests {
         }
     }
 
+    /// Returns the config for a client of the test server on `port` passing messages to
+    /// `handler`, which tests override with the fields under test.
+    fn test_config(port: u16, handler: PyObject) -> WebSocketConfig {
+        WebSocketConfig {
+            url: format!("ws://127.0.0.1:{port}"),
+            handler: Some(Arc::new(handler)),
+            ..Default::default()
+        }
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn basic_client_test() {
@@ -351,16 +621,11 @@ counter = Counter()",
             (counter, handler)
         });
 
-        let config = WebSocketConfig::py_new(
-            format!("ws://127.0.0.1:{}", server.port),
-            Python::with_gil(|py| handler.clone_ref(py)),
-            vec![(header_key, header_value)],
-            None,
-            None,
-            None,
-            None,
-        );
-        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            ..test_config(server.port, Python::with_gil(|py| handler.clone_ref(py)))
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
             .await
             .unwrap();
 
@@ -456,16 +721,13 @@ checker = Checker()",
 
         // Initialize test server and config
         let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
-        let config = WebSocketConfig::py_new(
-            format!("ws://127.0.0.1:{}", server.port),
-            Python::with_gil(|py| handler.clone_ref(py)),
-            vec![(header_key, header_value)],
-            Some(1),
-            Some("heartbeat message".to_string()),
-            None,
-            None,
-        );
-        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            heartbeat: Some(1),
+            heartbeat_msg: Some("heartbeat message".to_string()),
+            ..test_config(server.port, Python::with_gil(|py| handler.clone_ref(py)))
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
             .await
             .unwrap();
 
@@ -486,4 +748,1605 @@ checker = Checker()",
         client.disconnect().await;
         assert!(client.is_disconnected());
     }
+
+    fn heartbeat_counter() -> (PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class HeartbeatCounter:
+    def __init__(self):
+        self.count = 0
+
+    def handler(self, bytes):
+        if bytes.decode() == 'heartbeat message':
+            self.count = self.count + 1
+
+    def get_count(self):
+        return self.count
+
+counter = HeartbeatCounter()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let counter = pymod.getattr("counter").unwrap().into_py(py);
+            let handler = counter.getattr(py, "handler").unwrap().into_py(py);
+
+            (counter, handler)
+        })
+    }
+
+    fn get_heartbeat_count(counter: &PyObject) -> usize {
+        Python::with_gil(|py| {
+            counter
+                .getattr(py, "get_count")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn suspend_heartbeat_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (counter, handler) = heartbeat_counter();
+
+        // Initialize test server and config, the server echoes heartbeats back
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            heartbeat: Some(1),
+            heartbeat_msg: Some("heartbeat message".to_string()),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        client.suspend_heartbeat();
+        assert!(client.is_heartbeat_suspended());
+        sleep(Duration::from_millis(2500)).await;
+        assert_eq!(get_heartbeat_count(&counter), 0);
+        assert!(!client.is_disconnected());
+
+        client.resume_heartbeat();
+        assert!(!client.is_heartbeat_suspended());
+        sleep(Duration::from_millis(2500)).await;
+        assert!(get_heartbeat_count(&counter) > 0);
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn heartbeat_send_failure_reconnects_test() {
+        prepare_freethreaded_python();
+
+        // Server which drops the first connection straight after the handshake
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = server.local_addr().unwrap().port();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let server_task = {
+            let connections = connections.clone();
+            task::spawn(async move {
+                let mut held = Vec::new();
+                loop {
+                    let (conn, _) = server.accept().await.unwrap();
+                    let websocket = accept_async(conn).await.unwrap();
+                    if connections.fetch_add(1, Ordering::SeqCst) > 0 {
+                        held.push(websocket);
+                    }
+                }
+            })
+        };
+
+        // Without a handler there is no read task, so only the heartbeat can detect the
+        // dropped connection
+        let config = WebSocketConfig {
+            url: format!("ws://127.0.0.1:{port}"),
+            heartbeat: Some(1),
+            ..Default::default()
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        while connections.load(Ordering::SeqCst) < 2 && start.elapsed() < Duration::from_secs(10) {
+            sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+        assert!(logs_contain("Heartbeat send failed, triggering reconnect"));
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        server_task.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn heartbeat_pause_window_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (counter, handler) = heartbeat_counter();
+
+        // Pause heartbeats for a window covering the whole test
+        let now = get_atomic_clock_realtime().get_time_ns().as_u64();
+        let window = (now, now + 60_000_000_000);
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            heartbeat: Some(1),
+            heartbeat_msg: Some("heartbeat message".to_string()),
+            heartbeat_pause_windows: vec![window],
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(2500)).await;
+        assert_eq!(get_heartbeat_count(&counter), 0);
+        assert!(!client.is_disconnected());
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn sequence_gap_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+
+        let (recorder, handler, gap_handler) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class GapRecorder:
+    def __init__(self):
+        self.count = 0
+        self.gaps = []
+
+    def handler(self, bytes):
+        self.count = self.count + 1
+
+    def on_sequence_gap(self, expected, got):
+        self.gaps.append((expected, got))
+
+    def get_count(self):
+        return self.count
+
+    def get_gaps(self):
+        return self.gaps
+
+recorder = GapRecorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+            let gap_handler = recorder.getattr(py, "on_sequence_gap").unwrap().into_py(py);
+
+            (recorder, handler, gap_handler)
+        });
+
+        // Initialize test server and config, the server echoes messages back
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            sequence_extractor: Some(SequenceExtractor::JsonPointer("/seq".to_string())),
+            sequence_gap_handler: Some(Arc::new(gap_handler)),
+            ..test_config(server.port, Python::with_gil(|py| handler.clone_ref(py)))
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        // Sequence 3 is skipped
+        for seq in [1, 2, 4, 5] {
+            client
+                .send_text(format!(r#"{{"seq":{seq}}}"#))
+                .await
+                .unwrap();
+        }
+
+        sleep(Duration::from_secs(1)).await;
+        let (count, gaps): (usize, Vec<(u64, u64)>) = Python::with_gil(|py| {
+            let count = recorder
+                .getattr(py, "get_count")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            let gaps = recorder
+                .getattr(py, "get_gaps")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            (count, gaps)
+        });
+        assert_eq!(count, 4);
+        assert_eq!(gaps, vec![(3, 4)]);
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn snapshot_replay_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+
+        let (recorder, handler, late_handler, snapshot_predicate) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class SnapshotRecorder:
+    def __init__(self):
+        self.received = []
+        self.late_received = []
+
+    def handler(self, bytes):
+        self.received.append(bytes.decode())
+
+    def late_handler(self, bytes):
+        self.late_received.append(bytes.decode())
+
+    def is_snapshot(self, bytes):
+        return bytes.startswith(b'snapshot')
+
+    def get_received(self):
+        return self.received
+
+    def get_late_received(self):
+        return self.late_received
+
+recorder = SnapshotRecorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+            let late_handler = recorder.getattr(py, "late_handler").unwrap().into_py(py);
+            let snapshot_predicate = recorder.getattr(py, "is_snapshot").unwrap().into_py(py);
+
+            (recorder, handler, late_handler, snapshot_predicate)
+        });
+
+        // Initialize test server and config, the server echoes messages back
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            snapshot_predicate: Some(Arc::new(snapshot_predicate)),
+            ..test_config(server.port, Python::with_gil(|py| handler.clone_ref(py)))
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        for msg in ["snapshot 1", "update 1", "snapshot 2", "update 2"] {
+            client.send_text(msg.to_string()).await.unwrap();
+        }
+        sleep(Duration::from_secs(1)).await;
+
+        // Late handler starts from the retained snapshot then receives new messages
+        client.attach_handler(late_handler);
+        client.send_text("update 3".to_string()).await.unwrap();
+        sleep(Duration::from_secs(1)).await;
+
+        let (received, late_received): (Vec<String>, Vec<String>) = Python::with_gil(|py| {
+            let received = recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            let late_received = recorder
+                .getattr(py, "get_late_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            (received, late_received)
+        });
+        assert_eq!(
+            received,
+            vec![
+                "snapshot 1",
+                "update 1",
+                "snapshot 2",
+                "update 2",
+                "update 3"
+            ]
+        );
+        assert_eq!(late_received, vec!["snapshot 2", "update 3"]);
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    /// Starts a server which challenges each connection with the `nonce` and only
+    /// echoes messages back once the expected signed response is received.
+    async fn setup_auth_server(nonce: &'static str) -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                let mut websocket = accept_async(conn).await.unwrap();
+
+                task::spawn(async move {
+                    websocket
+                        .send(Message::Text(format!("challenge:{nonce}")))
+                        .await
+                        .unwrap();
+                    let expected: String = nonce.chars().rev().collect();
+                    match websocket.next().await {
+                        Some(Ok(Message::Text(response)))
+                            if response == format!("signed:{expected}") =>
+                        {
+                            websocket
+                                .send(Message::Text("authenticated".to_string()))
+                                .await
+                                .unwrap();
+                        }
+                        _ => {
+                            let _ = websocket.send(Message::Text("denied".to_string())).await;
+                            let _ = websocket.close(None).await;
+                            return;
+                        }
+                    }
+
+                    while let Some(Ok(msg)) = websocket.next().await {
+                        if msg.is_binary() || msg.is_text() {
+                            websocket.send(msg).await.unwrap();
+                        } else if msg.is_close() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        (task, port)
+    }
+
+    fn auth_recorder(reverse: bool) -> (PyObject, PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                &format!(
+                    r"
+class AuthRecorder:
+    def __init__(self):
+        self.received = []
+
+    def handler(self, bytes):
+        self.received.append(bytes.decode())
+
+    def auth_flow(self, bytes):
+        msg = bytes.decode()
+        if msg.startswith('challenge:'):
+            nonce = msg[len('challenge:'):]
+            return ('signed:' + (nonce[::-1] if {} else nonce)).encode()
+        return msg == 'authenticated'
+
+    def get_received(self):
+        return self.received
+
+recorder = AuthRecorder()",
+                    if reverse { "True" } else { "False" }
+                ),
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+            let auth_flow = recorder.getattr(py, "auth_flow").unwrap().into_py(py);
+
+            (recorder, handler, auth_flow)
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn auth_flow_test() {
+        prepare_freethreaded_python();
+
+        let (server, port) = setup_auth_server("nonce-1234").await;
+        let (recorder, handler, auth_flow) = auth_recorder(true);
+
+        let client = WebSocketClient::connect(
+            WebSocketConfig {
+                max_reconnection_tries: Some(0),
+                auth_flow: Some(Arc::new(auth_flow)),
+                ..test_config(port, handler)
+            },
+            None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // The signed response unlocked the connection so messages are echoed back
+        client.send_text("hello".to_string()).await.unwrap();
+        sleep(Duration::from_secs(1)).await;
+
+        let received: Vec<String> = Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        });
+        assert_eq!(received, vec!["hello"]);
+        assert!(logs_contain("Auth flow completed"));
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn auth_flow_rejected_test() {
+        prepare_freethreaded_python();
+
+        let (server, port) = setup_auth_server("nonce-1234").await;
+        let (_, handler, auth_flow) = auth_recorder(false);
+
+        let result = WebSocketClient::connect(
+            WebSocketConfig {
+                max_reconnection_tries: Some(0),
+                auth_flow: Some(Arc::new(auth_flow)),
+                ..test_config(port, handler)
+            },
+            None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+        )
+        .await;
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "IO error: Auth flow rejected"
+        );
+        server.abort();
+    }
+
+    #[rstest]
+    #[case(Error::ConnectionClosed, true)]
+    #[case(Error::AlreadyClosed, true)]
+    #[case(Error::WriteBufferFull(Message::Text("data".to_string())), true)]
+    #[case(Error::Protocol(ProtocolError::SendAfterClosing), true)]
+    #[case(Error::Capacity(CapacityError::MessageTooLong { size: 2, max_size: 1 }), false)]
+    #[case(Error::Utf8, false)]
+    fn send_error_classification_test(#[case] error: Error, #[case] retryable: bool) {
+        prepare_freethreaded_python();
+
+        let send_error = SendError::from(error);
+        assert_eq!(send_error.is_retryable(), retryable);
+
+        Python::with_gil(|py| {
+            let err = send_error.into_py_err();
+            assert!(err.is_instance_of::<WebSocketClientError>(py));
+            assert_eq!(err.is_instance_of::<WebSocketRetryableError>(py), retryable);
+            assert_eq!(err.is_instance_of::<WebSocketFatalError>(py), !retryable);
+        });
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn send_error_categories_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        // Sending after the close frame is retryable while the client reconnects
+        client.send_close_message().await;
+        let result = client.send_bytes(b"ping".to_vec()).await;
+        assert!(matches!(result, Err(SendError::Retryable(_))));
+
+        // Retrying succeeds once reconnected
+        sleep(Duration::from_secs(2)).await;
+        client.send_bytes(b"ping".to_vec()).await.unwrap();
+
+        // Sending once the client is closed is fatal
+        client.disconnect().await;
+        assert!(client.is_closed());
+        let result = client.send_bytes(b"ping".to_vec()).await;
+        assert!(matches!(result, Err(SendError::Fatal(_))));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn disconnect_cancels_reconnect_backoff_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            max_reconnection_tries: Some(100),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        client.send_close_message().await;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !logs_contain("Reconnect failed") {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // Teardown happens well within the remaining backoff delay
+        let start = Instant::now();
+        client.disconnect().await;
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert!(client.is_disconnected());
+        assert!(logs_contain("Reconnect backoff cancelled"));
+    }
+
+    fn state_recorder() -> (PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class StateRecorder:
+    def __init__(self):
+        self.transitions = []
+
+    def on_state_change(self, old, new, ts):
+        self.transitions.append((old, new, ts))
+
+    def get_transitions(self):
+        return self.transitions
+
+recorder = StateRecorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let on_state_change = recorder.getattr(py, "on_state_change").unwrap().into_py(py);
+
+            (recorder, on_state_change)
+        })
+    }
+
+    fn get_transitions(recorder: &PyObject) -> Vec<(ConnectionState, ConnectionState, u64)> {
+        Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_transitions")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn on_state_change_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+        let (recorder, on_state_change) = state_recorder();
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(
+            config,
+            None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            Some(on_state_change),
+        )
+        .await
+        .unwrap();
+
+        // Close the connection so the client reconnects
+        client.send_close_message().await;
+        sleep(Duration::from_secs(2)).await;
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+
+        let transitions = get_transitions(&recorder);
+        let states: Vec<(ConnectionState, ConnectionState)> = transitions
+            .iter()
+            .map(|(old, new, _)| (*old, *new))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (ConnectionState::CLOSED, ConnectionState::ACTIVE),
+                (ConnectionState::ACTIVE, ConnectionState::RECONNECT),
+                (ConnectionState::RECONNECT, ConnectionState::ACTIVE),
+                (ConnectionState::ACTIVE, ConnectionState::DISCONNECT),
+                (ConnectionState::DISCONNECT, ConnectionState::CLOSED),
+            ]
+        );
+        assert!(transitions.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+        assert!(transitions[0].2 > 0);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn disconnect_while_reconnecting_state_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+        let (recorder, on_state_change) = state_recorder();
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            max_reconnection_tries: Some(100),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(
+            config,
+            None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            Some(on_state_change),
+        )
+        .await
+        .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        client.send_close_message().await;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !logs_contain("Reconnect failed") {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // Disconnecting while the connection is down still closes the client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+
+        let states: Vec<(ConnectionState, ConnectionState)> = get_transitions(&recorder)
+            .iter()
+            .map(|(old, new, _)| (*old, *new))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (ConnectionState::CLOSED, ConnectionState::ACTIVE),
+                (ConnectionState::ACTIVE, ConnectionState::RECONNECT),
+                (ConnectionState::RECONNECT, ConnectionState::DISCONNECT),
+                (ConnectionState::DISCONNECT, ConnectionState::CLOSED),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn reconnect_delays_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+        let (recorder, on_state_change) = state_recorder();
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            max_reconnection_tries: Some(3),
+            reconnect_backoff: Some(BackoffStrategy::EXPONENTIAL),
+            reconnect_delay_initial_ms: Some(50),
+            reconnect_delay_max_ms: Some(100),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(
+            config,
+            None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            Some(on_state_change),
+        )
+        .await
+        .unwrap();
+
+        // Stop accepting connections and close the current one so reconnects fail
+        drop(server);
+        client.send_close_message().await;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !client.is_disconnected() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let transitions = get_transitions(&recorder);
+        let states: Vec<(ConnectionState, ConnectionState)> = transitions
+            .iter()
+            .map(|(old, new, _)| (*old, *new))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (ConnectionState::CLOSED, ConnectionState::ACTIVE),
+                (ConnectionState::ACTIVE, ConnectionState::RECONNECT),
+                (ConnectionState::RECONNECT, ConnectionState::CLOSED),
+            ]
+        );
+        assert!(logs_contain("Retry 3/3"));
+
+        // The retries wait 50ms, 100ms then 100ms, rather than the default 1 second each
+        let reconnecting = Duration::from_nanos(transitions[2].2 - transitions[1].2);
+        assert!(reconnecting >= Duration::from_millis(250));
+        assert!(reconnecting < Duration::from_secs(2));
+    }
+
+    #[rstest]
+    #[case(Some(0), None)]
+    #[case(Some(200), Some(100))]
+    #[case(None, Some(500))]
+    #[tokio::test]
+    async fn invalid_reconnect_delays_test(
+        #[case] reconnect_delay_initial_ms: Option<u64>,
+        #[case] reconnect_delay_max_ms: Option<u64>,
+    ) {
+        prepare_freethreaded_python();
+
+        let (_, handler) = heartbeat_counter();
+
+        // The delays are validated before connecting, so no server is needed
+        let config = WebSocketConfig {
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            ..test_config(1, handler)
+        };
+        let err = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().contains("Invalid reconnect delays"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn subscription_toggles_coalesced_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let (recorder, handler) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class Recorder:
+    def __init__(self):
+        self.received = []
+
+    def handler(self, bytes):
+        self.received.append(bytes.decode())
+
+    def get_received(self):
+        return self.received
+
+recorder = Recorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+
+            (recorder, handler)
+        });
+
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            subscription_debounce_ms: Some(200),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        let request = |subscribe: bool, topic: &str| {
+            let action = if subscribe { "sub" } else { "unsub" };
+            (topic.to_string(), format!("{action}:{topic}"))
+        };
+
+        // Toggles cancelling out are never sent
+        for _ in 0..3 {
+            let (topic, message) = request(true, "ETHUSDT");
+            client.subscribe(topic, message).await.unwrap();
+            let (topic, message) = request(false, "ETHUSDT");
+            client.unsubscribe(topic, message).await.unwrap();
+        }
+
+        // Only the net subscribe is sent for an odd number of toggles
+        for subscribe in [true, false, true, false, true] {
+            let (topic, message) = request(subscribe, "BTCUSDT");
+            if subscribe {
+                client.subscribe(topic, message).await.unwrap();
+            } else {
+                client.unsubscribe(topic, message).await.unwrap();
+            }
+        }
+        sleep(Duration::from_millis(500)).await;
+
+        // A repeated subscribe leaves the state unchanged once the window elapses
+        let (topic, message) = request(true, "BTCUSDT");
+        client.subscribe(topic, message).await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        let received: Vec<String> = Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        });
+        assert_eq!(received, vec!["sub:BTCUSDT".to_string()]);
+        assert!(client.is_subscribed("BTCUSDT"));
+        assert!(!client.is_subscribed("ETHUSDT"));
+        assert!(logs_contain(
+            "Subscription changes for ETHUSDT cancelled out"
+        ));
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    /// Returns a handler recording the topics of failed subscription changes.
+    fn subscription_error_recorder() -> (PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+errors = []
+
+def handler(topic, error):
+    errors.append(topic)",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let errors = pymod.getattr("errors").unwrap().into_py(py);
+            let handler = pymod.getattr("handler").unwrap().into_py(py);
+
+            (errors, handler)
+        })
+    }
+
+    fn get_errors(errors: &PyObject) -> Vec<String> {
+        Python::with_gil(|py| errors.extract(py).unwrap())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn debounced_subscription_send_failure_test() {
+        prepare_freethreaded_python();
+
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let server = setup_recording_server(server, received.clone());
+        let (errors, error_handler) = subscription_error_recorder();
+
+        // Close the writer, so the debounced send fails once the window elapses
+        let (stream, _) = connect_async(format!("ws://127.0.0.1:{port}"))
+            .await
+            .unwrap();
+        let (mut writer, _reader) = stream.split();
+        writer.close().await.unwrap();
+        let writer = Arc::new(tokio::sync::Mutex::new(writer));
+        let subscriptions = Arc::new(std::sync::Mutex::new(SubscriptionDebouncer::new(
+            Some(50),
+            Some(Arc::new(error_handler)),
+        )));
+
+        request_subscription(
+            subscriptions.clone(),
+            writer,
+            "BTCUSDT".to_string(),
+            true,
+            "sub:BTCUSDT".to_string(),
+        )
+        .await
+        .unwrap();
+        let start = Instant::now();
+        while get_errors(&errors).is_empty() && start.elapsed() < Duration::from_secs(2) {
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        // The failure is reported and the subscription state is left unchanged
+        assert_eq!(get_errors(&errors), vec!["BTCUSDT"]);
+        assert!(!subscriptions.lock().unwrap().is_subscribed("BTCUSDT"));
+        assert!(received.lock().unwrap().is_empty());
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn disconnect_cancels_debounced_subscription_test() {
+        prepare_freethreaded_python();
+
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let server = setup_recording_server(server, received.clone());
+        let (_, handler) = heartbeat_counter();
+        let (errors, error_handler) = subscription_error_recorder();
+
+        let config = WebSocketConfig {
+            subscription_debounce_ms: Some(200),
+            subscription_error_handler: Some(Arc::new(error_handler)),
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        client
+            .subscribe("BTCUSDT".to_string(), "sub:BTCUSDT".to_string())
+            .await
+            .unwrap();
+        client.disconnect().await;
+        sleep(Duration::from_millis(400)).await;
+
+        // The flush task was aborted, rather than failing to send on the closed connection
+        assert!(received.lock().unwrap().is_empty());
+        assert!(get_errors(&errors).is_empty());
+        assert!(!logs_contain("Error sending subscription change"));
+        assert!(!client.is_subscribed("BTCUSDT"));
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn ping_pong_keepalive_test() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+        let (_, handler) = heartbeat_counter();
+
+        // The server replies to each ping with a pong while reading
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig {
+            headers: vec![(header_key, header_value)],
+            ping_interval_ms: Some(200),
+            ..test_config(server.port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(1000)).await;
+
+        assert!(!client.is_disconnected());
+        assert!(logs_contain("Sent ping"));
+        assert!(logs_contain("Received pong"));
+        assert!(!logs_contain("Pong not received"));
+
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+    }
+
+    /// Accepts websocket connections and holds them open without reading, so pings are
+    /// never answered, counting the number of connections accepted.
+    async fn setup_unresponsive_server(connections: Arc<AtomicUsize>) -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let mut websockets = Vec::new();
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                websockets.push(accept_async(conn).await.unwrap());
+                connections.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        (task, port)
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn missed_pong_triggers_reconnect_test() {
+        prepare_freethreaded_python();
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let (server, port) = setup_unresponsive_server(connections.clone()).await;
+        let (_, handler) = heartbeat_counter();
+
+        let config = WebSocketConfig {
+            ping_interval_ms: Some(200),
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        // First ping is sent after 200ms, and found unanswered when the next is due
+        sleep(Duration::from_millis(1000)).await;
+
+        assert!(logs_contain(
+            "Pong not received within 200ms - triggering reconnect"
+        ));
+        assert!(connections.load(Ordering::SeqCst) >= 2);
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+        server.abort();
+    }
+
+    /// Closes the first connection with a close frame, holding later connections open.
+    async fn setup_closing_server(code: CloseCode, reason: &'static str) -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let mut websockets = Vec::new();
+            let (conn, _) = server.accept().await.unwrap();
+            let mut websocket = accept_async(conn).await.unwrap();
+            let frame = CloseFrame {
+                code,
+                reason: reason.into(),
+            };
+            websocket.send(Message::Close(Some(frame))).await.unwrap();
+            websockets.push(websocket);
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                websockets.push(accept_async(conn).await.unwrap());
+            }
+        });
+
+        (task, port)
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn last_close_frame_test() {
+        prepare_freethreaded_python();
+
+        let (server, port) = setup_closing_server(CloseCode::Policy, "Rate limit exceeded").await;
+        let (_, handler) = heartbeat_counter();
+
+        let config = test_config(port, handler);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        // Client reads the close frame and reconnects
+        sleep(Duration::from_millis(500)).await;
+
+        let frame = client.last_close_frame().unwrap();
+        assert_eq!(frame.code, CloseCode::Policy);
+        assert_eq!(u16::from(frame.code), 1008);
+        assert_eq!(frame.reason, "Rate limit exceeded");
+        assert!(logs_contain(
+            "Received close message with code 1008 (Rate limit exceeded) - terminating"
+        ));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+        server.abort();
+    }
+
+    /// Records the first `Close` frame received, which is echoed back to the client.
+    async fn setup_close_recording_server(
+        received: Arc<std::sync::Mutex<Option<CloseFrame<'static>>>>,
+    ) -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let (conn, _) = server.accept().await.unwrap();
+            let mut websocket = accept_async(conn).await.unwrap();
+            while let Some(Ok(message)) = websocket.next().await {
+                if let Message::Close(frame) = message {
+                    *received.lock().unwrap() = frame;
+                }
+            }
+        });
+
+        (task, port)
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn close_with_code_test() {
+        prepare_freethreaded_python();
+
+        let received = Arc::new(std::sync::Mutex::new(None));
+        let (server, port) = setup_close_recording_server(received.clone()).await;
+        let (_, handler) = heartbeat_counter();
+
+        let config = test_config(port, handler);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        client
+            .close_with_code(4000, Some("Shutting down".to_string()))
+            .await
+            .unwrap();
+
+        assert!(client.is_disconnected());
+        let frame = received.lock().unwrap().clone().unwrap();
+        assert_eq!(u16::from(frame.code), 4000);
+        assert_eq!(frame.reason, "Shutting down");
+
+        // The server's close frame in reply was received before closing
+        let frame = client.last_close_frame().unwrap();
+        assert_eq!(u16::from(frame.code), 4000);
+        assert!(logs_contain("Close handshake completed"));
+
+        server.abort();
+    }
+
+    #[rstest]
+    #[case(999)]
+    #[case(1005)]
+    #[case(1006)]
+    #[case(1015)]
+    #[case(5000)]
+    #[tokio::test]
+    async fn close_with_invalid_code_test(#[case] code: u16) {
+        prepare_freethreaded_python();
+
+        let received = Arc::new(std::sync::Mutex::new(None));
+        let (server, port) = setup_close_recording_server(received.clone()).await;
+        let (_, handler) = heartbeat_counter();
+
+        let config = test_config(port, handler);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+
+        assert!(client.close_with_code(code, None).await.is_err());
+        assert!(!client.is_disconnected());
+        assert!(client.pending_close_frame.lock().unwrap().is_none());
+
+        client.disconnect().await;
+        assert!(received.lock().unwrap().is_none());
+        server.abort();
+    }
+
+    /// Sends each of the `messages` to the first connection as a text message split into
+    /// continuation frames of `fragment_size` bytes, holding later connections open.
+    async fn setup_fragmenting_server(
+        messages: Vec<&'static str>,
+        fragment_size: usize,
+    ) -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let mut websockets = Vec::new();
+            let (conn, _) = server.accept().await.unwrap();
+            let mut websocket = accept_async(conn).await.unwrap();
+            for message in messages {
+                let chunks: Vec<&[u8]> = message.as_bytes().chunks(fragment_size).collect();
+                for (i, chunk) in chunks.iter().enumerate() {
+                    let opcode = if i == 0 {
+                        OpCode::Data(Data::Text)
+                    } else {
+                        OpCode::Data(Data::Continue)
+                    };
+                    let frame = Frame::message(chunk.to_vec(), opcode, i == chunks.len() - 1);
+                    websocket.send(Message::Frame(frame)).await.unwrap();
+                }
+            }
+            websockets.push(websocket);
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                websockets.push(accept_async(conn).await.unwrap());
+            }
+        });
+
+        (task, port)
+    }
+
+    fn get_received(recorder: &PyObject) -> Vec<String> {
+        Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn fragmented_message_reassembled_test() {
+        prepare_freethreaded_python();
+
+        let message = r#"{"bids":[[100,1]],"asks":[[101,2]]}"#;
+        let (server, port) = setup_fragmenting_server(vec![message], message.len() / 3 + 1).await;
+        let (recorder, handler, _) = auth_recorder(false);
+
+        let config = test_config(port, handler);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(get_received(&recorder), vec![message]);
+
+        client.disconnect().await;
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn fragmented_message_exceeding_max_size_test() {
+        prepare_freethreaded_python();
+
+        let message = "snapshot-snapshot-snapshot";
+        let (server, port) = setup_fragmenting_server(vec!["small", message], 9).await;
+        let (recorder, handler, _) = auth_recorder(false);
+
+        let config = WebSocketConfig {
+            max_message_size: Some(16),
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        // The oversized message is dropped and the client reconnects
+        assert_eq!(get_received(&recorder), vec!["small"]);
+        assert!(logs_contain("Received error message - terminating"));
+        assert!(!client.is_disconnected());
+
+        client.disconnect().await;
+        server.abort();
+    }
+
+    /// Records the text messages received on each connection, handling connections in
+    /// the server task so aborting it drops the live connection.
+    fn setup_recording_server(
+        server: TcpListener,
+        received: Arc<std::sync::Mutex<Vec<String>>>,
+    ) -> JoinHandle<()> {
+        task::spawn(async move {
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                let mut websocket = accept_async(conn).await.unwrap();
+                while let Some(Ok(msg)) = websocket.next().await {
+                    match msg {
+                        Message::Text(text) => received.lock().unwrap().push(text),
+                        Message::Close(_) => break,
+                        _ => (),
+                    }
+                }
+            }
+        })
+    }
+
+    /// Forwards the text messages received on each connection, tagged with the connection
+    /// index, and drops the connection without a close frame on a "drop" message.
+    async fn setup_dropping_server() -> (
+        JoinHandle<()>,
+        u16,
+        tokio::sync::mpsc::UnboundedReceiver<(usize, String)>,
+    ) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let task = task::spawn(async move {
+            for index in 0.. {
+                let (conn, _) = server.accept().await.unwrap();
+                let mut websocket = accept_async(conn).await.unwrap();
+                while let Some(Ok(msg)) = websocket.next().await {
+                    match msg {
+                        Message::Text(text) if text == "drop" => break,
+                        Message::Text(text) => {
+                            let _ = tx.send((index, text));
+                        }
+                        Message::Close(_) => break,
+                        _ => (),
+                    }
+                }
+            }
+        });
+
+        (task, port, rx)
+    }
+
+    async fn next_received(
+        rx: &mut tokio::sync::mpsc::UnboundedReceiver<(usize, String)>,
+    ) -> (usize, String) {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for message")
+            .expect("server stopped")
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn resubscribe_after_reconnect_test() {
+        prepare_freethreaded_python();
+
+        let (server, port, mut rx) = setup_dropping_server().await;
+        let (_, handler) = heartbeat_counter();
+
+        let config = WebSocketConfig {
+            max_reconnection_tries: Some(10),
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        for topic in ["BTCUSDT", "ETHUSDT", "SOLUSDT"] {
+            client
+                .subscribe(topic.to_string(), format!("sub:{topic}"))
+                .await
+                .unwrap();
+        }
+        client
+            .unsubscribe("SOLUSDT".to_string(), "unsub:SOLUSDT".to_string())
+            .await
+            .unwrap();
+
+        for expected in ["sub:BTCUSDT", "sub:ETHUSDT", "sub:SOLUSDT", "unsub:SOLUSDT"] {
+            assert_eq!(next_received(&mut rx).await, (0, expected.to_string()));
+        }
+
+        // Drop the connection, so only the topics still subscribed are resent
+        client.send_text("drop".to_string()).await.unwrap();
+
+        assert_eq!(next_received(&mut rx).await, (1, "sub:BTCUSDT".to_string()));
+        assert_eq!(next_received(&mut rx).await, (1, "sub:ETHUSDT".to_string()));
+        assert!(client.is_subscribed("BTCUSDT"));
+        assert!(client.is_subscribed("ETHUSDT"));
+        assert!(!client.is_subscribed("SOLUSDT"));
+
+        client.disconnect().await;
+        assert!(rx.try_recv().is_err());
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn resubscribe_messages_after_server_restart_test() {
+        prepare_freethreaded_python();
+
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let server = setup_recording_server(server, received.clone());
+        let (_, handler) = heartbeat_counter();
+
+        let config = WebSocketConfig {
+            max_reconnection_tries: Some(50),
+            reconnect_delay_initial_ms: Some(50),
+            resubscribe_messages: vec![b"sub:trades".to_vec()],
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        client.set_resubscribe_messages(vec![b"sub:trades".to_vec(), b"sub:quotes".to_vec()]);
+
+        // Kill the server, dropping the connection, then restore it on the same port
+        server.abort();
+        let _ = server.await;
+        let server = TcpListener::bind(format!("127.0.0.1:{port}"))
+            .await
+            .unwrap();
+        let restored = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let server = setup_recording_server(server, restored.clone());
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while restored.lock().unwrap().len() < 2 {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for resubscribe messages");
+
+        // Only the updated messages are resent, and only after the reconnect
+        assert!(received.lock().unwrap().is_empty());
+        assert_eq!(*restored.lock().unwrap(), vec!["sub:trades", "sub:quotes"]);
+
+        client.disconnect().await;
+        server.abort();
+    }
+
+    /// Sends a text message and then a binary message to the first connection, holding
+    /// later connections open.
+    async fn setup_mixed_frame_server() -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let mut websockets = Vec::new();
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                let mut websocket = accept_async(conn).await.unwrap();
+                if websockets.is_empty() {
+                    websocket
+                        .send(Message::Text("control".to_string()))
+                        .await
+                        .unwrap();
+                    websocket
+                        .send(Message::Binary(vec![0, 1, 2]))
+                        .await
+                        .unwrap();
+                }
+                websockets.push(websocket);
+            }
+        });
+
+        (task, port)
+    }
+
+    fn message_type_recorder() -> (PyObject, PyObject) {
+        Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class MessageTypeRecorder:
+    def __init__(self):
+        self.received = []
+
+    def handler(self, bytes, message_type=None):
+        self.received.append((bytes, message_type))
+
+    def get_received(self):
+        return self.received
+
+recorder = MessageTypeRecorder()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let recorder = pymod.getattr("recorder").unwrap().into_py(py);
+            let handler = recorder.getattr(py, "handler").unwrap().into_py(py);
+
+            (recorder, handler)
+        })
+    }
+
+    #[rstest]
+    #[case(true, Some(WsMessageType::Text), Some(WsMessageType::Binary))]
+    #[case(false, None, None)]
+    #[tokio::test]
+    async fn handler_message_type_test(
+        #[case] include_message_type: bool,
+        #[case] text_type: Option<WsMessageType>,
+        #[case] binary_type: Option<WsMessageType>,
+    ) {
+        prepare_freethreaded_python();
+
+        let (server, port) = setup_mixed_frame_server().await;
+        let (recorder, handler) = message_type_recorder();
+
+        let config = WebSocketConfig {
+            include_message_type,
+            ..test_config(port, handler)
+        };
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None, None)
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        let received: Vec<(Vec<u8>, Option<WsMessageType>)> = Python::with_gil(|py| {
+            recorder
+                .getattr(py, "get_received")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        });
+        assert_eq!(
+            received,
+            vec![
+                (b"control".to_vec(), text_type),
+                (vec![0, 1, 2], binary_type),
+            ]
+        );
+
+        client.disconnect().await;
+        server.abort();
+    }
 }
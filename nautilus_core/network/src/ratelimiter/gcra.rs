@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{cmp, fmt::Display, time::Duration};
+use std::{cmp, fmt::Display, num::NonZeroU32, time::Duration};
 
 use super::{clock, nanos::Nanos, quota::Quota, StateStore};
 
@@ -62,6 +62,14 @@ impl StateSnapshot {
             self.tau.as_u64(),
         ) / self.t.as_u64()) as u32
     }
+
+    /// Returns the time from the measurement until the full burst capacity is restored.
+    pub fn reset_after(&self) -> Duration {
+        self.tat
+            .saturating_sub(self.t)
+            .saturating_sub(self.time_of_measurement)
+            .into()
+    }
 }
 
 /// A negative rate-limiting outcome.
@@ -115,6 +123,12 @@ impl<P: clock::Reference> Display for NotUntil<P> {
     }
 }
 
+/// Error indicating that a weighted request can never succeed because its weight exceeds
+/// the burst capacity of the quota.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("required weight exceeds the burst capacity of {0}")]
+pub struct InsufficientCapacity(pub u32);
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Gcra {
     /// The "weight" of a single packet in units of time.
@@ -136,6 +150,12 @@ impl Gcra {
         t0 + self.t
     }
 
+    /// Returns a snapshot of the rate limiter state `tat` measured at `t0`, without updating it.
+    pub(crate) fn snapshot(&self, tat: Option<Nanos>, t0: Nanos) -> StateSnapshot {
+        let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+        StateSnapshot::new(self.t, self.tau, t0, tat)
+    }
+
     /// Tests a single cell against the rate limiter state and updates it at the given key.
     pub(crate) fn test_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
         &self,
@@ -161,4 +181,40 @@ impl Gcra {
             }
         })
     }
+
+    /// Tests `n` cells at once against the rate limiter state and updates it at the given key.
+    ///
+    /// Either all `n` cells are let through or none are.
+    pub(crate) fn test_n_all_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        n: NonZeroU32,
+        state: &S,
+        t0: P,
+    ) -> Result<Result<(), NotUntil<P>>, InsufficientCapacity> {
+        let t0 = t0.duration_since(start);
+        let tau = self.tau;
+        let t = self.t;
+        let additional_weight = t * u64::from(n.get() - 1);
+
+        // Check that we can allow enough cells through
+        if additional_weight + t > tau {
+            return Err(InsufficientCapacity((tau.as_u64() / t.as_u64()) as u32));
+        }
+
+        Ok(state.measure_and_replace(key, |tat| {
+            let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+            let earliest_time = (tat + additional_weight).saturating_sub(tau);
+            if t0 < earliest_time {
+                Err(NotUntil::new(
+                    StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
+                    start,
+                ))
+            } else {
+                let next = cmp::max(tat, t0) + t + additional_weight;
+                Ok(((), next))
+            }
+        }))
+    }
 }
@@ -23,7 +23,7 @@ pub mod quota;
 
 use std::{
     hash::Hash,
-    num::NonZeroU64,
+    num::{NonZeroU32, NonZeroU64},
     sync::atomic::{AtomicU64, Ordering},
     time::Duration,
 };
@@ -32,9 +32,10 @@ use dashmap::DashMap;
 use futures_util::StreamExt;
 use tokio::time::sleep;
 
+pub use self::gcra::InsufficientCapacity;
 use self::{
-    clock::{Clock, FakeRelativeClock, MonotonicClock},
-    gcra::{Gcra, NotUntil},
+    clock::{Clock, FakeRelativeClock, MonotonicClock, Reference},
+    gcra::{Gcra, NotUntil, StateSnapshot},
     nanos::Nanos,
     quota::Quota,
 };
@@ -51,6 +52,11 @@ use self::{
 pub struct InMemoryState(AtomicU64);
 
 impl InMemoryState {
+    /// Returns the current theoretical arrival time, if any cell has been measured yet.
+    pub(crate) fn peek(&self) -> Option<Nanos> {
+        NonZeroU64::new(self.0.load(Ordering::Acquire)).map(|n| n.get().into())
+    }
+
     pub(crate) fn measure_and_replace_one<T, F, E>(&self, mut f: F) -> Result<T, E>
     where
         F: FnMut(Option<Nanos>) -> Result<(T, Nanos), E>,
@@ -182,10 +188,74 @@ where
         }
     }
 
+    /// Checks whether `weight` cells are available for the given key, consuming all of them
+    /// if so and none otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `weight` exceeds the burst capacity of the key's quota.
+    pub fn check_key_n(
+        &self,
+        key: &K,
+        weight: NonZeroU32,
+    ) -> Result<Result<(), NotUntil<C::Instant>>, InsufficientCapacity> {
+        match self.gcra.get(key) {
+            Some(quota) => {
+                quota.test_n_all_and_update(self.start, key, weight, &self.state, self.clock.now())
+            }
+            None => self.default_gcra.as_ref().map_or(Ok(Ok(())), |gcra| {
+                gcra.test_n_all_and_update(self.start, key, weight, &self.state, self.clock.now())
+            }),
+        }
+    }
+
+    /// Waits until `weight` cells are available for the given key and consumes them.
+    ///
+    /// This models weight-based venue limits, where different operations consume different
+    /// amounts of a shared bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `weight` exceeds the burst capacity of the key's quota, as such a
+    /// request could never be let through.
+    pub async fn acquire(&self, key: &K, weight: NonZeroU32) -> Result<(), InsufficientCapacity> {
+        loop {
+            match self.check_key_n(key, weight)? {
+                Ok(()) => return Ok(()),
+                Err(neg) => sleep(neg.wait_time_from(self.clock.now())).await,
+            }
+        }
+    }
+
+    /// Returns the number of cells which can currently be consumed for the given key without
+    /// being rate limited, or `None` if no quota applies to the key.
+    pub fn remaining(&self, key: &K) -> Option<u32> {
+        self.snapshot(key)
+            .map(|snapshot| snapshot.remaining_burst_capacity())
+    }
+
+    /// Returns the time until the full burst capacity for the given key is restored, or `None`
+    /// if no quota applies to the key.
+    pub fn reset_after(&self, key: &K) -> Option<Duration> {
+        self.snapshot(key).map(|snapshot| snapshot.reset_after())
+    }
+
+    fn snapshot(&self, key: &K) -> Option<StateSnapshot> {
+        let t0 = self.clock.now().duration_since(self.start);
+        let tat = self.state.get(key).and_then(|state| state.peek());
+        match self.gcra.get(key) {
+            Some(gcra) => Some(gcra.snapshot(tat, t0)),
+            None => self
+                .default_gcra
+                .as_ref()
+                .map(|gcra| gcra.snapshot(tat, t0)),
+        }
+    }
+
     pub async fn until_key_ready(&self, key: &K) {
         loop {
             match self.check_key(key) {
-                Ok(x) => x,
+                Ok(()) => break,
                 Err(neg) => {
                     sleep(neg.wait_time_from(self.clock.now())).await;
                 }
@@ -210,7 +280,10 @@ where
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{num::NonZeroU32, time::Duration};
+    use std::{
+        num::NonZeroU32,
+        time::{Duration, Instant},
+    };
 
     use dashmap::DashMap;
 
@@ -218,7 +291,7 @@ mod tests {
         clock::{Clock, FakeRelativeClock},
         gcra::Gcra,
         quota::Quota,
-        DashMapStateStore, RateLimiter,
+        DashMapStateStore, InsufficientCapacity, RateLimiter,
     };
 
     fn initialize_mock_rate_limiter() -> RateLimiter<String, FakeRelativeClock> {
@@ -342,4 +415,128 @@ mod tests {
         assert!(mock_limiter.check_key(&"per_second".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"per_minute".to_string()).is_err());
     }
+
+    #[test]
+    fn test_weighted_check_against_fixed_bucket() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "weighted".to_string();
+        let weight = |n| NonZeroU32::new(n).unwrap();
+
+        mock_limiter.add_quota_for_key(key.clone(), Quota::per_second(weight(10)));
+
+        // Mixed weights consume the shared bucket of 10
+        assert!(mock_limiter.check_key_n(&key, weight(5)).unwrap().is_ok());
+        assert!(mock_limiter.check_key_n(&key, weight(3)).unwrap().is_ok());
+
+        // Only 2 remaining, a weight of 3 is rejected without consuming anything
+        assert!(mock_limiter.check_key_n(&key, weight(3)).unwrap().is_err());
+        assert!(mock_limiter.check_key_n(&key, weight(2)).unwrap().is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+
+        // Replenishes at one cell per 100ms
+        mock_limiter.advance_clock(Duration::from_millis(300));
+        assert!(mock_limiter.check_key_n(&key, weight(3)).unwrap().is_ok());
+        assert!(mock_limiter.check_key_n(&key, weight(1)).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_weighted_check_exceeding_capacity() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let result = mock_limiter.check_key_n(&"default".to_string(), NonZeroU32::new(3).unwrap());
+
+        assert_eq!(result, Err(InsufficientCapacity(2)));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_blocks_until_weight_available() {
+        let limiter = RateLimiter::new_with_quota(
+            Some(Quota::per_second(NonZeroU32::new(10).unwrap())),
+            vec![],
+        );
+        let key = "weighted".to_string();
+
+        let start = Instant::now();
+        limiter
+            .acquire(&key, NonZeroU32::new(8).unwrap())
+            .await
+            .unwrap();
+        limiter
+            .acquire(&key, NonZeroU32::new(2).unwrap())
+            .await
+            .unwrap();
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        // Bucket is empty, a weight of 4 must wait for 4 cells to replenish (~400ms)
+        limiter
+            .acquire(&key, NonZeroU32::new(4).unwrap())
+            .await
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_remaining_decrements_per_check() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "remaining".to_string();
+
+        mock_limiter.add_quota_for_key(key.clone(), Quota::per_second(NonZeroU32::new(3).unwrap()));
+
+        assert_eq!(mock_limiter.remaining(&key), Some(3));
+        assert_eq!(mock_limiter.reset_after(&key), Some(Duration::ZERO));
+
+        for expected in [2, 1, 0] {
+            assert!(mock_limiter.check_key(&key).is_ok());
+            assert_eq!(mock_limiter.remaining(&key), Some(expected));
+        }
+        assert!(mock_limiter.check_key(&key).is_err());
+        assert_eq!(mock_limiter.remaining(&key), Some(0));
+
+        // Three cells replenish at one per ~333ms
+        let reset_after = mock_limiter.reset_after(&key).unwrap();
+        assert!(reset_after > Duration::from_millis(990) && reset_after <= Duration::from_secs(1));
+
+        mock_limiter.advance_clock(Duration::from_millis(400));
+        assert_eq!(mock_limiter.remaining(&key), Some(1));
+
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert_eq!(mock_limiter.remaining(&key), Some(3));
+        assert_eq!(mock_limiter.reset_after(&key), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_remaining_for_weighted_checks_and_default_quota() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "weighted".to_string();
+
+        mock_limiter
+            .add_quota_for_key(key.clone(), Quota::per_second(NonZeroU32::new(10).unwrap()));
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(4).unwrap())
+            .unwrap()
+            .is_ok());
+
+        assert_eq!(mock_limiter.remaining(&key), Some(6));
+        assert_eq!(mock_limiter.remaining(&"default".to_string()), Some(2));
+    }
+
+    #[test]
+    fn test_remaining_without_quota() {
+        let limiter: RateLimiter<String, _> = RateLimiter::new_with_quota(None, vec![]);
+        let key = "unlimited".to_string();
+
+        assert!(limiter.check_key(&key).is_ok());
+        assert_eq!(limiter.remaining(&key), None);
+        assert_eq!(limiter.reset_after(&key), None);
+    }
+
+    #[tokio::test]
+    async fn test_await_keys_ready_takes_one_cell_per_key() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "ready".to_string();
+
+        mock_limiter.add_quota_for_key(key.clone(), Quota::per_second(NonZeroU32::new(3).unwrap()));
+        mock_limiter.await_keys_ready(Some(vec![key.clone()])).await;
+
+        assert_eq!(mock_limiter.remaining(&key), Some(2));
+    }
 }
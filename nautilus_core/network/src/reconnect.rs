@@ -0,0 +1,96 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Process-wide dampening of concurrent reconnect attempts.
+//!
+//! When many clients lose their connections at the same time (e.g. after a shared
+//! network blip) they would otherwise all reconnect at once and could overwhelm the
+//! venue. An optional limit staggers the reconnects: every client acquires a permit
+//! before attempting to reconnect and releases it once the attempt completes.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::backoff::{Backoff, BackoffStrategy};
+
+/// The default initial delay (milliseconds) between reconnect attempts.
+const DEFAULT_RECONNECT_DELAY_INITIAL_MS: u64 = 1_000;
+
+/// The default maximum delay (milliseconds) between reconnect attempts.
+const DEFAULT_RECONNECT_DELAY_MAX_MS: u64 = 30_000;
+
+static RECONNECT_SEMAPHORE: Mutex<Option<Arc<Semaphore>>> = Mutex::new(None);
+
+/// Sets the maximum number of concurrent reconnect attempts across the process.
+///
+/// A `limit` of `None` removes the limit. Attempts already waiting on a previous
+/// limit continue to wait on it.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `limit` is zero.
+pub fn set_max_concurrent_reconnects(limit: Option<usize>) {
+    assert!(limit != Some(0), "Reconnect limit must be positive");
+    let mut guard = RECONNECT_SEMAPHORE
+        .lock()
+        .expect("Reconnect semaphore poisoned");
+    *guard = limit.map(|limit| Arc::new(Semaphore::new(limit)));
+}
+
+/// Waits for a reconnect permit if a process-wide limit is set.
+///
+/// Custom clients should acquire a permit before each reconnect attempt.
+///
+/// The permit is held until it is dropped, which should be when the reconnect
+/// attempt completes.
+pub async fn acquire_reconnect_permit() -> Option<OwnedSemaphorePermit> {
+    let semaphore = RECONNECT_SEMAPHORE
+        .lock()
+        .expect("Reconnect semaphore poisoned")
+        .clone()?;
+    semaphore.acquire_owned().await.ok()
+}
+
+/// Creates the [`Backoff`] for the delays between reconnect attempts with the `strategy`,
+/// defaulting to an initial delay of 1 second and a max delay of 30 seconds.
+///
+/// # Errors
+///
+/// Returns an error if the initial delay is zero or exceeds the max delay.
+pub(crate) fn reconnect_backoff(
+    strategy: BackoffStrategy,
+    delay_initial_ms: Option<u64>,
+    delay_max_ms: Option<u64>,
+) -> std::io::Result<Backoff> {
+    let delay_initial_ms = delay_initial_ms.unwrap_or(DEFAULT_RECONNECT_DELAY_INITIAL_MS);
+    let delay_max_ms = delay_max_ms.unwrap_or(DEFAULT_RECONNECT_DELAY_MAX_MS);
+    if delay_initial_ms == 0 || delay_initial_ms > delay_max_ms {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "Invalid reconnect delays: initial {delay_initial_ms}ms must be positive and not exceed max {delay_max_ms}ms"
+            ),
+        ));
+    }
+    Ok(strategy.backoff(
+        Duration::from_millis(delay_initial_ms),
+        Duration::from_millis(delay_max_ms),
+    ))
+}
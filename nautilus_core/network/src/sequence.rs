@@ -0,0 +1,159 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sequence number tracking for detecting gaps in message feeds.
+
+/// Extracts a monotonically increasing sequence number from a raw message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceExtractor {
+    /// A JSON pointer (RFC 6901) to an unsigned integer field, e.g. `/data/seq`.
+    JsonPointer(String),
+    /// A big-endian unsigned integer of `len` bytes (at most 8) starting at `offset`.
+    ByteOffset { offset: usize, len: usize },
+}
+
+impl SequenceExtractor {
+    /// Returns the sequence number of the message, or `None` if the message carries none.
+    #[must_use]
+    pub fn extract(&self, data: &[u8]) -> Option<u64> {
+        match self {
+            Self::JsonPointer(pointer) => serde_json::from_slice::<serde_json::Value>(data)
+                .ok()?
+                .pointer(pointer)?
+                .as_u64(),
+            Self::ByteOffset { offset, len } => {
+                if *len == 0 || *len > 8 {
+                    return None;
+                }
+                let bytes = data.get(*offset..offset + len)?;
+                Some(
+                    bytes
+                        .iter()
+                        .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte)),
+                )
+            }
+        }
+    }
+}
+
+/// Tracks the sequence numbers of received messages to detect gaps.
+///
+/// Messages without a sequence number (e.g. heartbeats) are ignored, as are duplicate or
+/// out-of-order sequence numbers which do not skip ahead of the last one seen.
+#[derive(Debug, Clone)]
+pub struct SequenceTracker {
+    extractor: SequenceExtractor,
+    last: Option<u64>,
+}
+
+impl SequenceTracker {
+    /// Creates a new [`SequenceTracker`] instance.
+    #[must_use]
+    pub const fn new(extractor: SequenceExtractor) -> Self {
+        Self {
+            extractor,
+            last: None,
+        }
+    }
+
+    /// Returns the last sequence number seen.
+    #[must_use]
+    pub const fn last(&self) -> Option<u64> {
+        self.last
+    }
+
+    /// Records the sequence number of the message, returning the `(expected, got)` sequence
+    /// numbers if one or more messages were skipped.
+    pub fn check(&mut self, data: &[u8]) -> Option<(u64, u64)> {
+        let got = self.extractor.extract(data)?;
+        let gap = match self.last {
+            Some(last) if got > last + 1 => Some((last + 1, got)),
+            _ => None,
+        };
+        self.last = Some(self.last.map_or(got, |last| last.max(got)));
+        gap
+    }
+
+    /// Resets the tracker so the next sequence number seen is accepted as the start.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{SequenceExtractor, SequenceTracker};
+
+    #[rstest]
+    #[case(r#"{"seq":42}"#, "/seq", Some(42))]
+    #[case(r#"{"data":{"u":7}}"#, "/data/u", Some(7))]
+    #[case(r#"{"data":{"u":"7"}}"#, "/data/u", None)]
+    #[case(r#"{"op":"pong"}"#, "/seq", None)]
+    #[case("not json", "/seq", None)]
+    fn test_extract_json_pointer(
+        #[case] data: &str,
+        #[case] pointer: &str,
+        #[case] expected: Option<u64>,
+    ) {
+        let extractor = SequenceExtractor::JsonPointer(pointer.to_string());
+        assert_eq!(extractor.extract(data.as_bytes()), expected);
+    }
+
+    #[rstest]
+    #[case(&[0xFF, 0x00, 0x01, 0x02], 1, 3, Some(0x0102))]
+    #[case(&[0x00, 0x00, 0x00, 0x05], 0, 4, Some(5))]
+    #[case(&[0x01, 0x02], 1, 2, None)]
+    #[case(&[0x01, 0x02], 0, 0, None)]
+    fn test_extract_byte_offset(
+        #[case] data: &[u8],
+        #[case] offset: usize,
+        #[case] len: usize,
+        #[case] expected: Option<u64>,
+    ) {
+        let extractor = SequenceExtractor::ByteOffset { offset, len };
+        assert_eq!(extractor.extract(data), expected);
+    }
+
+    #[rstest]
+    fn test_tracker_detects_skipped_sequence() {
+        let mut tracker = SequenceTracker::new(SequenceExtractor::JsonPointer("/seq".to_string()));
+
+        assert_eq!(tracker.check(br#"{"seq":1}"#), None);
+        assert_eq!(tracker.check(br#"{"seq":2}"#), None);
+        assert_eq!(tracker.check(br#"{"op":"heartbeat"}"#), None);
+        assert_eq!(tracker.check(br#"{"seq":5}"#), Some((3, 5)));
+        assert_eq!(tracker.check(br#"{"seq":6}"#), None);
+        assert_eq!(tracker.last(), Some(6));
+    }
+
+    #[rstest]
+    fn test_tracker_ignores_duplicates_and_resets() {
+        let mut tracker = SequenceTracker::new(SequenceExtractor::JsonPointer("/seq".to_string()));
+
+        assert_eq!(tracker.check(br#"{"seq":10}"#), None);
+        assert_eq!(tracker.check(br#"{"seq":10}"#), None);
+        assert_eq!(tracker.check(br#"{"seq":9}"#), None);
+        assert_eq!(tracker.last(), Some(10));
+
+        tracker.reset();
+        assert_eq!(tracker.check(br#"{"seq":100}"#), None);
+        assert_eq!(tracker.check(br#"{"seq":102}"#), Some((101, 102)));
+    }
+}
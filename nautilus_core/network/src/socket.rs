@@ -16,19 +16,27 @@
 //! A high-performance raw TCP client implementation with TLS capability.
 
 use std::{
+    collections::VecDeque,
+    num::NonZeroUsize,
+    path::PathBuf,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant, SystemTime},
 };
 
-use nautilus_cryptography::providers::install_cryptographic_provider;
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_cryptography::{
+    providers::install_cryptographic_provider,
+    tls::{create_tls_config_with_client_cert, load_client_cert},
+};
 use pyo3::prelude::*;
+use socket2::SockRef;
 use tokio::{
-    io::{split, AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf},
+    io::{split, AsyncBufReadExt, AsyncWriteExt, BufReader, ReadHalf, WriteHalf},
     net::TcpStream,
-    sync::Mutex,
+    sync::{mpsc, oneshot, Mutex},
     task,
     time::sleep,
 };
@@ -36,12 +44,183 @@ use tokio_tungstenite::{
     tungstenite::{client::IntoClientRequest, stream::Mode, Error},
     MaybeTlsStream,
 };
+use tokio_util::sync::CancellationToken;
 
-use crate::tls::tcp_tls;
+use crate::{
+    backoff::{Backoff, BackoffStrategy},
+    buffer::{BufferBudget, BufferLease},
+    reconnect::{acquire_reconnect_permit, reconnect_backoff},
+    tls::{tcp_tls, Connector},
+};
 
 type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
 type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
 type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
+type SharedClientCert = Arc<std::sync::Mutex<Option<Vec<u8>>>>;
+/// Data queued for the dedicated writer task, with the sender to report the write result.
+type QueuedWrite = (Vec<u8>, oneshot::Sender<Result<(), std::io::Error>>);
+
+/// The default lead time before client certificate expiry to proactively reconnect.
+const DEFAULT_CERT_EXPIRY_LEAD_SECS: u64 = 300;
+
+fn writer_task_stopped() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::NotConnected, "Writer task has stopped")
+}
+
+fn writer_poisoned() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::BrokenPipe,
+        "Writer poisoned by an incomplete send, awaiting reconnect",
+    )
+}
+
+/// Awaits the `send` future, failing with an [`std::io::ErrorKind::TimedOut`] error if it
+/// does not complete within `timeout_ms` (or never timing out when `None`).
+pub(crate) async fn with_send_timeout(
+    send: impl std::future::Future<Output = Result<(), std::io::Error>>,
+    timeout_ms: Option<u64>,
+) -> Result<(), std::io::Error> {
+    let Some(timeout_ms) = timeout_ms else {
+        return send.await;
+    };
+
+    tokio::time::timeout(Duration::from_millis(timeout_ms), send)
+        .await
+        .unwrap_or_else(|_| {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("Send timed out after {timeout_ms}ms"),
+            ))
+        })
+}
+
+/// Marks the writer `poisoned` when dropped while still armed, i.e. when a write fails or
+/// is cancelled before it completes.
+struct PoisonOnDrop<'a> {
+    poisoned: &'a AtomicBool,
+    armed: bool,
+}
+
+impl Drop for PoisonOnDrop<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            self.poisoned.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Writes all the `data` as one frame, then flushes the writer if `flush` is set, so
+/// data buffered by the stream (e.g. by TLS) is written to the socket.
+///
+/// A write which fails or is cancelled part way through a frame (e.g. by a send timeout)
+/// marks the writer `poisoned`, so it is never followed by another frame on the same
+/// connection, and the client reconnects. A write still in flight leaves the writer alive.
+pub(crate) async fn write_data(
+    writer: &mut TcpWriter,
+    poisoned: &AtomicBool,
+    data: &[u8],
+    flush: bool,
+) -> Result<(), std::io::Error> {
+    if poisoned.load(Ordering::SeqCst) {
+        return Err(writer_poisoned());
+    }
+    let mut guard = PoisonOnDrop {
+        poisoned,
+        armed: true,
+    };
+    writer.write_all(data).await?;
+    if flush {
+        writer.flush().await?;
+    }
+    guard.armed = false;
+    Ok(())
+}
+
+/// Flushes the writer, so data buffered by the stream (e.g. by TLS) is written to the
+/// socket, unless the writer is `poisoned` by an incomplete write.
+pub(crate) async fn flush_data(
+    writer: &mut TcpWriter,
+    poisoned: &AtomicBool,
+) -> Result<(), std::io::Error> {
+    if poisoned.load(Ordering::SeqCst) {
+        return Err(writer_poisoned());
+    }
+    writer.flush().await
+}
+
+/// Tracks the reconnects and current connection uptime of a client.
+#[derive(Debug, Default)]
+pub(crate) struct ConnectionStats {
+    reconnect_count: AtomicU64,
+    /// The UNIX nanoseconds the current connection was established, or zero if not connected.
+    connected_since: AtomicU64,
+    /// The delay (milliseconds) before the next reconnect attempt, or zero if not reconnecting.
+    reconnect_delay_ms: AtomicU64,
+}
+
+impl ConnectionStats {
+    /// Returns the next delay from the `backoff`, recording it as the delay before the
+    /// next reconnect attempt.
+    pub(crate) fn next_reconnect_delay(&self, backoff: &mut Backoff) -> Duration {
+        let delay = backoff.next_duration();
+        self.reconnect_delay_ms
+            .store(delay.as_millis() as u64, Ordering::SeqCst);
+        delay
+    }
+
+    /// Returns the delay before the next reconnect attempt, or `None` if not reconnecting.
+    pub(crate) fn reconnect_delay_ms(&self) -> Option<u64> {
+        match self.reconnect_delay_ms.load(Ordering::SeqCst) {
+            0 => None,
+            delay => Some(delay),
+        }
+    }
+
+    fn on_connected(&self) {
+        let now = get_atomic_clock_realtime().get_time_ns().as_u64();
+        self.connected_since.store(now, Ordering::SeqCst);
+    }
+
+    fn on_reconnected(&self) {
+        // Record the new connection before counting it, so it is visible with the count
+        self.on_connected();
+        self.reconnect_delay_ms.store(0, Ordering::SeqCst);
+        self.reconnect_count.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn on_disconnected(&self) {
+        self.connected_since.store(0, Ordering::SeqCst);
+    }
+
+    /// Resets the stats once the client is closed, as they only describe a live client.
+    fn on_closed(&self) {
+        self.reconnect_count.store(0, Ordering::SeqCst);
+        self.reconnect_delay_ms.store(0, Ordering::SeqCst);
+        self.on_disconnected();
+    }
+}
+
+/// Tracks the data received by a client, across reconnects.
+#[derive(Debug, Default)]
+struct ReceiveStats {
+    bytes_received: AtomicU64,
+    messages_received: AtomicU64,
+    /// The UNIX nanoseconds the last message was passed to the handler, or zero if none.
+    last_message_ns: AtomicU64,
+}
+
+impl ReceiveStats {
+    fn on_bytes(&self, bytes: usize) {
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn on_message(&self) {
+        let now = get_atomic_clock_realtime().get_time_ns().as_u64();
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.last_message_ns.store(now, Ordering::Relaxed);
+    }
+}
 
 /// Configuration for TCP socket connection.
 #[derive(Debug, Clone)]
@@ -60,6 +239,64 @@ pub struct SocketConfig {
     pub handler: Arc<PyObject>,
     /// The optional heartbeat with period and beat message.
     pub heartbeat: Option<(u64, Vec<u8>)>,
+    /// The optional timeout in milliseconds since the last inbound data, after which the
+    /// peer is considered dead and the client reconnects.
+    pub heartbeat_timeout_ms: Option<u64>,
+    /// The optional directory of PEM files with the client certificate chain and key.
+    pub certs_dir: Option<PathBuf>,
+    /// The lead time in seconds before client certificate expiry to proactively reconnect,
+    /// reloading the certificate from `certs_dir` (default 300).
+    pub cert_expiry_lead_secs: Option<u64>,
+    /// The optional budget capping the bytes held in the receive buffers of all clients
+    /// sharing it. Reads wait while the budget is exhausted, applying backpressure to the
+    /// server, and a message which exceeds the whole budget terminates the connection.
+    pub buffer_budget: Option<BufferBudget>,
+    /// The maximum number of consecutive reconnect attempts before the client closes
+    /// the connection, or `None` to keep reconnecting indefinitely.
+    pub max_reconnect_attempts: Option<u32>,
+    /// The strategy for the delays between reconnect attempts, starting from
+    /// `reconnect_delay_initial_ms` up to `reconnect_delay_max_ms`.
+    pub reconnect_backoff: BackoffStrategy,
+    /// The initial delay (milliseconds) between reconnect attempts (default 1000).
+    pub reconnect_delay_initial_ms: Option<u64>,
+    /// The maximum delay (milliseconds) between reconnect attempts (default 30000).
+    pub reconnect_delay_max_ms: Option<u64>,
+    /// The optional capacity of a channel on which sends are queued in order for a
+    /// dedicated writer task, rather than each sender contending on the writer lock.
+    /// Senders wait for capacity while the channel is full.
+    pub send_channel_capacity: Option<NonZeroUsize>,
+    /// If `TCP_NODELAY` is set, sending small messages immediately rather than
+    /// coalescing them (Nagle's algorithm).
+    pub nodelay: bool,
+    /// The optional size (bytes) of the socket receive buffer (`SO_RCVBUF`).
+    pub recv_buffer_size: Option<usize>,
+    /// The optional size (bytes) of the socket send buffer (`SO_SNDBUF`).
+    pub send_buffer_size: Option<usize>,
+    /// If the writer is flushed after each send, rather than relying on the stream
+    /// (e.g. a buffered TLS session) to write the data to the socket.
+    pub auto_flush: bool,
+}
+
+/// Sets the `TCP_NODELAY` and optional receive and send buffer sizes on the `stream`.
+///
+/// # Errors
+///
+/// Returns an error if any of the options cannot be set on the socket.
+pub fn set_socket_options(
+    stream: &TcpStream,
+    nodelay: bool,
+    recv_buffer_size: Option<usize>,
+    send_buffer_size: Option<usize>,
+) -> std::io::Result<()> {
+    let socket = SockRef::from(stream);
+    socket.set_nodelay(nodelay)?;
+    if let Some(size) = recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(size) = send_buffer_size {
+        socket.set_send_buffer_size(size)?;
+    }
+    Ok(())
 }
 
 /// Creates a TcpStream with the server.
@@ -77,6 +314,12 @@ pub struct SocketConfig {
 /// The client uses a suffix to separate messages on the byte stream. It is
 /// appended to all sent messages and heartbeats. It is also used the split
 /// the received byte stream.
+///
+/// When a `certs_dir` is configured the client presents the client certificate
+/// loaded from it, and reconnects ahead of the certificate expiry so a rotated
+/// certificate is picked up before the connection is rejected. The certificate is
+/// reloaded and the TLS connector rebuilt on every reconnect, so certificates
+/// rotated on disk are used without restarting the client.
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
@@ -85,7 +328,15 @@ struct SocketClientInner {
     config: SocketConfig,
     read_task: task::JoinHandle<()>,
     heartbeat_task: Option<task::JoinHandle<()>>,
+    cert_expiry_task: Option<task::JoinHandle<()>>,
     writer: SharedTcpWriter,
+    writer_poisoned: Arc<AtomicBool>,
+    writer_task: Option<task::JoinHandle<()>>,
+    send_queue: Option<mpsc::Sender<QueuedWrite>>,
+    send_receiver: Option<Arc<Mutex<mpsc::Receiver<QueuedWrite>>>>,
+    buffered_bytes: Arc<AtomicUsize>,
+    receive_stats: Arc<ReceiveStats>,
+    client_cert: SharedClientCert,
 }
 
 impl SocketClientInner {
@@ -96,51 +347,193 @@ impl SocketClientInner {
             url,
             mode,
             heartbeat,
+            heartbeat_timeout_ms,
             suffix,
             handler,
+            certs_dir,
+            cert_expiry_lead_secs,
+            buffer_budget,
+            max_reconnect_attempts: _,
+            reconnect_backoff: _,
+            reconnect_delay_initial_ms: _,
+            reconnect_delay_max_ms: _,
+            send_channel_capacity,
+            nodelay,
+            recv_buffer_size,
+            send_buffer_size,
+            auto_flush,
         } = &config;
-        let (reader, writer) = Self::tls_connect_with_server(url, *mode).await?;
+        let (connector, cert) = Self::load_connector(certs_dir.as_ref())?;
+        let (reader, writer) = Self::tls_connect_with_server(
+            url,
+            *mode,
+            connector,
+            *nodelay,
+            *recv_buffer_size,
+            *send_buffer_size,
+        )
+        .await?;
         let shared_writer = Arc::new(Mutex::new(writer));
+        let writer_poisoned = Arc::new(AtomicBool::new(false));
+        let cert_not_after = cert.as_ref().map(|(_, not_after)| *not_after);
+        let client_cert = Arc::new(std::sync::Mutex::new(cert.map(|(der, _)| der)));
 
         let handler1 = Python::with_gil(|py| handler.clone_ref(py));
+        let buffered_bytes = Arc::new(AtomicUsize::new(0));
+        let receive_stats = Arc::new(ReceiveStats::default());
         // Keep receiving messages from socket pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler1, suffix.clone());
+        let read_task = Self::spawn_read_task(
+            reader,
+            handler1,
+            suffix.clone(),
+            buffered_bytes.clone(),
+            receive_stats.clone(),
+            buffer_budget.clone(),
+            *heartbeat_timeout_ms,
+        );
 
         // Optionally create heartbeat task
-        let heartbeat_task =
-            Self::spawn_heartbeat_task(heartbeat.clone(), shared_writer.clone(), suffix.clone());
+        let heartbeat_task = Self::spawn_heartbeat_task(
+            heartbeat.clone(),
+            shared_writer.clone(),
+            writer_poisoned.clone(),
+            suffix.clone(),
+        );
+
+        // Optionally create client certificate expiry task
+        let cert_expiry_task = Self::spawn_cert_expiry_task(cert_not_after, *cert_expiry_lead_secs);
+
+        // Optionally create the send channel and its writer task
+        let (send_queue, send_receiver) = match send_channel_capacity {
+            Some(capacity) => {
+                let (send_queue, receiver) = mpsc::channel::<QueuedWrite>(capacity.get());
+                (Some(send_queue), Some(Arc::new(Mutex::new(receiver))))
+            }
+            None => (None, None),
+        };
+        let writer_task = send_receiver.clone().map(|receiver| {
+            Self::spawn_writer_task(
+                receiver,
+                shared_writer.clone(),
+                writer_poisoned.clone(),
+                *auto_flush,
+            )
+        });
 
         Ok(Self {
             config,
             read_task,
             heartbeat_task,
+            cert_expiry_task,
             writer: shared_writer,
+            writer_poisoned,
+            writer_task,
+            send_queue,
+            send_receiver,
+            buffered_bytes,
+            receive_stats,
+            client_cert,
         })
     }
 
     pub async fn tls_connect_with_server(
         url: &str,
         mode: Mode,
+        connector: Option<Connector>,
+        nodelay: bool,
+        recv_buffer_size: Option<usize>,
+        send_buffer_size: Option<usize>,
     ) -> Result<(TcpReader, TcpWriter), Error> {
         tracing::debug!("Connecting to server");
         let stream = TcpStream::connect(url).await?;
+        set_socket_options(&stream, nodelay, recv_buffer_size, send_buffer_size)?;
         tracing::debug!("Making TLS connection");
         let request = url.into_client_request()?;
-        tcp_tls(&request, mode, stream, None).await.map(split)
+        tcp_tls(&request, mode, stream, connector).await.map(split)
     }
 
+    /// Loads the client certificate from `certs_dir`, returning a connector which
+    /// presents it, with the DER encoded leaf certificate and its expiry time.
+    ///
+    /// TODO: fix error type
+    fn load_connector(
+        certs_dir: Option<&PathBuf>,
+    ) -> Result<(Option<Connector>, Option<(Vec<u8>, SystemTime)>), Error> {
+        let Some(certs_dir) = certs_dir else {
+            return Ok((None, None));
+        };
+
+        let cert = load_client_cert(certs_dir)
+            .map_err(|e| Error::Io(std::io::Error::other(e.to_string())))?;
+        let leaf = cert
+            .chain
+            .first()
+            .map(|der| der.to_vec())
+            .unwrap_or_default();
+        let not_after = cert.not_after;
+        let config = create_tls_config_with_client_cert(cert)
+            .map_err(|e| Error::Io(std::io::Error::other(e.to_string())))?;
+        tracing::debug!("Loaded client certificate from {}", certs_dir.display());
+
+        Ok((Some(Connector::Rustls(config)), Some((leaf, not_after))))
+    }
+
+    /// Spawns the task receiving messages from the socket and passing them to the `handler`.
+    ///
+    /// If a `heartbeat_timeout_ms` is given, the task finishes when no data has been
+    /// received for that long, so the controller reconnects to an unresponsive peer.
+    /// Time spent waiting for the `buffer_budget` does not count towards the timeout.
     #[must_use]
     pub fn spawn_read_task(
-        mut reader: TcpReader,
+        reader: TcpReader,
         handler: PyObject,
         suffix: Vec<u8>,
+        buffered_bytes: Arc<AtomicUsize>,
+        receive_stats: Arc<ReceiveStats>,
+        buffer_budget: Option<BufferBudget>,
+        heartbeat_timeout_ms: Option<u64>,
     ) -> task::JoinHandle<()> {
         // Keep receiving messages from socket pass them as arguments to handler
         task::spawn(async move {
+            // Received data waits in the reader until budget is reserved for it, so an
+            // idle connection holds no budget
+            let mut reader = BufReader::new(reader);
             let mut buf = Vec::new();
+            buffered_bytes.store(0, Ordering::Relaxed);
+            let heartbeat_timeout = heartbeat_timeout_ms.map(Duration::from_millis);
+            let mut last_received = Instant::now();
+            // The lease releases the budget held by the buffer when the task ends
+            let mut lease = buffer_budget.map(BufferLease::new);
 
             loop {
-                match reader.read_buf(&mut buf).await {
+                if let Some(lease) = lease.as_ref() {
+                    if buf.len() >= lease.capacity() {
+                        tracing::error!(
+                            "Message exceeds buffer budget of {} bytes - terminating",
+                            lease.capacity(),
+                        );
+                        break;
+                    }
+                }
+
+                let read = async { reader.fill_buf().await.map(<[u8]>::len) };
+                let result = match heartbeat_timeout {
+                    Some(timeout) => {
+                        let remaining = timeout.saturating_sub(last_received.elapsed());
+                        if let Ok(result) = tokio::time::timeout(remaining, read).await {
+                            result
+                        } else {
+                            tracing::error!(
+                                "No data received within {}ms, triggering reconnect",
+                                timeout.as_millis(),
+                            );
+                            break;
+                        }
+                    }
+                    None => read.await,
+                };
+
+                match result {
                     // Connection has been terminated or vector buffer is completely
                     Ok(0) => {
                         tracing::error!("Cannot read anymore bytes");
@@ -151,8 +544,23 @@ impl SocketClientInner {
                         break;
                     }
                     // Received bytes of data
-                    Ok(bytes) => {
+                    Ok(available) => {
+                        last_received = Instant::now();
+
+                        // Only take up to the budget reserved for the buffer
+                        let bytes = match lease.as_mut() {
+                            Some(lease) => {
+                                let max = available.min(lease.capacity() - buf.len());
+                                let reserved = lease.reserve(max).await;
+                                last_received = Instant::now();
+                                reserved
+                            }
+                            None => available,
+                        };
+                        buf.extend_from_slice(&reader.buffer()[..bytes]);
+                        reader.consume(bytes);
                         tracing::trace!("Received <binary> {bytes} bytes");
+                        receive_stats.on_bytes(bytes);
 
                         // While received data has a line break
                         // drain it and pass it to the handler
@@ -163,6 +571,7 @@ impl SocketClientInner {
                         {
                             let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
                             data.truncate(data.len() - suffix.len());
+                            receive_stats.on_message();
 
                             if let Err(e) =
                                 Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
@@ -171,6 +580,10 @@ impl SocketClientInner {
                                 break;
                             }
                         }
+                        if let Some(lease) = lease.as_mut() {
+                            lease.shrink_to(buf.len());
+                        }
+                        buffered_bytes.store(buf.len(), Ordering::Relaxed);
                     }
                 };
             }
@@ -181,6 +594,7 @@ impl SocketClientInner {
     pub fn spawn_heartbeat_task(
         heartbeat: Option<(u64, Vec<u8>)>,
         writer: SharedTcpWriter,
+        writer_poisoned: Arc<AtomicBool>,
         suffix: Vec<u8>,
     ) -> Option<task::JoinHandle<()>> {
         heartbeat.map(|(duration, mut message)| {
@@ -191,15 +605,80 @@ impl SocketClientInner {
                     sleep(duration).await;
                     tracing::debug!("Sending heartbeat");
                     let mut guard = writer.lock().await;
-                    match guard.write_all(&message).await {
+                    match write_data(&mut guard, &writer_poisoned, &message, false).await {
                         Ok(()) => tracing::debug!("Sent heartbeat"),
-                        Err(e) => tracing::error!("Failed to send heartbeat: {e}"),
+                        Err(e) => {
+                            // Terminate the task so the controller detects the
+                            // dead connection and reconnects
+                            tracing::error!("Heartbeat send failed, triggering reconnect: {e}");
+                            break;
+                        }
                     }
                 }
             })
         })
     }
 
+    /// Optionally spawn a task which finishes `lead_secs` before the client
+    /// certificate expires, so the controller reconnects and reloads it.
+    pub fn spawn_cert_expiry_task(
+        not_after: Option<SystemTime>,
+        lead_secs: Option<u64>,
+    ) -> Option<task::JoinHandle<()>> {
+        let not_after = not_after?;
+        let lead = Duration::from_secs(lead_secs.unwrap_or(DEFAULT_CERT_EXPIRY_LEAD_SECS));
+        let deadline = not_after
+            .checked_sub(lead)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let Ok(remaining) = deadline.duration_since(SystemTime::now()) else {
+            tracing::warn!(
+                "Client certificate expires within {}s, proactive reconnect disabled until rotated",
+                lead.as_secs(),
+            );
+            return None;
+        };
+
+        Some(task::spawn(async move {
+            sleep(remaining).await;
+            // Terminate the task so the controller reconnects with a reloaded certificate
+            tracing::warn!("Client certificate expiring, triggering reconnect");
+        }))
+    }
+
+    /// Spawns the task writing the data queued on the send channel in order.
+    ///
+    /// Data already queued when the writer lock is acquired is written under the same
+    /// lock. Data whose sender has stopped waiting (e.g. after a send timeout) before it
+    /// is written is dropped, so a retried send is not written twice.
+    fn spawn_writer_task(
+        receiver: Arc<Mutex<mpsc::Receiver<QueuedWrite>>>,
+        writer: SharedTcpWriter,
+        writer_poisoned: Arc<AtomicBool>,
+        auto_flush: bool,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
+            let mut receiver = receiver.lock().await;
+            while let Some(queued) = receiver.recv().await {
+                let mut guard = writer.lock().await;
+                let mut next = Some(queued);
+                while let Some((data, result_tx)) = next {
+                    if result_tx.is_closed() {
+                        tracing::debug!("Dropping queued data abandoned by its sender");
+                    } else {
+                        let result =
+                            write_data(&mut guard, &writer_poisoned, &data, auto_flush).await;
+                        if let Err(ref e) = result {
+                            tracing::error!("Error writing queued data: {e}");
+                        }
+                        let _ = result_tx.send(result);
+                    }
+                    next = receiver.try_recv().ok();
+                }
+            }
+            tracing::debug!("Writer task finished");
+        })
+    }
+
     /// Shutdown read task and the connection.
     ///
     /// The client must be explicitly shutdown before dropping otherwise
@@ -220,6 +699,24 @@ impl SocketClientInner {
             }
         }
 
+        // Cancel certificate expiry task
+        if let Some(ref handle) = self.cert_expiry_task.take() {
+            if !handle.is_finished() {
+                tracing::debug!("Abort certificate expiry task");
+                handle.abort();
+            }
+        }
+
+        // Cancel writer task, which may be blocked holding the writer lock, and wait
+        // for it to stop so it cannot write after the writer is shutdown
+        if let Some(handle) = self.writer_task.take() {
+            if !handle.is_finished() {
+                tracing::debug!("Abort writer task");
+                handle.abort();
+            }
+            let _ = handle.await;
+        }
+
         tracing::debug!("Shutdown writer");
         let mut writer = self.writer.lock().await;
         writer.shutdown().await
@@ -236,35 +733,112 @@ impl SocketClientInner {
             url,
             mode,
             heartbeat,
+            heartbeat_timeout_ms,
             suffix,
             handler,
+            certs_dir,
+            cert_expiry_lead_secs,
+            buffer_budget,
+            max_reconnect_attempts: _,
+            reconnect_backoff: _,
+            reconnect_delay_initial_ms: _,
+            reconnect_delay_max_ms: _,
+            send_channel_capacity: _,
+            nodelay,
+            recv_buffer_size,
+            send_buffer_size,
+            auto_flush,
         } = &self.config;
+        let _permit = acquire_reconnect_permit().await;
         tracing::debug!("Reconnecting client");
-        let (reader, new_writer) = Self::tls_connect_with_server(url, *mode).await?;
+        // Reload the client certificate, so one rotated on disk is used
+        let (connector, cert) = Self::load_connector(certs_dir.as_ref())?;
+        let (reader, new_writer) = Self::tls_connect_with_server(
+            url,
+            *mode,
+            connector,
+            *nodelay,
+            *recv_buffer_size,
+            *send_buffer_size,
+        )
+        .await?;
+        let cert_not_after = cert.as_ref().map(|(_, not_after)| *not_after);
+        *self.client_cert.lock().unwrap() = cert.map(|(der, _)| der);
+
+        // Abort any tasks still attached to the old connection
+        if !self.read_task.is_finished() {
+            self.read_task.abort();
+        }
+        if let Some(handle) = self.heartbeat_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.cert_expiry_task.take() {
+            handle.abort();
+        }
+        // The writer task may be blocked writing to the old connection
+        if let Some(handle) = self.writer_task.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
 
         tracing::debug!("Use new writer end");
         let mut guard = self.writer.lock().await;
         *guard = new_writer;
+        self.writer_poisoned.store(false, Ordering::SeqCst);
         drop(guard);
 
         let handler1 = Python::with_gil(|py| handler.clone_ref(py));
         tracing::debug!("Recreate reader and heartbeat task");
-        self.read_task = Self::spawn_read_task(reader, handler1, suffix.clone());
-        self.heartbeat_task =
-            Self::spawn_heartbeat_task(heartbeat.clone(), self.writer.clone(), suffix.clone());
+        self.read_task = Self::spawn_read_task(
+            reader,
+            handler1,
+            suffix.clone(),
+            self.buffered_bytes.clone(),
+            self.receive_stats.clone(),
+            buffer_budget.clone(),
+            *heartbeat_timeout_ms,
+        );
+        self.heartbeat_task = Self::spawn_heartbeat_task(
+            heartbeat.clone(),
+            self.writer.clone(),
+            self.writer_poisoned.clone(),
+            suffix.clone(),
+        );
+        self.cert_expiry_task =
+            Self::spawn_cert_expiry_task(cert_not_after, *cert_expiry_lead_secs);
+        self.writer_task = self.send_receiver.clone().map(|receiver| {
+            Self::spawn_writer_task(
+                receiver,
+                self.writer.clone(),
+                self.writer_poisoned.clone(),
+                *auto_flush,
+            )
+        });
         Ok(())
     }
 
     /// Check if the client is still connected.
     ///
-    /// The client is connected if the read task has not finished. It is expected
-    /// that in case of any failure client or server side. The read task will be
-    /// shutdown. There might be some delay between the connection being closed
-    /// and the client detecting it.
+    /// The client is connected if the read task and the optional heartbeat and
+    /// certificate expiry tasks have not finished. It is expected that in case of
+    /// any failure client or server side. The read task will be shutdown, while a
+    /// failed heartbeat write terminates the heartbeat task. An impending client
+    /// certificate expiry finishes the expiry task, and a send which failed or timed
+    /// out poisons the writer. There might be some delay between the connection being
+    /// closed and the client detecting it.
     #[inline]
     #[must_use]
     pub fn is_alive(&self) -> bool {
-        !self.read_task.is_finished()
+        !self.writer_poisoned.load(Ordering::SeqCst)
+            && !self.read_task.is_finished()
+            && !self
+                .heartbeat_task
+                .as_ref()
+                .is_some_and(task::JoinHandle::is_finished)
+            && !self
+                .cert_expiry_task
+                .as_ref()
+                .is_some_and(task::JoinHandle::is_finished)
     }
 }
 
@@ -280,37 +854,115 @@ impl Drop for SocketClientInner {
                 handle.abort();
             }
         }
+
+        // Cancel certificate expiry task
+        if let Some(ref handle) = self.cert_expiry_task.take() {
+            if !handle.is_finished() {
+                handle.abort();
+            }
+        }
+
+        // Cancel writer task
+        if let Some(ref handle) = self.writer_task.take() {
+            if !handle.is_finished() {
+                handle.abort();
+            }
+        }
     }
 }
 
+/// A send waiting to be written, either queued for the writer task or written under
+/// the writer lock.
+enum PendingSend {
+    Queued(Result<oneshot::Receiver<Result<(), std::io::Error>>, std::io::Error>),
+    /// Waiting for capacity on the full send channel.
+    Waiting(
+        mpsc::Sender<QueuedWrite>,
+        QueuedWrite,
+        oneshot::Receiver<Result<(), std::io::Error>>,
+    ),
+    Locked(Vec<u8>),
+}
+
+/// The handlers and reconnect settings of the task controlling a [`SocketClient`]
+/// connection.
+struct ControllerConfig {
+    post_reconnection: Option<PyObject>,
+    post_disconnection: Option<PyObject>,
+    on_reconnect_attempt: Option<PyObject>,
+    max_reconnect_attempts: Option<u32>,
+    reconnect_backoff: Backoff,
+}
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
 )]
 pub struct SocketClient {
     pub(crate) writer: SharedTcpWriter,
+    pub(crate) writer_poisoned: Arc<AtomicBool>,
     pub(crate) controller_task: task::JoinHandle<()>,
     pub(crate) disconnect_mode: Arc<AtomicBool>,
+    pub(crate) cancellation_token: CancellationToken,
     pub(crate) suffix: Vec<u8>,
+    pub(crate) buffered_bytes: Arc<AtomicUsize>,
+    pub(crate) client_cert: SharedClientCert,
+    pub(crate) send_queue: Option<mpsc::Sender<QueuedWrite>>,
+    pub(crate) auto_flush: bool,
+    stats: Arc<ConnectionStats>,
+    receive_stats: Arc<ReceiveStats>,
 }
 
 impl SocketClient {
+    /// Creates a socket client connected to the server.
+    ///
+    /// The optional `on_reconnect_attempt` handler is called with `(attempt, delay_ms)`
+    /// before each reconnect attempt, where `attempt` counts from 1 for each lost connection
+    /// and `delay_ms` is the backoff delay waited before the attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reconnect delays are invalid, or the connection fails.
     pub async fn connect(
         config: SocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        on_reconnect_attempt: Option<PyObject>,
     ) -> Result<Self, Error> {
         let suffix = config.suffix.clone();
+        let backoff = reconnect_backoff(
+            config.reconnect_backoff,
+            config.reconnect_delay_initial_ms,
+            config.reconnect_delay_max_ms,
+        )
+        .map_err(Error::Io)?;
+        let controller_config = ControllerConfig {
+            post_reconnection,
+            post_disconnection,
+            on_reconnect_attempt,
+            max_reconnect_attempts: config.max_reconnect_attempts,
+            reconnect_backoff: backoff,
+        };
+        let auto_flush = config.auto_flush;
         let inner = SocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
+        let writer_poisoned = inner.writer_poisoned.clone();
+        let send_queue = inner.send_queue.clone();
+        let buffered_bytes = inner.buffered_bytes.clone();
+        let receive_stats = inner.receive_stats.clone();
+        let client_cert = inner.client_cert.clone();
         let disconnect_mode = Arc::new(AtomicBool::new(false));
+        let cancellation_token = CancellationToken::new();
+        let stats = Arc::new(ConnectionStats::default());
+        stats.on_connected();
 
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
-            post_reconnection,
-            post_disconnection,
+            cancellation_token.clone(),
+            stats.clone(),
+            controller_config,
         );
 
         if let Some(handler) = post_connection {
@@ -322,18 +974,27 @@ impl SocketClient {
 
         Ok(Self {
             writer,
+            writer_poisoned,
             controller_task,
             disconnect_mode,
+            cancellation_token,
             suffix,
+            buffered_bytes,
+            client_cert,
+            send_queue,
+            auto_flush,
+            stats,
+            receive_stats,
         })
     }
 
     /// Set disconnect mode to true.
     ///
     /// Controller task will periodically check the disconnect mode
-    /// and shutdown the client if it is not alive.
+    /// and shutdown the client if it is not alive, cancelling any reconnect backoff.
     pub async fn disconnect(&self) {
         self.disconnect_mode.store(true, Ordering::SeqCst);
+        self.cancellation_token.cancel();
 
         match tokio::time::timeout(Duration::from_secs(5), async {
             while !self.is_disconnected() {
@@ -352,9 +1013,129 @@ impl SocketClient {
     }
 
     pub async fn send_bytes(&self, data: &[u8]) -> Result<(), std::io::Error> {
-        let mut writer = self.writer.lock().await;
-        writer.write_all(data).await?;
-        writer.write_all(&self.suffix).await
+        self.send_with_timeout(data, None).await
+    }
+
+    /// Sends the `data` followed by the configured suffix, waiting at most `timeout_ms`
+    /// for the writer and the write to complete (or indefinitely when `None`).
+    ///
+    /// A send which times out may or may not have been delivered: it may have been
+    /// written in full, in part, or not at all. The writer is poisoned so nothing more is
+    /// written to the connection and the client reconnects, while data still queued on
+    /// the send channel is dropped, so only sends which are safe to repeat should be
+    /// retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::ErrorKind::TimedOut`] error if the send does not complete
+    /// within `timeout_ms`, e.g. when the peer stops reading and the send buffer is full,
+    /// an [`std::io::ErrorKind::BrokenPipe`] error if the writer was poisoned by an
+    /// earlier send and the client has not yet reconnected, or any error from the write.
+    pub async fn send_with_timeout(
+        &self,
+        data: &[u8],
+        timeout_ms: Option<u64>,
+    ) -> Result<(), std::io::Error> {
+        let mut frame = data.to_vec();
+        frame.extend(&self.suffix);
+        self.send_frame(frame, timeout_ms).await
+    }
+
+    /// Returns a future sending the `frame` (including the suffix), waiting at most
+    /// `timeout_ms` as for [`Self::send_with_timeout`].
+    ///
+    /// With a send channel the frame is queued before returning, so frames are written
+    /// in the order this is called. While the channel is full the frame is queued once
+    /// capacity is available instead, so frames waiting concurrently may be reordered.
+    pub(crate) fn send_frame(
+        &self,
+        frame: Vec<u8>,
+        timeout_ms: Option<u64>,
+    ) -> impl std::future::Future<Output = Result<(), std::io::Error>> + Send + 'static {
+        let send = match self.send_queue {
+            Some(ref send_queue) => Self::enqueue(send_queue, frame),
+            None => PendingSend::Locked(frame),
+        };
+        let writer = self.writer.clone();
+        let writer_poisoned = self.writer_poisoned.clone();
+        let auto_flush = self.auto_flush;
+
+        async move {
+            let write = async {
+                match send {
+                    PendingSend::Queued(result_rx) => result_rx?
+                        .await
+                        .unwrap_or_else(|_| Err(writer_task_stopped())),
+                    PendingSend::Waiting(send_queue, queued, result_rx) => {
+                        send_queue
+                            .send(queued)
+                            .await
+                            .map_err(|_| writer_task_stopped())?;
+                        result_rx
+                            .await
+                            .unwrap_or_else(|_| Err(writer_task_stopped()))
+                    }
+                    PendingSend::Locked(frame) => {
+                        let mut writer = writer.lock().await;
+                        write_data(&mut writer, &writer_poisoned, &frame, auto_flush).await
+                    }
+                }
+            };
+
+            let result = with_send_timeout(write, timeout_ms).await;
+            if let Err(ref e) = result {
+                if e.kind() == std::io::ErrorKind::TimedOut {
+                    tracing::warn!("{e}, poisoning writer to force a reconnect");
+                    writer_poisoned.store(true, Ordering::SeqCst);
+                }
+            }
+            result
+        }
+    }
+
+    /// Flushes the writer, so any data buffered by the stream (e.g. by TLS) is written
+    /// to the socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer is poisoned by an incomplete send, as for
+    /// [`SocketClient::send_with_timeout`], or any error from the flush.
+    pub async fn flush(&self) -> Result<(), std::io::Error> {
+        flush_data(&mut *self.writer.lock().await, &self.writer_poisoned).await
+    }
+
+    /// Queues the `data` (including the suffix) on the `send_queue` for the writer task,
+    /// returning the pending send with the receiver of the write result.
+    ///
+    /// Data is written in the order it was queued, without the caller locking the writer.
+    /// When the channel is full the data is returned to be queued once capacity is
+    /// available. Dropping the receiver before the data is written cancels the write.
+    fn enqueue(send_queue: &mpsc::Sender<QueuedWrite>, data: Vec<u8>) -> PendingSend {
+        let (result_tx, result_rx) = oneshot::channel();
+        match send_queue.try_send((data, result_tx)) {
+            Ok(()) => PendingSend::Queued(Ok(result_rx)),
+            Err(mpsc::error::TrySendError::Full(queued)) => {
+                PendingSend::Waiting(send_queue.clone(), queued, result_rx)
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                PendingSend::Queued(Err(writer_task_stopped()))
+            }
+        }
+    }
+
+    /// Sends the UTF-8 encoded `text` followed by the configured suffix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not active or the write fails.
+    pub async fn send_text(&self, text: &str) -> Result<(), std::io::Error> {
+        if !self.is_active() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Client is not active",
+            ));
+        }
+        self.send_bytes(text.as_bytes()).await
     }
 
     #[must_use]
@@ -362,61 +1143,401 @@ impl SocketClient {
         self.controller_task.is_finished()
     }
 
+    /// Returns whether the client is active, i.e. it is neither disconnecting
+    /// nor disconnected.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.disconnect_mode.load(Ordering::SeqCst) && !self.is_disconnected()
+    }
+
+    /// Returns the DER encoded client certificate presented on the current connection,
+    /// as reloaded from `certs_dir` on each reconnect.
+    #[must_use]
+    pub fn client_cert(&self) -> Option<Vec<u8>> {
+        self.client_cert.lock().unwrap().clone()
+    }
+
+    /// Returns the total bytes currently held in the client's receive buffer.
+    #[must_use]
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of times the client has successfully reconnected, which is
+    /// reset to zero once the client is closed.
+    #[must_use]
+    pub fn reconnect_count(&self) -> u64 {
+        self.stats.reconnect_count.load(Ordering::SeqCst)
+    }
+
+    /// Returns when the current connection was established, or `None` while the client
+    /// is reconnecting or closed.
+    #[must_use]
+    pub fn connected_since(&self) -> Option<UnixNanos> {
+        match self.stats.connected_since.load(Ordering::SeqCst) {
+            0 => None,
+            ts => Some(UnixNanos::from(ts)),
+        }
+    }
+
+    /// Returns the delay (milliseconds) before the next reconnect attempt, as drawn from
+    /// the reconnect backoff (including any jitter), or `None` while not reconnecting.
+    #[must_use]
+    pub fn current_reconnect_delay_ms(&self) -> Option<u64> {
+        self.stats.reconnect_delay_ms()
+    }
+
+    /// Returns the total bytes read from the socket, across reconnects.
+    #[must_use]
+    pub fn bytes_received(&self) -> u64 {
+        self.receive_stats.bytes_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of messages passed to the handler, across reconnects.
+    #[must_use]
+    pub fn messages_received(&self) -> u64 {
+        self.receive_stats.messages_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns when the last message was passed to the handler, or `None` if no message
+    /// has been received.
+    #[must_use]
+    pub fn last_message_ns(&self) -> Option<UnixNanos> {
+        match self.receive_stats.last_message_ns.load(Ordering::Relaxed) {
+            0 => None,
+            ts => Some(UnixNanos::from(ts)),
+        }
+    }
+
     fn spawn_controller_task(
         mut inner: SocketClientInner,
         disconnect_mode: Arc<AtomicBool>,
-        post_reconnection: Option<PyObject>,
-        post_disconnection: Option<PyObject>,
+        cancellation_token: CancellationToken,
+        stats: Arc<ConnectionStats>,
+        config: ControllerConfig,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
+            let ControllerConfig {
+                post_reconnection,
+                post_disconnection,
+                on_reconnect_attempt,
+                max_reconnect_attempts,
+                reconnect_backoff: mut backoff,
+            } = config;
+            let mut reconnect_attempts: u32 = 0;
+
             loop {
                 sleep(Duration::from_millis(100)).await;
 
                 // Check if client needs to disconnect
                 let disconnected = disconnect_mode.load(Ordering::SeqCst);
-                match (disconnected, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            tracing::debug!("Reconnected successfully");
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => tracing::debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        tracing::error!(
-                                            "Error calling `post_reconnection` handler: {e}"
-                                        );
+                let alive = inner.is_alive();
+                if !alive {
+                    stats.on_disconnected();
+                }
+
+                match (disconnected, alive) {
+                    (false, false)
+                        if max_reconnect_attempts.is_some_and(|max| reconnect_attempts >= max) =>
+                    {
+                        tracing::error!(
+                            "Max reconnect attempts {reconnect_attempts} exceeded, closing connection"
+                        );
+                        stats.on_closed();
+                        if let Err(e) = inner.shutdown().await {
+                            tracing::error!("Error on `shutdown`: {e}");
+                        }
+                        Self::call_post_disconnection(post_disconnection.as_ref());
+                        break;
+                    }
+                    (false, false) => {
+                        Self::call_on_reconnect_attempt(
+                            on_reconnect_attempt.as_ref(),
+                            reconnect_attempts + 1,
+                            stats.reconnect_delay_ms().unwrap_or_default(),
+                        );
+                        match inner.reconnect().await {
+                            Ok(()) => {
+                                tracing::debug!("Reconnected successfully");
+                                reconnect_attempts = 0;
+                                backoff.reset();
+                                stats.on_reconnected();
+
+                                if let Some(ref handler) = post_reconnection {
+                                    Python::with_gil(|py| match handler.call0(py) {
+                                        Ok(_) => {
+                                            tracing::debug!("Called `post_reconnection` handler");
+                                        }
+                                        Err(e) => {
+                                            tracing::error!(
+                                                "Error calling `post_reconnection` handler: {e}"
+                                            );
+                                        }
+                                    });
+                                }
+                            }
+                            Err(e) => {
+                                reconnect_attempts += 1;
+                                match max_reconnect_attempts {
+                                    Some(max) => tracing::warn!(
+                                        "Reconnect failed {e}. Attempt {reconnect_attempts}/{max}"
+                                    ),
+                                    None => tracing::warn!(
+                                        "Reconnect failed {e}. Attempt {reconnect_attempts}"
+                                    ),
+                                }
+                                tokio::select! {
+                                    () = sleep(stats.next_reconnect_delay(&mut backoff)) => {}
+                                    () = cancellation_token.cancelled() => {
+                                        tracing::debug!("Reconnect backoff cancelled");
                                     }
-                                });
+                                }
                             }
                         }
-                        Err(e) => {
-                            tracing::error!("Reconnect failed {e}");
-                            break;
-                        }
-                    },
+                    }
                     (true, true) => {
                         tracing::debug!("Shutting down inner client");
+                        stats.on_closed();
                         match inner.shutdown().await {
                             Ok(()) => tracing::debug!("Closed connection"),
                             Err(e) => tracing::error!("Error on `shutdown`: {e}"),
                         }
 
-                        if let Some(ref handler) = post_disconnection {
-                            Python::with_gil(|py| match handler.call0(py) {
-                                Ok(_) => tracing::debug!("Called `post_disconnection` handler"),
-                                Err(e) => {
-                                    tracing::error!(
-                                        "Error calling `post_disconnection` handler: {e}"
-                                    );
-                                }
-                            });
-                        }
+                        Self::call_post_disconnection(post_disconnection.as_ref());
+                        break;
+                    }
+                    (true, false) => {
+                        stats.on_closed();
                         break;
                     }
-                    (true, false) => break,
                     _ => (),
                 }
             }
         })
     }
+
+    fn call_on_reconnect_attempt(handler: Option<&PyObject>, attempt: u32, delay_ms: u64) {
+        if let Some(handler) = handler {
+            Python::with_gil(|py| match handler.call1(py, (attempt, delay_ms)) {
+                Ok(_) => tracing::debug!("Called `on_reconnect_attempt` handler"),
+                Err(e) => tracing::error!("Error calling `on_reconnect_attempt` handler: {e}"),
+            });
+        }
+    }
+
+    fn call_post_disconnection(handler: Option<&PyObject>) {
+        if let Some(handler) = handler {
+            Python::with_gil(|py| match handler.call0(py) {
+                Ok(_) => tracing::debug!("Called `post_disconnection` handler"),
+                Err(e) => tracing::error!("Error calling `post_disconnection` handler: {e}"),
+            });
+        }
+    }
+}
+
+/// Policy controlling how a [`SocketSupervisor`] restarts closed clients.
+///
+/// A client is restarted at most `max_restarts` times within any rolling
+/// `window`. Once the limit is reached the client is left closed.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    /// The maximum number of restarts allowed within the window.
+    pub max_restarts: u32,
+    /// The rolling window over which restarts are counted.
+    pub window: Duration,
+}
+
+impl RestartPolicy {
+    /// Creates a new [`RestartPolicy`] instance.
+    #[must_use]
+    pub const fn new(max_restarts: u32, window: Duration) -> Self {
+        Self {
+            max_restarts,
+            window,
+        }
+    }
+}
+
+/// The handlers a supervised client is connected with, passed to each restarted client.
+struct SupervisedHandlers {
+    post_connection: Option<PyObject>,
+    post_reconnection: Option<PyObject>,
+    post_disconnection: Option<PyObject>,
+    on_reconnect_attempt: Option<PyObject>,
+}
+
+impl SupervisedHandlers {
+    /// Connects a client from the `config` with a reference to each of the handlers.
+    async fn connect(&self, config: SocketConfig) -> Result<SocketClient, Error> {
+        let (post_connection, post_reconnection, post_disconnection, on_reconnect_attempt) =
+            Python::with_gil(|py| {
+                let clone = |handler: &Option<PyObject>| {
+                    handler.as_ref().map(|handler| handler.clone_ref(py))
+                };
+                (
+                    clone(&self.post_connection),
+                    clone(&self.post_reconnection),
+                    clone(&self.post_disconnection),
+                    clone(&self.on_reconnect_attempt),
+                )
+            });
+        SocketClient::connect(
+            config,
+            post_connection,
+            post_reconnection,
+            post_disconnection,
+            on_reconnect_attempt,
+        )
+        .await
+    }
+}
+
+struct SupervisedClient {
+    config: SocketConfig,
+    handlers: SupervisedHandlers,
+    client: SocketClient,
+    restarts: VecDeque<Instant>,
+}
+
+impl SupervisedClient {
+    /// Returns true if the client closed without a deliberate disconnect.
+    fn is_closed_unexpectedly(&self) -> bool {
+        self.client.is_disconnected() && !self.client.disconnect_mode.load(Ordering::SeqCst)
+    }
+}
+
+/// Supervises a set of [`SocketClient`]s and recreates them when they close.
+///
+/// Each client is recreated from its stored [`SocketConfig`] and handlers when its
+/// controller task has terminated without a deliberate disconnect, i.e. the inner
+/// client could not reconnect within its `max_reconnect_attempts`. Restarts are
+/// bounded by the [`RestartPolicy`].
+pub struct SocketSupervisor {
+    policy: RestartPolicy,
+    clients: Vec<SupervisedClient>,
+}
+
+impl SocketSupervisor {
+    /// Creates a new [`SocketSupervisor`] instance.
+    #[must_use]
+    pub const fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            clients: Vec::new(),
+        }
+    }
+
+    /// Connects a new client from the given `config` and handlers, as for
+    /// [`SocketClient::connect`], and places it under supervision.
+    ///
+    /// Returns the index of the supervised client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` has no `max_reconnect_attempts`, as the client
+    /// then keeps reconnecting and never closes to be restarted, or the connection fails.
+    pub async fn connect(
+        &mut self,
+        config: SocketConfig,
+        post_connection: Option<PyObject>,
+        post_reconnection: Option<PyObject>,
+        post_disconnection: Option<PyObject>,
+        on_reconnect_attempt: Option<PyObject>,
+    ) -> Result<usize, Error> {
+        if config.max_reconnect_attempts.is_none() {
+            return Err(Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "A supervised client requires `max_reconnect_attempts`",
+            )));
+        }
+
+        let handlers = SupervisedHandlers {
+            post_connection,
+            post_reconnection,
+            post_disconnection,
+            on_reconnect_attempt,
+        };
+        let client = handlers.connect(config.clone()).await?;
+        self.clients.push(SupervisedClient {
+            config,
+            handlers,
+            client,
+            restarts: VecDeque::new(),
+        });
+        Ok(self.clients.len() - 1)
+    }
+
+    /// Returns the supervised client at the given `index`.
+    #[must_use]
+    pub fn client(&self, index: usize) -> Option<&SocketClient> {
+        self.clients.get(index).map(|supervised| &supervised.client)
+    }
+
+    /// Returns the number of restarts for the client at `index` within the current window.
+    #[must_use]
+    pub fn restart_count(&self, index: usize) -> usize {
+        self.clients
+            .get(index)
+            .map_or(0, |supervised| supervised.restarts.len())
+    }
+
+    /// Checks every supervised client and restarts those which closed unexpectedly.
+    ///
+    /// Returns the number of clients which were restarted.
+    pub async fn check(&mut self) -> usize {
+        let policy = self.policy;
+        let mut restarted = 0;
+
+        for (index, supervised) in self.clients.iter_mut().enumerate() {
+            if !supervised.is_closed_unexpectedly() {
+                continue;
+            }
+
+            let now = Instant::now();
+            while let Some(ts) = supervised.restarts.front() {
+                if now.duration_since(*ts) > policy.window {
+                    supervised.restarts.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            if supervised.restarts.len() >= policy.max_restarts as usize {
+                tracing::warn!("Client {index} exceeded restart policy, not restarting");
+                continue;
+            }
+
+            tracing::debug!("Restarting client {index}");
+            match supervised.handlers.connect(supervised.config.clone()).await {
+                Ok(client) => {
+                    supervised.client = client;
+                    supervised.restarts.push_back(now);
+                    restarted += 1;
+                    tracing::debug!("Restarted client {index}");
+                }
+                Err(e) => {
+                    // Count failed attempts against the policy to avoid a restart storm
+                    supervised.restarts.push_back(now);
+                    tracing::error!("Failed to restart client {index}: {e}");
+                }
+            }
+        }
+
+        restarted
+    }
+
+    /// Continuously checks the supervised clients at the given `interval`.
+    pub async fn supervise(&mut self, interval: Duration) {
+        loop {
+            sleep(interval).await;
+            self.check().await;
+        }
+    }
+
+    /// Deliberately disconnects all supervised clients.
+    pub async fn disconnect_all(&self) {
+        for supervised in &self.clients {
+            supervised.client.disconnect().await;
+        }
+    }
 }
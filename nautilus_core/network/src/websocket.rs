@@ -15,6 +15,7 @@
 
 //! A high-performance WebSocket client implementation.
 use std::{
+    collections::{BTreeMap, HashMap},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -26,22 +27,46 @@ use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
 };
+use nautilus_core::time::get_atomic_clock_realtime;
 use nautilus_cryptography::providers::install_cryptographic_provider;
 use pyo3::{prelude::*, types::PyBytes};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
 use tokio_tungstenite::{
-    connect_async,
-    tungstenite::{client::IntoClientRequest, http::HeaderValue, Error, Message},
+    connect_async, connect_async_with_config,
+    tungstenite::{
+        client::IntoClientRequest,
+        error::ProtocolError,
+        http::HeaderValue,
+        protocol::{frame::coding::CloseCode, CloseFrame, WebSocketConfig as ProtocolConfig},
+        Error, Message,
+    },
     MaybeTlsStream, WebSocketStream,
 };
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    backoff::{Backoff, BackoffStrategy},
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+    reconnect::{acquire_reconnect_permit, reconnect_backoff},
+    sequence::{SequenceExtractor, SequenceTracker},
+};
+/// The maximum duration of the `auth_flow` challenge-response after connecting.
+const AUTH_FLOW_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The maximum duration to wait for the server's `Close` frame after sending one.
+const CLOSE_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The maximum duration to wait for the controller task to finish on disconnect.
+pub(crate) const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
 pub type MessageReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+type SharedCloseFrame = Arc<std::sync::Mutex<Option<CloseFrame<'static>>>>;
+type SharedResubscribeMessages = Arc<std::sync::Mutex<Vec<Vec<u8>>>>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
@@ -54,6 +79,456 @@ pub struct WebSocketConfig {
     pub heartbeat_msg: Option<String>,
     pub ping_handler: Option<Arc<PyObject>>,
     pub max_reconnection_tries: Option<u64>,
+    pub sequence_extractor: Option<SequenceExtractor>,
+    pub sequence_gap_handler: Option<Arc<PyObject>>,
+    pub reconnect_on_sequence_gap: bool,
+    pub snapshot_predicate: Option<Arc<PyObject>>,
+    /// The scheduled `(start, end)` UNIX nanosecond windows (e.g. venue maintenance)
+    /// during which heartbeats are not sent.
+    pub heartbeat_pause_windows: Vec<(u64, u64)>,
+    /// The optional challenge-response handler called with each message received after
+    /// connecting, returning the bytes to respond with, or `True`/`False` once the
+    /// connection is authenticated/rejected. The client only becomes active on success.
+    pub auth_flow: Option<Arc<PyObject>>,
+    /// The window (milliseconds) over which subscribe and unsubscribe requests for the same
+    /// topic are coalesced, so only the net change is sent.
+    pub subscription_debounce_ms: Option<u64>,
+    /// The optional handler called with `(topic, error)` when a debounced subscription
+    /// change fails to send, in which case the subscription state is left unchanged.
+    pub subscription_error_handler: Option<Arc<PyObject>>,
+    /// The optional interval (milliseconds) at which protocol-level Ping frames are sent.
+    /// If the Pong for a Ping is not received before the next Ping is due, the connection
+    /// is considered dead and the client reconnects.
+    pub ping_interval_ms: Option<u64>,
+    /// The maximum size (bytes) of a message reassembled from continuation frames, or
+    /// `None` for the tungstenite default of 64 MiB. A larger message terminates the
+    /// connection, so the client reconnects.
+    pub max_message_size: Option<usize>,
+    /// If the `handler` is called with `(data, WsMessageType)`, so protocols multiplexing
+    /// text and binary frames can branch on the frame type, rather than with only the data.
+    pub include_message_type: bool,
+    /// The strategy for the delays between reconnect attempts (default `FIXED`), starting
+    /// from `reconnect_delay_initial_ms` up to `reconnect_delay_max_ms`.
+    pub reconnect_backoff: Option<BackoffStrategy>,
+    /// The initial delay (milliseconds) between reconnect attempts (default 1000).
+    pub reconnect_delay_initial_ms: Option<u64>,
+    /// The maximum delay (milliseconds) between reconnect attempts (default 30000).
+    pub reconnect_delay_max_ms: Option<u64>,
+    /// The messages (e.g. subscription requests) resent after each reconnect, once the
+    /// `post_reconnection` handler has been called. Messages which are valid UTF-8 are
+    /// sent as text frames, otherwise as binary frames.
+    pub resubscribe_messages: Vec<Vec<u8>>,
+}
+
+/// Represents the frame type of a message received by a `WebSocketClient`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum WsMessageType {
+    /// The message was received as a text frame (valid UTF-8).
+    Text,
+    /// The message was received as a binary frame.
+    Binary,
+}
+
+/// Represents the connection state of a `WebSocketClient`.
+///
+/// Every transition is passed to the `on_state_change` handler as `(old, new, ts)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum ConnectionState {
+    /// The client is connected.
+    ACTIVE,
+    /// The connection was lost and the client is reconnecting.
+    RECONNECT,
+    /// The client is disconnecting.
+    DISCONNECT,
+    /// The client is closed and no longer reconnecting.
+    CLOSED,
+}
+
+/// Creates a `Close` frame with the close `code` and optional `reason`.
+///
+/// # Errors
+///
+/// Returns an error if the `code` is outside 1000-4999, or is one of the codes 1005,
+/// 1006 and 1015 reserved for reporting a missing status, an abnormal closure and a
+/// TLS handshake failure, which must not be sent in a `Close` frame.
+pub(crate) fn close_frame(
+    code: u16,
+    reason: Option<String>,
+) -> anyhow::Result<CloseFrame<'static>> {
+    match code {
+        1005 | 1006 | 1015 => anyhow::bail!("Close code {code} is reserved and cannot be sent"),
+        1000..=4999 => Ok(CloseFrame {
+            code: CloseCode::from(code),
+            reason: reason.unwrap_or_default().into(),
+        }),
+        _ => anyhow::bail!("Close code {code} is outside the valid range 1000-4999"),
+    }
+}
+
+/// Creates a text message if the `data` is valid UTF-8, otherwise a binary message.
+fn data_message(data: Vec<u8>) -> Message {
+    match String::from_utf8(data) {
+        Ok(text) => Message::Text(text),
+        Err(e) => Message::Binary(e.into_bytes()),
+    }
+}
+
+/// Calls the `handler` with the message `data`, along with its `message_type` if
+/// `include_message_type` is set.
+fn call_handler(
+    py: Python<'_>,
+    handler: &PyObject,
+    data: &[u8],
+    message_type: WsMessageType,
+    include_message_type: bool,
+) -> PyResult<PyObject> {
+    let data = PyBytes::new_bound(py, data);
+    if include_message_type {
+        handler.call1(py, (data, message_type))
+    } else {
+        handler.call1(py, (data,))
+    }
+}
+
+/// Tracks the `ConnectionState` of a client and calls the `on_state_change` handler
+/// on every transition.
+struct ConnectionStateNotifier {
+    state: ConnectionState,
+    handler: Option<PyObject>,
+}
+
+impl ConnectionStateNotifier {
+    const fn new(handler: Option<PyObject>) -> Self {
+        Self {
+            state: ConnectionState::CLOSED,
+            handler,
+        }
+    }
+
+    fn transition(&mut self, new: ConnectionState) {
+        let old = self.state;
+        if old == new {
+            return;
+        }
+        self.state = new;
+        tracing::debug!("Connection state {old:?} -> {new:?}");
+
+        if let Some(ref handler) = self.handler {
+            let ts = get_atomic_clock_realtime().get_time_ns().as_u64();
+            Python::with_gil(|py| match handler.call1(py, (old, new, ts)) {
+                Ok(_) => tracing::debug!("Called `on_state_change` handler"),
+                Err(e) => tracing::error!("Error calling `on_state_change` handler: {e}"),
+            });
+        }
+    }
+}
+
+/// Represents errors that can occur when sending messages with the `WebSocketClient`.
+///
+/// Distinguishes errors where the send may succeed if retried (e.g. the connection is
+/// reconnecting or the write buffer is full) from those where it never will (e.g. the
+/// client is closed or the message is too large).
+#[derive(thiserror::Error, Debug)]
+pub enum SendError {
+    #[error("Retryable send error: {0}")]
+    Retryable(String),
+
+    #[error("Fatal send error: {0}")]
+    Fatal(String),
+}
+
+impl SendError {
+    /// Classifies the send `error`, which is always fatal once the client is closed.
+    #[must_use]
+    pub fn classify(error: Error, client_closed: bool) -> Self {
+        if client_closed {
+            Self::Fatal(format!("Client is closed: {error}"))
+        } else {
+            Self::from(error)
+        }
+    }
+
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::Retryable(_))
+    }
+}
+
+impl From<Error> for SendError {
+    fn from(source: Error) -> Self {
+        match source {
+            Error::ConnectionClosed
+            | Error::AlreadyClosed
+            | Error::Io(_)
+            | Error::Tls(_)
+            | Error::WriteBufferFull(_)
+            | Error::Protocol(ProtocolError::SendAfterClosing) => {
+                Self::Retryable(source.to_string())
+            }
+            _ => Self::Fatal(source.to_string()),
+        }
+    }
+}
+
+/// Handlers attached to a connected client in addition to the primary `handler`.
+///
+/// The last message identified as a snapshot by the `snapshot_predicate` is retained
+/// and replayed to each newly attached handler, so it starts from a consistent state.
+#[derive(Default)]
+pub(crate) struct AttachedHandlers {
+    handlers: Vec<Arc<PyObject>>,
+    last_snapshot: Option<Vec<u8>>,
+}
+
+type SharedAttachedHandlers = Arc<std::sync::Mutex<AttachedHandlers>>;
+
+impl AttachedHandlers {
+    /// Retains the message if it's a snapshot and passes it to the attached handlers.
+    fn on_message(&mut self, py: Python<'_>, data: &[u8], snapshot_predicate: Option<&PyObject>) {
+        if let Some(predicate) = snapshot_predicate {
+            match predicate
+                .call1(py, (PyBytes::new_bound(py, data),))
+                .and_then(|is_snapshot| is_snapshot.extract::<bool>(py))
+            {
+                Ok(true) => self.last_snapshot = Some(data.to_vec()),
+                Ok(false) => (),
+                Err(e) => tracing::error!("Error calling `snapshot_predicate`: {e}"),
+            }
+        }
+
+        for handler in &self.handlers {
+            if let Err(e) = handler.call1(py, (PyBytes::new_bound(py, data),)) {
+                tracing::error!("Error calling attached handler: {e}");
+            }
+        }
+    }
+
+    /// Replays the last snapshot (if any) to the handler and attaches it.
+    fn attach(&mut self, py: Python<'_>, handler: PyObject) {
+        if let Some(ref snapshot) = self.last_snapshot {
+            tracing::debug!("Replaying last snapshot to attached handler");
+            if let Err(e) = handler.call1(py, (PyBytes::new_bound(py, snapshot),)) {
+                tracing::error!("Error calling attached handler: {e}");
+            }
+        }
+        self.handlers.push(Arc::new(handler));
+    }
+}
+
+/// Coalesces subscribe and unsubscribe requests for each topic, so only the net change
+/// to the subscription state is sent to the server.
+///
+/// With a debounce window the first request for a topic opens the window, and the last
+/// request made before it elapses is sent if it changes the subscription state. Without
+/// a window each request is resolved immediately, so redundant requests are still dropped.
+///
+/// The subscription state is only updated once a change has been sent successfully, and
+/// the subscribe message of each subscribed topic is resent after a reconnect.
+#[derive(Default)]
+pub(crate) struct SubscriptionDebouncer {
+    window: Option<Duration>,
+    subscribed: BTreeMap<String, String>,
+    pending: HashMap<String, (bool, String)>,
+    flush_tasks: HashMap<String, task::AbortHandle>,
+    error_handler: Option<Arc<PyObject>>,
+}
+
+pub(crate) type SharedSubscriptionDebouncer = Arc<std::sync::Mutex<SubscriptionDebouncer>>;
+
+impl SubscriptionDebouncer {
+    pub(crate) fn new(window_ms: Option<u64>, error_handler: Option<Arc<PyObject>>) -> Self {
+        Self {
+            window: window_ms.map(Duration::from_millis),
+            error_handler,
+            ..Default::default()
+        }
+    }
+
+    /// Records the latest request for the topic, returning whether it opened a debounce
+    /// window, after which the topic must be flushed.
+    fn request(&mut self, topic: &str, subscribe: bool, message: String) -> bool {
+        self.pending
+            .insert(topic.to_string(), (subscribe, message))
+            .is_none()
+    }
+
+    /// Takes the pending request for the topic, returning it if it changes the
+    /// subscription state, which must then be committed once sent.
+    fn take(&mut self, topic: &str) -> Option<(bool, String)> {
+        self.flush_tasks.remove(topic);
+        let (subscribe, message) = self.pending.remove(topic)?;
+        (subscribe != self.is_subscribed(topic)).then_some((subscribe, message))
+    }
+
+    /// Commits a subscription change for the topic whose `message` was sent successfully.
+    fn commit(&mut self, topic: &str, subscribe: bool, message: String) {
+        if subscribe {
+            self.subscribed.insert(topic.to_string(), message);
+        } else {
+            self.subscribed.remove(topic);
+        }
+    }
+
+    /// Aborts the tasks flushing debounced requests, dropping the pending requests.
+    pub(crate) fn cancel_pending(&mut self) {
+        for (_, handle) in self.flush_tasks.drain() {
+            handle.abort();
+        }
+        self.pending.clear();
+    }
+
+    pub(crate) fn is_subscribed(&self, topic: &str) -> bool {
+        self.subscribed.contains_key(topic)
+    }
+
+    /// Returns the subscribe messages of the subscribed topics, in topic order.
+    fn subscribe_messages(&self) -> Vec<String> {
+        self.subscribed.values().cloned().collect()
+    }
+}
+
+/// Requests a subscription change for the topic, sending the `message` now or once the
+/// debounce window elapses if it changes the subscription state.
+///
+/// # Errors
+///
+/// Returns an error if a change sent immediately (without a debounce window) fails. A
+/// debounced change is sent after this returns, so its failure is instead reported to
+/// the debouncer's error handler.
+pub(crate) async fn request_subscription(
+    subscriptions: SharedSubscriptionDebouncer,
+    writer: SharedMessageWriter,
+    topic: String,
+    subscribe: bool,
+    message: String,
+) -> Result<(), Error> {
+    let (window, opened) = {
+        let mut guard = subscriptions.lock().unwrap();
+        let opened = guard.request(&topic, subscribe, message);
+        (guard.window, opened)
+    };
+
+    let Some(window) = window else {
+        let request = subscriptions.lock().unwrap().take(&topic);
+        if let Some((subscribe, message)) = request {
+            tracing::trace!("Sending subscription change for {topic}: {message}");
+            writer
+                .lock()
+                .await
+                .send(Message::Text(message.clone()))
+                .await?;
+            subscriptions
+                .lock()
+                .unwrap()
+                .commit(&topic, subscribe, message);
+        }
+        return Ok(());
+    };
+
+    if opened {
+        let handle = task::spawn(flush_subscription(
+            subscriptions.clone(),
+            writer,
+            topic.clone(),
+            window,
+        ));
+        subscriptions
+            .lock()
+            .unwrap()
+            .flush_tasks
+            .insert(topic, handle.abort_handle());
+    }
+    Ok(())
+}
+
+/// Sends the net subscription change for the topic once the debounce `window` elapses,
+/// calling the error handler if the send fails.
+async fn flush_subscription(
+    subscriptions: SharedSubscriptionDebouncer,
+    writer: SharedMessageWriter,
+    topic: String,
+    window: Duration,
+) {
+    sleep(window).await;
+    let request = subscriptions.lock().unwrap().take(&topic);
+    let Some((subscribe, message)) = request else {
+        tracing::debug!("Subscription changes for {topic} cancelled out");
+        return;
+    };
+
+    tracing::trace!("Sending net subscription change for {topic}: {message}");
+    let result = writer
+        .lock()
+        .await
+        .send(Message::Text(message.clone()))
+        .await;
+    let error_handler = {
+        let mut guard = subscriptions.lock().unwrap();
+        if result.is_ok() {
+            guard.commit(&topic, subscribe, message);
+        }
+        guard.error_handler.clone()
+    };
+
+    if let Err(e) = result {
+        tracing::error!("Error sending subscription change for {topic}: {e}");
+        if let Some(handler) = error_handler {
+            Python::with_gil(|py| {
+                if let Err(e) = handler.call1(py, (topic.as_str(), e.to_string())) {
+                    tracing::error!("Error calling `subscription_error_handler`: {e}");
+                }
+            });
+        }
+    }
+}
+
+/// Detects gaps in the sequence numbers of messages received by the read task.
+///
+/// On a gap the handler is called with the `(expected, got)` sequence numbers, and if
+/// configured the read task terminates so the controller reconnects, allowing the
+/// `post_reconnection` handler to resubscribe.
+struct SequenceGapMonitor {
+    tracker: SequenceTracker,
+    handler: Option<Arc<PyObject>>,
+    reconnect_on_gap: bool,
+}
+
+impl SequenceGapMonitor {
+    fn from_config(config: &WebSocketConfig) -> Option<Self> {
+        config.sequence_extractor.as_ref().map(|extractor| Self {
+            tracker: SequenceTracker::new(extractor.clone()),
+            handler: config.sequence_gap_handler.clone(),
+            reconnect_on_gap: config.reconnect_on_sequence_gap,
+        })
+    }
+
+    /// Checks the message for a sequence gap, returning whether the read task should
+    /// terminate.
+    fn on_message(&mut self, data: &[u8]) -> bool {
+        let Some((expected, got)) = self.tracker.check(data) else {
+            return false;
+        };
+
+        tracing::warn!("Sequence gap detected: expected {expected}, got {got}");
+        if let Some(ref handler) = self.handler {
+            if let Err(e) = Python::with_gil(|py| handler.call1(py, (expected, got))) {
+                tracing::error!("Error calling `on_sequence_gap` handler: {e}");
+            }
+        }
+
+        if self.reconnect_on_gap {
+            tracing::warn!("Terminating read task to resubscribe after sequence gap");
+        }
+        self.reconnect_on_gap
+    }
 }
 
 /// `WebSocketClient` connects to a websocket server to read and send messages.
@@ -70,12 +545,22 @@ pub struct WebSocketConfig {
 ///
 /// The client also maintains a heartbeat if given a duration in seconds.
 /// It's preferable to set the duration slightly lower - heartbeat more
-/// frequently - than the required amount.
+/// frequently - than the required amount. Heartbeats are skipped while
+/// suspended or during a scheduled pause window, without affecting the
+/// connection.
 struct WebSocketClientInner {
     config: WebSocketConfig,
     read_task: Option<task::JoinHandle<()>>,
     heartbeat_task: Option<task::JoinHandle<()>>,
+    ping_task: Option<task::JoinHandle<()>>,
     writer: SharedMessageWriter,
+    attached: SharedAttachedHandlers,
+    heartbeat_suspended: Arc<AtomicBool>,
+    awaiting_pong: Arc<AtomicBool>,
+    last_close_frame: SharedCloseFrame,
+    pending_close_frame: SharedCloseFrame,
+    subscriptions: SharedSubscriptionDebouncer,
+    resubscribe_messages: SharedResubscribeMessages,
 }
 
 impl WebSocketClientInner {
@@ -92,31 +577,92 @@ impl WebSocketClientInner {
             heartbeat_msg,
             ping_handler,
             max_reconnection_tries,
+            sequence_extractor,
+            sequence_gap_handler,
+            reconnect_on_sequence_gap,
+            snapshot_predicate,
+            heartbeat_pause_windows,
+            auth_flow,
+            subscription_debounce_ms,
+            subscription_error_handler,
+            ping_interval_ms,
+            max_message_size,
+            include_message_type,
+            reconnect_backoff: _,
+            reconnect_delay_initial_ms: _,
+            reconnect_delay_max_ms: _,
+            resubscribe_messages,
         } = &config;
-        let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
+        let (mut writer, mut reader) =
+            Self::connect_with_server(url, headers.clone(), *max_message_size).await?;
+        if let Some(auth_flow) = auth_flow {
+            Self::authenticate(&mut writer, &mut reader, auth_flow).await?;
+        }
         let writer = Arc::new(Mutex::new(writer));
+        let attached = SharedAttachedHandlers::default();
+        let heartbeat_suspended = Arc::new(AtomicBool::new(false));
+        let awaiting_pong = Arc::new(AtomicBool::new(false));
+        let last_close_frame = SharedCloseFrame::default();
+        let pending_close_frame = SharedCloseFrame::default();
+        let subscriptions = Arc::new(std::sync::Mutex::new(SubscriptionDebouncer::new(
+            *subscription_debounce_ms,
+            subscription_error_handler.clone(),
+        )));
+        let resubscribe_messages = Arc::new(std::sync::Mutex::new(resubscribe_messages.clone()));
 
         // Only spawn read task if handler is provided
-        let read_task = handler
-            .as_ref()
-            .map(|handler| Self::spawn_read_task(reader, handler.clone(), ping_handler.clone()));
+        let read_task = handler.as_ref().map(|handler| {
+            Self::spawn_read_task(
+                reader,
+                handler.clone(),
+                ping_handler.clone(),
+                SequenceGapMonitor::from_config(&config),
+                attached.clone(),
+                snapshot_predicate.clone(),
+                awaiting_pong.clone(),
+                last_close_frame.clone(),
+                *include_message_type,
+            )
+        });
+
+        // Pongs are only observed by the read task
+        let ping_task = read_task.as_ref().and_then(|_| {
+            Self::spawn_ping_task(*ping_interval_ms, writer.clone(), awaiting_pong.clone())
+        });
 
-        let heartbeat_task =
-            Self::spawn_heartbeat_task(*heartbeat, heartbeat_msg.clone(), writer.clone());
+        let heartbeat_task = Self::spawn_heartbeat_task(
+            *heartbeat,
+            heartbeat_msg.clone(),
+            writer.clone(),
+            heartbeat_suspended.clone(),
+            heartbeat_pause_windows.clone(),
+        );
 
         Ok(Self {
             config,
             read_task,
             heartbeat_task,
+            ping_task,
             writer,
+            attached,
+            heartbeat_suspended,
+            awaiting_pong,
+            last_close_frame,
+            pending_close_frame,
+            subscriptions,
+            resubscribe_messages,
         })
     }
 
     /// Connects with the server creating a tokio-tungstenite websocket stream.
+    ///
+    /// Fragmented messages are reassembled by the stream, up to `max_message_size`, so
+    /// the reader only yields complete messages.
     #[inline]
     pub async fn connect_with_server(
         url: &str,
         headers: Vec<(String, String)>,
+        max_message_size: Option<usize>,
     ) -> Result<(MessageWriter, MessageReader), Error> {
         let mut request = url.into_client_request()?;
         let req_headers = request.headers_mut();
@@ -131,14 +677,95 @@ impl WebSocketClientInner {
             req_headers.insert(header_name_str, header_value);
         }
 
-        connect_async(request).await.map(|resp| resp.0.split())
+        let mut config = ProtocolConfig::default();
+        if let Some(max_message_size) = max_message_size {
+            config.max_message_size = Some(max_message_size);
+            config.max_frame_size = config
+                .max_frame_size
+                .map(|max_frame_size| max_frame_size.min(max_message_size));
+        }
+
+        connect_async_with_config(request, Some(config), false)
+            .await
+            .map(|resp| resp.0.split())
+    }
+
+    /// Performs the `auth_flow` challenge-response on a new connection.
+    ///
+    /// Each message received is passed to the `auth_flow` until it returns `True`,
+    /// and any bytes returned are sent as the response. Fails if the `auth_flow` returns
+    /// `False` or raises, or the flow doesn't complete within [`AUTH_FLOW_TIMEOUT`].
+    async fn authenticate(
+        writer: &mut MessageWriter,
+        reader: &mut MessageReader,
+        auth_flow: &PyObject,
+    ) -> Result<(), Error> {
+        enum AuthStep {
+            Respond(Vec<u8>),
+            Authenticated,
+            Rejected,
+        }
+
+        let auth_error = |msg: String| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                msg,
+            ))
+        };
+
+        tracing::debug!("Starting auth flow");
+        let flow = async {
+            loop {
+                let data = match reader.next().await {
+                    Some(Ok(Message::Text(data))) => data.into_bytes(),
+                    Some(Ok(Message::Binary(data))) => data,
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e),
+                    None => return Err(auth_error("Connection closed during auth flow".into())),
+                };
+
+                let step = Python::with_gil(|py| -> PyResult<AuthStep> {
+                    let result = auth_flow.call1(py, (PyBytes::new_bound(py, &data),))?;
+                    match result.extract::<bool>(py) {
+                        Ok(true) => Ok(AuthStep::Authenticated),
+                        Ok(false) => Ok(AuthStep::Rejected),
+                        Err(_) => result.extract::<Vec<u8>>(py).map(AuthStep::Respond),
+                    }
+                })
+                .map_err(|e| auth_error(format!("Error calling `auth_flow`: {e}")))?;
+
+                match step {
+                    AuthStep::Respond(response) => writer.send(data_message(response)).await?,
+                    AuthStep::Authenticated => return Ok(()),
+                    AuthStep::Rejected => return Err(auth_error("Auth flow rejected".into())),
+                }
+            }
+        };
+
+        match tokio::time::timeout(AUTH_FLOW_TIMEOUT, flow).await {
+            Ok(Ok(())) => {
+                tracing::debug!("Auth flow completed");
+                Ok(())
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Auth flow failed: {e}");
+                Err(e)
+            }
+            Err(_) => Err(auth_error("Timeout waiting for auth flow".into())),
+        }
     }
 
     /// Optionally spawn a hearbeat task to periodically ping the server.
+    ///
+    /// Heartbeats are skipped while `suspended` is set or the current time falls
+    /// within one of the `pause_windows`. The task finishes if a heartbeat cannot be
+    /// sent, so the controller reconnects.
     pub fn spawn_heartbeat_task(
         heartbeat: Option<u64>,
         message: Option<String>,
         writer: SharedMessageWriter,
+        suspended: Arc<AtomicBool>,
+        pause_windows: Vec<(u64, u64)>,
     ) -> Option<task::JoinHandle<()>> {
         tracing::debug!("Started task 'heartbeat'");
         heartbeat.map(|duration| {
@@ -146,6 +773,18 @@ impl WebSocketClientInner {
                 let duration = Duration::from_secs(duration);
                 loop {
                     sleep(duration).await;
+                    if suspended.load(Ordering::SeqCst) {
+                        tracing::trace!("Heartbeat suspended");
+                        continue;
+                    }
+                    let now = get_atomic_clock_realtime().get_time_ns().as_u64();
+                    if pause_windows
+                        .iter()
+                        .any(|(start, end)| (*start..*end).contains(&now))
+                    {
+                        tracing::trace!("Heartbeat paused for scheduled window");
+                        continue;
+                    }
                     let mut guard = writer.lock().await;
                     let guard_send_response = match message.clone() {
                         Some(msg) => guard.send(Message::Text(msg)).await,
@@ -153,7 +792,45 @@ impl WebSocketClientInner {
                     };
                     match guard_send_response {
                         Ok(()) => tracing::trace!("Sent ping"),
-                        Err(e) => tracing::error!("Error sending ping: {e}"),
+                        Err(e) => {
+                            tracing::error!("Heartbeat send failed, triggering reconnect: {e}");
+                            break;
+                        }
+                    }
+                }
+            })
+        })
+    }
+
+    /// Optionally spawn a task to send Ping frames every `interval_ms`.
+    ///
+    /// The task finishes if the Pong for the previous Ping, cleared from `awaiting_pong`
+    /// by the read task, has not been received when the next Ping is due, or if a Ping
+    /// cannot be sent.
+    pub fn spawn_ping_task(
+        interval_ms: Option<u64>,
+        writer: SharedMessageWriter,
+        awaiting_pong: Arc<AtomicBool>,
+    ) -> Option<task::JoinHandle<()>> {
+        interval_ms.map(|interval_ms| {
+            tracing::debug!("Started task 'ping'");
+            task::spawn(async move {
+                let interval = Duration::from_millis(interval_ms);
+                loop {
+                    sleep(interval).await;
+                    if awaiting_pong.swap(true, Ordering::SeqCst) {
+                        tracing::error!(
+                            "Pong not received within {interval_ms}ms - triggering reconnect"
+                        );
+                        break;
+                    }
+                    let mut guard = writer.lock().await;
+                    match guard.send(Message::Ping(vec![])).await {
+                        Ok(()) => tracing::trace!("Sent ping"),
+                        Err(e) => {
+                            tracing::error!("Ping send failed, triggering reconnect: {e}");
+                            break;
+                        }
                     }
                 }
             })
@@ -161,10 +838,19 @@ impl WebSocketClientInner {
     }
 
     /// Keep receiving messages from socket and pass them as arguments to handler.
+    ///
+    /// A `Close` frame received from the server is stored in `last_close_frame`.
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_read_task(
         mut reader: MessageReader,
         handler: Arc<PyObject>,
         ping_handler: Option<Arc<PyObject>>,
+        mut sequence_monitor: Option<SequenceGapMonitor>,
+        attached: SharedAttachedHandlers,
+        snapshot_predicate: Option<Arc<PyObject>>,
+        awaiting_pong: Arc<AtomicBool>,
+        last_close_frame: SharedCloseFrame,
+        include_message_type: bool,
     ) -> task::JoinHandle<()> {
         tracing::debug!("Started task 'read'");
         task::spawn(async move {
@@ -173,21 +859,55 @@ impl WebSocketClientInner {
                     Some(Ok(Message::Binary(data))) => {
                         tracing::trace!("Received message <binary> {} bytes", data.len());
                         if let Err(e) = Python::with_gil(|py| {
-                            handler.call1(py, (PyBytes::new_bound(py, &data),))
+                            call_handler(
+                                py,
+                                &handler,
+                                &data,
+                                WsMessageType::Binary,
+                                include_message_type,
+                            )?;
+                            attached.lock().unwrap().on_message(
+                                py,
+                                &data,
+                                snapshot_predicate.as_deref(),
+                            );
+                            Ok::<(), PyErr>(())
                         }) {
                             tracing::error!("Error calling handler: {e}");
                             break;
                         }
+                        if let Some(ref mut monitor) = sequence_monitor {
+                            if monitor.on_message(&data) {
+                                break;
+                            }
+                        }
                         continue;
                     }
                     Some(Ok(Message::Text(data))) => {
                         tracing::trace!("Received message: {data}");
                         if let Err(e) = Python::with_gil(|py| {
-                            handler.call1(py, (PyBytes::new_bound(py, data.as_bytes()),))
+                            call_handler(
+                                py,
+                                &handler,
+                                data.as_bytes(),
+                                WsMessageType::Text,
+                                include_message_type,
+                            )?;
+                            attached.lock().unwrap().on_message(
+                                py,
+                                data.as_bytes(),
+                                snapshot_predicate.as_deref(),
+                            );
+                            Ok::<(), PyErr>(())
                         }) {
                             tracing::error!("Error calling handler: {e}");
                             break;
                         }
+                        if let Some(ref mut monitor) = sequence_monitor {
+                            if monitor.on_message(data.as_bytes()) {
+                                break;
+                            }
+                        }
                         continue;
                     }
                     Some(Ok(Message::Ping(ping))) => {
@@ -205,9 +925,20 @@ impl WebSocketClientInner {
                     }
                     Some(Ok(Message::Pong(_))) => {
                         tracing::trace!("Received pong");
+                        awaiting_pong.store(false, Ordering::SeqCst);
                     }
-                    Some(Ok(Message::Close(_))) => {
-                        tracing::error!("Received close message - terminating");
+                    Some(Ok(Message::Close(frame))) => {
+                        match frame {
+                            Some(frame) => {
+                                tracing::error!(
+                                    "Received close message with code {} ({}) - terminating",
+                                    u16::from(frame.code),
+                                    frame.reason
+                                );
+                                *last_close_frame.lock().unwrap() = Some(frame);
+                            }
+                            None => tracing::error!("Received close message - terminating"),
+                        }
                         break;
                     }
                     Some(Ok(_)) => (),
@@ -235,6 +966,11 @@ impl WebSocketClientInner {
     pub async fn shutdown(&mut self) {
         tracing::debug!("Closing connection");
 
+        let pending_close_frame = self.pending_close_frame.lock().unwrap().take();
+        if let Some(frame) = pending_close_frame {
+            self.close_handshake(frame).await;
+        }
+
         if let Some(ref read_task) = self.read_task.take() {
             if !read_task.is_finished() {
                 read_task.abort();
@@ -250,6 +986,14 @@ impl WebSocketClientInner {
             }
         }
 
+        // Cancel ping task
+        if let Some(ref handle) = self.ping_task.take() {
+            if !handle.is_finished() {
+                handle.abort();
+                tracing::debug!("Aborted ping task");
+            }
+        }
+
         tracing::debug!("Closing writer");
         let mut write_half = self.writer.lock().await;
         if let Err(e) = write_half.close().await {
@@ -259,50 +1003,143 @@ impl WebSocketClientInner {
         }
     }
 
+    /// Sends the `Close` `frame` and waits for the read task to receive the server's
+    /// `Close` frame in reply, up to the close handshake timeout.
+    async fn close_handshake(&mut self, frame: CloseFrame<'static>) {
+        let code = u16::from(frame.code);
+        if let Err(e) = self
+            .writer
+            .lock()
+            .await
+            .send(Message::Close(Some(frame)))
+            .await
+        {
+            tracing::error!("Error sending close message with code {code}: {e}");
+            return;
+        }
+        tracing::debug!("Sent close message with code {code}");
+
+        let Some(read_task) = self.read_task.as_mut() else {
+            return;
+        };
+        match tokio::time::timeout(CLOSE_HANDSHAKE_TIMEOUT, read_task).await {
+            Ok(_) => tracing::debug!("Close handshake completed"),
+            Err(_) => tracing::warn!(
+                "Close message not received within {CLOSE_HANDSHAKE_TIMEOUT:?} - closing"
+            ),
+        }
+    }
+
     /// Reconnect with server.
     ///
     /// Make a new connection with server. Use the new read and write halves
     /// to update self writer and read and heartbeat tasks.
     pub async fn reconnect(&mut self) -> Result<(), Error> {
+        let _permit = acquire_reconnect_permit().await;
         self.shutdown().await;
 
-        let (new_writer, reader) =
-            Self::connect_with_server(&self.config.url, self.config.headers.clone()).await?;
+        let (mut new_writer, mut reader) = Self::connect_with_server(
+            &self.config.url,
+            self.config.headers.clone(),
+            self.config.max_message_size,
+        )
+        .await?;
+        if let Some(ref auth_flow) = self.config.auth_flow {
+            Self::authenticate(&mut new_writer, &mut reader, auth_flow).await?;
+        }
         let mut guard = self.writer.lock().await;
         *guard = new_writer;
         drop(guard);
 
+        // The server sends a fresh snapshot on the new connection
+        self.attached.lock().unwrap().last_snapshot = None;
+        self.awaiting_pong.store(false, Ordering::SeqCst);
+
         if let Some(ref handler) = self.config.handler {
             self.read_task = Some(Self::spawn_read_task(
                 reader,
                 handler.clone(),
                 self.config.ping_handler.clone(),
+                SequenceGapMonitor::from_config(&self.config),
+                self.attached.clone(),
+                self.config.snapshot_predicate.clone(),
+                self.awaiting_pong.clone(),
+                self.last_close_frame.clone(),
+                self.config.include_message_type,
             ));
+            self.ping_task = Self::spawn_ping_task(
+                self.config.ping_interval_ms,
+                self.writer.clone(),
+                self.awaiting_pong.clone(),
+            );
         }
 
         self.heartbeat_task = Self::spawn_heartbeat_task(
             self.config.heartbeat,
             self.config.heartbeat_msg.clone(),
             self.writer.clone(),
+            self.heartbeat_suspended.clone(),
+            self.config.heartbeat_pause_windows.clone(),
         );
 
         Ok(())
     }
 
+    /// Resends the configured resubscribe messages, then the subscribe message of each
+    /// subscribed topic, to restore the subscriptions after a reconnect.
+    async fn resubscribe(&self) {
+        let mut messages: Vec<Message> = self
+            .resubscribe_messages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|data| match String::from_utf8(data.clone()) {
+                Ok(text) => Message::Text(text),
+                Err(e) => Message::Binary(e.into_bytes()),
+            })
+            .collect();
+        messages.extend(
+            self.subscriptions
+                .lock()
+                .unwrap()
+                .subscribe_messages()
+                .into_iter()
+                .map(Message::Text),
+        );
+        if messages.is_empty() {
+            return;
+        }
+
+        let count = messages.len();
+        let mut guard = self.writer.lock().await;
+        for message in messages {
+            if let Err(e) = guard.send(message).await {
+                tracing::error!("Error sending resubscribe message: {e}");
+                return;
+            }
+        }
+        tracing::debug!("Sent {count} resubscribe message(s)");
+    }
+
     /// Check if the client is still connected.
     ///
-    /// The client is connected if the read task has not finished. It is expected
-    /// that in case of any failure client or server side. The read task will be
-    /// shutdown or will receive a `Close` frame which will finish it. There
-    /// might be some delay between the connection being closed and the client
-    /// detecting.
+    /// The client is connected if the read task and the optional ping and heartbeat tasks
+    /// have not finished. It is expected that in case of any failure client or server side.
+    /// The read task will be shutdown or will receive a `Close` frame which will finish it,
+    /// while a missed Pong finishes the ping task and a failed send either of them. There might be some delay between
+    /// the connection being closed and the client detecting. A read task shut down by
+    /// a failed reconnect leaves the client disconnected until it reconnects.
     #[inline]
     #[must_use]
     pub fn is_alive(&self) -> bool {
-        match &self.read_task {
+        let read_alive = match &self.read_task {
             Some(read_task) => !read_task.is_finished(),
-            None => true, // Stream is being used directly
-        }
+            None => self.config.handler.is_none(), // Stream is being used directly
+        };
+        let finished = |handle: &Option<task::JoinHandle<()>>| {
+            handle.as_ref().is_some_and(task::JoinHandle::is_finished)
+        };
+        read_alive && !finished(&self.ping_task) && !finished(&self.heartbeat_task)
     }
 }
 
@@ -320,6 +1157,13 @@ impl Drop for WebSocketClientInner {
                 handle.abort();
             }
         }
+
+        // Cancel ping task
+        if let Some(ref handle) = self.ping_task.take() {
+            if !handle.is_finished() {
+                handle.abort();
+            }
+        }
     }
 }
 
@@ -332,6 +1176,15 @@ pub struct WebSocketClient {
     pub(crate) controller_task: task::JoinHandle<()>,
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
     pub(crate) disconnect_mode: Arc<AtomicBool>,
+    pub(crate) cancellation_token: CancellationToken,
+    pub(crate) attached: SharedAttachedHandlers,
+    pub(crate) heartbeat_suspended: Arc<AtomicBool>,
+    pub(crate) subscriptions: SharedSubscriptionDebouncer,
+    pub(crate) resubscribe_messages: SharedResubscribeMessages,
+    pub(crate) last_close_frame: SharedCloseFrame,
+    pub(crate) pending_close_frame: SharedCloseFrame,
+    /// Cancelled once the controller task finishes and the client is `CLOSED`.
+    pub(crate) closed_token: CancellationToken,
 }
 
 impl WebSocketClient {
@@ -360,19 +1213,49 @@ impl WebSocketClient {
                 heartbeat_msg,
                 ping_handler: None,
                 max_reconnection_tries,
+                sequence_extractor: None,
+                sequence_gap_handler: None,
+                reconnect_on_sequence_gap: false,
+                snapshot_predicate: None,
+                heartbeat_pause_windows: Vec::new(),
+                auth_flow: None,
+                subscription_debounce_ms: None,
+                subscription_error_handler: None,
+                ping_interval_ms: None,
+                max_message_size: None,
+                include_message_type: false,
+                reconnect_backoff: None,
+                reconnect_delay_initial_ms: None,
+                reconnect_delay_max_ms: None,
+                resubscribe_messages: Vec::new(),
             }
         };
+        let backoff = reconnect_backoff(BackoffStrategy::FIXED, None, None).map_err(Error::Io)?;
 
         let disconnect_mode = Arc::new(AtomicBool::new(false));
+        let cancellation_token = CancellationToken::new();
+        let closed_token = CancellationToken::new();
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
         let inner = WebSocketClientInner::connect_url(config).await?;
+        let attached = inner.attached.clone();
+        let heartbeat_suspended = inner.heartbeat_suspended.clone();
+        let last_close_frame = inner.last_close_frame.clone();
+        let pending_close_frame = inner.pending_close_frame.clone();
+        let subscriptions = inner.subscriptions.clone();
+        let resubscribe_messages = inner.resubscribe_messages.clone();
+        let mut state_notifier = ConnectionStateNotifier::new(None);
+        state_notifier.transition(ConnectionState::ACTIVE);
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            cancellation_token.clone(),
+            closed_token.clone(),
             None, // no post_reconnection
             None, // no post_disconnection
+            state_notifier,
             max_reconnection_tries,
+            backoff,
         );
 
         Ok((
@@ -382,6 +1265,14 @@ impl WebSocketClient {
                 controller_task,
                 rate_limiter,
                 disconnect_mode,
+                cancellation_token,
+                attached,
+                heartbeat_suspended,
+                subscriptions,
+                resubscribe_messages,
+                last_close_frame,
+                pending_close_frame,
+                closed_token,
             },
         ))
     }
@@ -390,6 +1281,9 @@ impl WebSocketClient {
     ///
     /// Creates an inner client and controller task to reconnect or disconnect
     /// the client. Also assumes ownership of writer from inner client.
+    ///
+    /// The optional `on_state_change` handler is called with `(old, new, ts)` on every
+    /// [`ConnectionState`] transition, starting with `CLOSED` to `ACTIVE` on connection.
     pub async fn connect(
         config: WebSocketConfig,
         post_connection: Option<PyObject>,
@@ -397,18 +1291,39 @@ impl WebSocketClient {
         post_disconnection: Option<PyObject>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        on_state_change: Option<PyObject>,
     ) -> Result<Self, Error> {
         tracing::debug!("Connecting");
+        let backoff = reconnect_backoff(
+            config.reconnect_backoff.unwrap_or(BackoffStrategy::FIXED),
+            config.reconnect_delay_initial_ms,
+            config.reconnect_delay_max_ms,
+        )
+        .map_err(Error::Io)?;
         let inner = WebSocketClientInner::connect_url(config.clone()).await?;
         let writer = inner.writer.clone();
+        let attached = inner.attached.clone();
+        let heartbeat_suspended = inner.heartbeat_suspended.clone();
+        let last_close_frame = inner.last_close_frame.clone();
+        let pending_close_frame = inner.pending_close_frame.clone();
+        let subscriptions = inner.subscriptions.clone();
+        let resubscribe_messages = inner.resubscribe_messages.clone();
         let disconnect_mode = Arc::new(AtomicBool::new(false));
+        let cancellation_token = CancellationToken::new();
+        let closed_token = CancellationToken::new();
+        let mut state_notifier = ConnectionStateNotifier::new(on_state_change);
+        state_notifier.transition(ConnectionState::ACTIVE);
 
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            cancellation_token.clone(),
+            closed_token.clone(),
             post_reconnection,
             post_disconnection,
+            state_notifier,
             config.max_reconnection_tries,
+            backoff,
         );
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
@@ -424,6 +1339,14 @@ impl WebSocketClient {
             controller_task,
             rate_limiter,
             disconnect_mode,
+            cancellation_token,
+            attached,
+            heartbeat_suspended,
+            subscriptions,
+            resubscribe_messages,
+            last_close_frame,
+            pending_close_frame,
+            closed_token,
         })
     }
 
@@ -432,15 +1355,26 @@ impl WebSocketClient {
         self.controller_task.is_finished()
     }
 
+    /// Returns whether the client is closed, either disconnecting or no longer
+    /// reconnecting, so sends can never succeed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.disconnect_mode.load(Ordering::SeqCst) || self.is_disconnected()
+    }
+
     /// Set disconnect mode to true.
     ///
     /// Controller task will periodically check the disconnect mode
-    /// and shutdown the client if it is alive
+    /// and shutdown the client if it is alive. Any in-progress reconnect
+    /// backoff is interrupted so teardown is prompt, and pending debounced
+    /// subscription changes are dropped.
     pub async fn disconnect(&self) {
         tracing::debug!("Disconnecting");
         self.disconnect_mode.store(true, Ordering::SeqCst);
+        self.cancellation_token.cancel();
+        self.subscriptions.lock().unwrap().cancel_pending();
 
-        match tokio::time::timeout(Duration::from_secs(5), async {
+        match tokio::time::timeout(DISCONNECT_TIMEOUT, async {
             while !self.is_disconnected() {
                 sleep(Duration::from_millis(10)).await;
             }
@@ -456,16 +1390,117 @@ impl WebSocketClient {
         }
     }
 
-    pub async fn send_text(&self, data: String) -> Result<(), Error> {
+    /// Closes the connection with the close `code` and optional `reason`.
+    ///
+    /// The `Close` frame is sent as the client disconnects, and the server's `Close`
+    /// frame in reply is awaited (for up to 2 seconds) before the client transitions
+    /// to `CLOSED`, rather than tearing down the connection without a handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `code` cannot be sent in a `Close` frame, in which case
+    /// the client is left connected.
+    pub async fn close_with_code(&self, code: u16, reason: Option<String>) -> anyhow::Result<()> {
+        *self.pending_close_frame.lock().unwrap() = Some(close_frame(code, reason)?);
+        self.disconnect().await;
+        Ok(())
+    }
+
+    /// Returns the last `Close` frame received from the server, e.g. to distinguish a
+    /// clean shutdown from a policy violation (code 1008) when the connection is lost.
+    ///
+    /// The frame is kept across reconnects until the server sends another.
+    #[must_use]
+    pub fn last_close_frame(&self) -> Option<CloseFrame<'static>> {
+        self.last_close_frame.lock().unwrap().clone()
+    }
+
+    /// Suspends sending heartbeats, without affecting the connection state.
+    pub fn suspend_heartbeat(&self) {
+        tracing::debug!("Suspending heartbeat");
+        self.heartbeat_suspended.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes sending heartbeats after [`Self::suspend_heartbeat`].
+    pub fn resume_heartbeat(&self) {
+        tracing::debug!("Resuming heartbeat");
+        self.heartbeat_suspended.store(false, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    pub fn is_heartbeat_suspended(&self) -> bool {
+        self.heartbeat_suspended.load(Ordering::SeqCst)
+    }
+
+    /// Attaches an additional handler for received messages.
+    ///
+    /// If a `snapshot_predicate` is configured the last snapshot received is first
+    /// replayed to the handler.
+    pub fn attach_handler(&self, handler: PyObject) {
+        Python::with_gil(|py| self.attached.lock().unwrap().attach(py, handler));
+    }
+
+    /// Requests a subscription to the topic by sending the text `message`.
+    ///
+    /// Requests within the configured `subscription_debounce_ms` window are coalesced, and
+    /// requests which would not change the subscription state are not sent. The `message`
+    /// is resent after each reconnect while the topic is subscribed.
+    pub async fn subscribe(&self, topic: String, message: String) -> Result<(), SendError> {
+        self.request_subscription(topic, true, message).await
+    }
+
+    /// Requests to unsubscribe from the topic by sending the text `message`.
+    ///
+    /// Requests within the configured `subscription_debounce_ms` window are coalesced, and
+    /// requests which would not change the subscription state are not sent.
+    pub async fn unsubscribe(&self, topic: String, message: String) -> Result<(), SendError> {
+        self.request_subscription(topic, false, message).await
+    }
+
+    /// Replaces the messages resent after each reconnect, taking effect from the next
+    /// reconnect.
+    pub fn set_resubscribe_messages(&self, messages: Vec<Vec<u8>>) {
+        *self.resubscribe_messages.lock().unwrap() = messages;
+    }
+
+    /// Returns whether the topic is subscribed, as last sent to the server.
+    #[must_use]
+    pub fn is_subscribed(&self, topic: &str) -> bool {
+        self.subscriptions.lock().unwrap().is_subscribed(topic)
+    }
+
+    async fn request_subscription(
+        &self,
+        topic: String,
+        subscribe: bool,
+        message: String,
+    ) -> Result<(), SendError> {
+        request_subscription(
+            self.subscriptions.clone(),
+            self.writer.clone(),
+            topic,
+            subscribe,
+            message,
+        )
+        .await
+        .map_err(|e| SendError::classify(e, self.is_closed()))
+    }
+
+    pub async fn send_text(&self, data: String) -> Result<(), SendError> {
         tracing::trace!("Sending text: {data:?}");
-        let mut guard = self.writer.lock().await;
-        guard.send(Message::Text(data)).await
+        self.send_message(Message::Text(data)).await
     }
 
-    pub async fn send_bytes(&self, data: Vec<u8>) -> Result<(), Error> {
+    pub async fn send_bytes(&self, data: Vec<u8>) -> Result<(), SendError> {
         tracing::trace!("Sending bytes: {data:?}");
+        self.send_message(Message::Binary(data)).await
+    }
+
+    async fn send_message(&self, message: Message) -> Result<(), SendError> {
         let mut guard = self.writer.lock().await;
-        guard.send(Message::Binary(data)).await
+        let result = guard.send(message).await;
+        drop(guard);
+        result.map_err(|e| SendError::classify(e, self.is_closed()))
     }
 
     pub async fn send_close_message(&self) {
@@ -476,16 +1511,21 @@ impl WebSocketClient {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         disconnect_mode: Arc<AtomicBool>,
+        cancellation_token: CancellationToken,
+        closed_token: CancellationToken,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        mut state_notifier: ConnectionStateNotifier,
         max_reconnection_tries: Option<u64>,
+        mut backoff: Backoff,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
+            let _closed_guard = closed_token.drop_guard();
             let check_interval = Duration::from_millis(100);
-            let retry_interval = Duration::from_millis(1000);
             let mut retry_counter: u64 = 0;
 
             loop {
@@ -494,39 +1534,55 @@ impl WebSocketClient {
                 // Check if client needs to disconnect
                 let disconnect = disconnect_mode.load(Ordering::SeqCst);
                 match (disconnect, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            tracing::debug!("Reconnected successfully");
-                            retry_counter = 0;
-
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => tracing::debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        tracing::error!(
-                                            "Error calling `post_reconnection` handler: {e}"
-                                        );
-                                    }
-                                });
+                    (false, false) => {
+                        state_notifier.transition(ConnectionState::RECONNECT);
+                        match inner.reconnect().await {
+                            Ok(()) => {
+                                tracing::debug!("Reconnected successfully");
+                                retry_counter = 0;
+                                backoff.reset();
+                                state_notifier.transition(ConnectionState::ACTIVE);
+
+                                if let Some(ref handler) = post_reconnection {
+                                    Python::with_gil(|py| match handler.call0(py) {
+                                        Ok(_) => {
+                                            tracing::debug!("Called `post_reconnection` handler");
+                                        }
+                                        Err(e) => {
+                                            tracing::error!(
+                                                "Error calling `post_reconnection` handler: {e}"
+                                            );
+                                        }
+                                    });
+                                }
+                                inner.resubscribe().await;
                             }
-                        }
-                        Err(e) => {
-                            if let Some(max_reconnection_tries) = max_reconnection_tries {
-                                if retry_counter < max_reconnection_tries {
-                                    retry_counter += 1;
-                                    tracing::warn!("Reconnect failed {e}. Retry {retry_counter}/{max_reconnection_tries}");
-                                    sleep(retry_interval).await;
+                            Err(e) => {
+                                if let Some(max_reconnection_tries) = max_reconnection_tries {
+                                    if retry_counter < max_reconnection_tries {
+                                        retry_counter += 1;
+                                        tracing::warn!("Reconnect failed {e}. Retry {retry_counter}/{max_reconnection_tries}");
+                                        tokio::select! {
+                                            () = sleep(backoff.next_duration()) => {}
+                                            () = cancellation_token.cancelled() => {
+                                                tracing::debug!("Reconnect backoff cancelled");
+                                            }
+                                        }
+                                    } else {
+                                        tracing::error!("Reconnect failed {e}");
+                                        state_notifier.transition(ConnectionState::CLOSED);
+                                        break;
+                                    }
                                 } else {
                                     tracing::error!("Reconnect failed {e}");
+                                    state_notifier.transition(ConnectionState::CLOSED);
                                     break;
                                 }
-                            } else {
-                                tracing::error!("Reconnect failed {e}");
-                                break;
                             }
                         }
-                    },
+                    }
                     (true, true) => {
+                        state_notifier.transition(ConnectionState::DISCONNECT);
                         tracing::debug!("Shutting down inner client");
                         inner.shutdown().await;
                         if let Some(ref handler) = post_disconnection {
@@ -539,13 +1595,17 @@ impl WebSocketClient {
                                 }
                             });
                         }
+                        state_notifier.transition(ConnectionState::CLOSED);
                         break;
                     }
                     // Close the heartbeat task on disconnect if the connection is already closed
                     (true, false) => {
+                        state_notifier.transition(ConnectionState::DISCONNECT);
                         tracing::debug!("Inner client is disconnected");
                         tracing::debug!("Shutting down inner client to clean up running tasks");
                         inner.shutdown().await;
+                        state_notifier.transition(ConnectionState::CLOSED);
+                        break;
                     }
                     _ => (),
                 }
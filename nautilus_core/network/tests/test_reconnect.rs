@@ -0,0 +1,133 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The reconnect limit is process-wide, so these tests run in their own binary
+//! to avoid stalling reconnects in other tests.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use nautilus_network::{
+    backoff::BackoffStrategy,
+    reconnect::{acquire_reconnect_permit, set_max_concurrent_reconnects},
+    socket::{SocketClient, SocketConfig},
+};
+use pyo3::{prelude::*, prepare_freethreaded_python};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+    task,
+    time::{sleep, Duration},
+};
+use tokio_tungstenite::tungstenite::stream::Mode;
+
+#[tokio::test]
+async fn test_reconnects_limited_across_clients() {
+    prepare_freethreaded_python();
+
+    const N: usize = 5;
+
+    // Server which holds accepted connections until they are dropped by the test
+    let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = server.local_addr().unwrap().port();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let streams: Arc<Mutex<Vec<TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
+    let server_task = {
+        let connections = connections.clone();
+        let streams = streams.clone();
+        task::spawn(async move {
+            loop {
+                let (stream, _) = server.accept().await.unwrap();
+                connections.fetch_add(1, Ordering::SeqCst);
+                streams.lock().await.push(stream);
+            }
+        })
+    };
+
+    let handler = Python::with_gil(|py| {
+        let pymod = PyModule::from_code_bound(
+            py,
+            r"
+def handler(bytes):
+    pass",
+            "",
+            "",
+        )
+        .unwrap();
+        pymod.getattr("handler").unwrap().into_py(py)
+    });
+    let handler = Arc::new(handler);
+
+    let mut clients = Vec::new();
+    for _ in 0..N {
+        let config = SocketConfig {
+            url: format!("127.0.0.1:{port}"),
+            handler: handler.clone(),
+            mode: Mode::Plain,
+            suffix: b"\r\n".to_vec(),
+            heartbeat: None,
+            heartbeat_timeout_ms: None,
+            certs_dir: None,
+            cert_expiry_lead_secs: None,
+            buffer_budget: None,
+            max_reconnect_attempts: None,
+            reconnect_backoff: BackoffStrategy::FIXED,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            send_channel_capacity: None,
+            nodelay: true,
+            recv_buffer_size: None,
+            send_buffer_size: None,
+            auto_flush: false,
+        };
+        clients.push(
+            SocketClient::connect(config, None, None, None, None)
+                .await
+                .unwrap(),
+        );
+    }
+    // The server may not have accepted the last connection yet
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while connections.load(Ordering::SeqCst) < N {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(connections.load(Ordering::SeqCst), N);
+
+    // Hold the only permit so no client can reconnect
+    set_max_concurrent_reconnects(Some(1));
+    let permit = acquire_reconnect_permit().await.unwrap();
+
+    // Drop every connection at once to trigger a reconnect storm
+    streams.lock().await.clear();
+    sleep(Duration::from_secs(1)).await;
+    assert_eq!(connections.load(Ordering::SeqCst), N);
+
+    // Releasing the permit lets the clients reconnect one at a time
+    drop(permit);
+    sleep(Duration::from_secs(1)).await;
+    assert_eq!(connections.load(Ordering::SeqCst), 2 * N);
+    assert!(clients.iter().all(|client| !client.is_disconnected()));
+
+    set_max_concurrent_reconnects(None);
+    for client in &clients {
+        client.disconnect().await;
+    }
+    server_task.abort();
+}
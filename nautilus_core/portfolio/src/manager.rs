@@ -20,7 +20,10 @@ use std::{cell::RefCell, rc::Rc};
 use nautilus_common::{cache::Cache, clock::Clock};
 use nautilus_core::{ffi::uuid::uuid4_new, nanos::UnixNanos};
 use nautilus_model::{
-    accounts::{any::AccountAny, base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{
+        any::AccountAny, base::Account, betting::BettingAccount, cash::CashAccount,
+        margin::MarginAccount,
+    },
     enums::{AccountType, OrderSide, OrderSideSpecified, PriceType},
     events::{AccountState, OrderFilled},
     instruments::InstrumentAny,
@@ -106,6 +109,11 @@ impl AccountsManager {
                 .map(|(updated_margin_account, state)| {
                     (AccountAny::Margin(updated_margin_account), state)
                 }),
+            AccountAny::Betting(betting_account) => self
+                .update_betting_balance_locked(&betting_account, instrument, orders_open, ts_event)
+                .map(|(updated_betting_account, state)| {
+                    (AccountAny::Betting(updated_betting_account), state)
+                }),
         }
     }
 
@@ -255,6 +263,82 @@ impl AccountsManager {
             ));
         }
 
+        let locked_money =
+            self.calculate_orders_locked(&mut account, AccountAny::Cash, &instrument, orders_open)?;
+
+        if let Some(balance) = account.balances.get_mut(&instrument.quote_currency()) {
+            balance.locked = locked_money;
+            let currency = balance.currency;
+            account.recalculate_balance(currency);
+        }
+
+        log::info!(
+            "{} balance_locked={}",
+            instrument.id(),
+            locked_money.to_string()
+        );
+
+        Some((
+            account.clone(),
+            self.generate_account_state(AccountAny::Cash(account), ts_event),
+        ))
+    }
+
+    /// Updates the locked balance of the betting account, being the liability of
+    /// the open orders.
+    fn update_betting_balance_locked(
+        &self,
+        account: &BettingAccount,
+        instrument: InstrumentAny,
+        orders_open: Vec<&OrderAny>,
+        ts_event: UnixNanos,
+    ) -> Option<(BettingAccount, AccountState)> {
+        let mut account = account.clone();
+        if orders_open.is_empty() {
+            let balance = account.balances.remove(&instrument.quote_currency());
+            if let Some(balance) = balance {
+                account.recalculate_balance(balance.currency);
+            }
+            return Some((
+                account.clone(),
+                self.generate_account_state(AccountAny::Betting(account), ts_event),
+            ));
+        }
+
+        let locked_money = self.calculate_orders_locked(
+            &mut account,
+            AccountAny::Betting,
+            &instrument,
+            orders_open,
+        )?;
+
+        if let Some(balance) = account.balances.get_mut(&instrument.quote_currency()) {
+            balance.locked = locked_money;
+            let currency = balance.currency;
+            account.recalculate_balance(currency);
+        }
+
+        log::info!(
+            "{} balance_locked={}",
+            instrument.id(),
+            locked_money.to_string()
+        );
+
+        Some((
+            account.clone(),
+            self.generate_account_state(AccountAny::Betting(account), ts_event),
+        ))
+    }
+
+    /// Returns the total balance locked by the open orders, in the base currency of the
+    /// account if it has one, otherwise in the settlement currency of the instrument.
+    fn calculate_orders_locked<A: Account + Clone>(
+        &self,
+        account: &mut A,
+        into_any: fn(A) -> AccountAny,
+        instrument: &InstrumentAny,
+        orders_open: Vec<&OrderAny>,
+    ) -> Option<Money> {
         let mut total_locked = Decimal::ZERO;
         let mut base_xrate = Decimal::ZERO;
 
@@ -294,7 +378,7 @@ impl AccountsManager {
                 if base_xrate.is_zero() {
                     currency = base_curr;
                     base_xrate = self.calculate_xrate_to_base(
-                        AccountAny::Cash(account.clone()),
+                        into_any(account.clone()),
                         instrument.clone(),
                         order.order_side_specified(),
                     );
@@ -306,24 +390,7 @@ impl AccountsManager {
             total_locked += locked;
         }
 
-        let locked_money = Money::new(total_locked.to_f64()?, currency);
-
-        if let Some(balance) = account.balances.get_mut(&instrument.quote_currency()) {
-            balance.locked = locked_money;
-            let currency = balance.currency;
-            account.recalculate_balance(currency);
-        }
-
-        log::info!(
-            "{} balance_locked={}",
-            instrument.id(),
-            locked_money.to_string()
-        );
-
-        Some((
-            account.clone(),
-            self.generate_account_state(AccountAny::Cash(account), ts_event),
-        ))
+        Some(Money::new(total_locked.to_f64()?, currency))
     }
 
     fn update_margin_init(
@@ -525,15 +592,30 @@ impl AccountsManager {
 
         match account {
             AccountAny::Cash(mut cash) => {
-                cash.update_balances(balances);
+                if let Err(e) = cash.update_balances(balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
                 if let Some(comm) = commission {
-                    cash.update_commissions(comm);
+                    cash.record_commission(comm, fill.ts_event);
                 }
             }
             AccountAny::Margin(mut margin) => {
-                margin.update_balances(balances);
+                if let Err(e) = margin.update_balances(balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
+                if let Some(comm) = commission {
+                    margin.record_commission(comm, fill.ts_event);
+                }
+            }
+            AccountAny::Betting(mut betting) => {
+                if let Err(e) = betting.update_balances(balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
                 if let Some(comm) = commission {
-                    margin.update_commissions(comm);
+                    betting.record_commission(comm, fill.ts_event);
                 }
             }
         }
@@ -639,15 +721,30 @@ impl AccountsManager {
 
         match account {
             AccountAny::Cash(mut cash) => {
-                cash.update_balances(new_balances);
+                if let Err(e) = cash.update_balances(new_balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
                 if let Some(commission) = commission {
-                    cash.update_commissions(commission);
+                    cash.record_commission(commission, fill.ts_event);
                 }
             }
             AccountAny::Margin(mut margin) => {
-                margin.update_balances(new_balances);
+                if let Err(e) = margin.update_balances(new_balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
+                if let Some(commission) = commission {
+                    margin.record_commission(commission, fill.ts_event);
+                }
+            }
+            AccountAny::Betting(mut betting) => {
+                if let Err(e) = betting.update_balances(new_balances) {
+                    log::error!("Cannot update balances: {e}");
+                    return;
+                }
                 if let Some(commission) = commission {
-                    margin.update_commissions(commission);
+                    betting.record_commission(commission, fill.ts_event);
                 }
             }
         }
@@ -677,6 +774,17 @@ impl AccountsManager {
                 self.clock.borrow().timestamp_ns(),
                 margin_account.base_currency(),
             ),
+            AccountAny::Betting(betting_account) => AccountState::new(
+                betting_account.id,
+                AccountType::Betting,
+                betting_account.balances.clone().into_values().collect(),
+                vec![],
+                false,
+                uuid4_new(),
+                ts_event,
+                self.clock.borrow().timestamp_ns(),
+                betting_account.base_currency(),
+            ),
         }
     }
 
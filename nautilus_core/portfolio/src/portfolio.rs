@@ -46,7 +46,7 @@ use nautilus_model::{
     accounts::AccountAny,
     data::{Data, QuoteTick},
     enums::{OrderSide, OrderType, PositionSide, PriceType},
-    events::{position::PositionEvent, AccountState, OrderEventAny},
+    events::{position::PositionEvent, AccountState, OrderEventAny, OrderFilled},
     identifiers::{InstrumentId, Venue},
     instruments::InstrumentAny,
     orders::OrderAny,
@@ -352,6 +352,10 @@ impl Portfolio {
                     log::warn!("Initial margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Initial margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -372,6 +376,10 @@ impl Portfolio {
                     log::warn!("Maintenance margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Maintenance margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -821,7 +829,7 @@ impl Portfolio {
                 };
 
             let account = match account {
-                AccountAny::Cash(_) => continue,
+                AccountAny::Cash(_) | AccountAny::Betting(_) => continue,
                 AccountAny::Margin(margin_account) => margin_account,
             };
 
@@ -982,7 +990,13 @@ impl Portfolio {
                 return None; // Cannot calculate
             };
 
-            let mut pnl = position.unrealized_pnl(last).as_f64();
+            let mut pnl = match position.calculate_unrealized_pnl(last, instrument) {
+                Ok(pnl) => pnl.as_f64(),
+                Err(e) => {
+                    log::error!("Cannot calculate unrealized PnL: {e}");
+                    return None;
+                }
+            };
 
             if let Some(base_currency) = account.base_currency() {
                 let xrate = self.calculate_xrate_to_base(instrument, account, position.entry);
@@ -1224,7 +1238,7 @@ fn update_quote_tick(
         portfolio_clone.calculate_unrealized_pnl(&quote.instrument_id);
 
     if result_init.is_some()
-        && (matches!(account, AccountAny::Cash(_))
+        && (matches!(account, AccountAny::Cash(_) | AccountAny::Betting(_))
             || (result_maint.is_some() && result_unrealized_pnl.is_some()))
     {
         inner
@@ -1244,6 +1258,12 @@ fn update_order(
     inner: Rc<RefCell<PortfolioState>>,
     event: &OrderEventAny,
 ) {
+    if let OrderEventAny::PartiallyFilled(order_filled) | OrderEventAny::Filled(order_filled) =
+        event
+    {
+        record_fill(&cache, order_filled);
+    }
+
     let borrowed_cache = cache.borrow();
     let account_id = match event.account_id() {
         Some(account_id) => account_id,
@@ -1273,6 +1293,11 @@ fn update_order(
                 return;
             }
         }
+        AccountAny::Betting(betting_account) => {
+            if !betting_account.base.calculate_account_state {
+                return;
+            }
+        }
     }
 
     match event {
@@ -1452,12 +1477,41 @@ fn update_position(
     }
 }
 
+/// Records the notional value of the `fill` on its account for turnover tracking, whether
+/// or not the account state is calculated locally.
+fn record_fill(cache: &Rc<RefCell<Cache>>, fill: &OrderFilled) {
+    let mut borrowed_cache = cache.borrow_mut();
+
+    let Some(instrument) = borrowed_cache.instrument(&fill.instrument_id).cloned() else {
+        log::error!(
+            "Cannot record fill: no instrument found for {}",
+            fill.instrument_id
+        );
+        return;
+    };
+    let Some(mut account) = borrowed_cache.account(&fill.account_id).cloned() else {
+        log::error!(
+            "Cannot record fill: no account registered for {}",
+            fill.account_id
+        );
+        return;
+    };
+
+    account.update_fills(&instrument, fill);
+    if let Err(e) = borrowed_cache.add_account(account) {
+        log::error!("Failed to update account: {e}");
+    }
+}
+
 pub fn update_account(cache: Rc<RefCell<Cache>>, event: &AccountState) {
     let mut borrowed_cache = cache.borrow_mut();
 
     if let Some(existing) = borrowed_cache.account(&event.account_id) {
         let mut account = existing.clone();
-        account.apply(event.clone());
+        if let Err(e) = account.apply(event.clone()) {
+            log::error!("Failed to apply account state: {e}");
+            return;
+        }
 
         if let Err(e) = borrowed_cache.update_account(account.clone()) {
             log::error!("Failed to update account: {}", e);
@@ -1491,10 +1545,11 @@ mod tests {
     use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
     use nautilus_core::nanos::UnixNanos;
     use nautilus_model::{
+        accounts::{any::AccountAny, betting::BettingAccount},
         data::QuoteTick,
         enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
         events::{
-            account::stubs::cash_account_state,
+            account::stubs::{betting_account_state, cash_account_state},
             order::stubs::{order_accepted, order_filled, order_submitted},
             AccountState, OrderAccepted, OrderEventAny, OrderFilled, OrderSubmitted,
             PositionChanged, PositionClosed, PositionEvent, PositionOpened,
@@ -1504,10 +1559,10 @@ mod tests {
             AccountId, ClientOrderId, PositionId, StrategyId, Symbol, TradeId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{audusd_sim, betting, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
+            BettingInstrument, CryptoPerpetual, CurrencyPair, InstrumentAny,
         },
-        orders::{OrderAny, OrderTestBuilder},
+        orders::{stubs::TestOrderStubs, OrderAny, OrderTestBuilder},
         position::Position,
         types::{AccountBalance, Currency, Money, Price, Quantity},
     };
@@ -1967,6 +2022,44 @@ mod tests {
         portfolio.update_order(&OrderEventAny::Filled(order_filled));
     }
 
+    #[rstest]
+    #[case::partially_filled(OrderEventAny::PartiallyFilled)]
+    #[case::filled(OrderEventAny::Filled)]
+    fn test_update_order_filled_records_turnover(
+        mut portfolio: Portfolio,
+        cash_account_state: AccountState,
+        instrument_btcusdt: InstrumentAny,
+        #[case] event: fn(OrderFilled) -> OrderEventAny,
+    ) {
+        portfolio.update_account(&cash_account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_btcusdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.561"))
+            .build();
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let order_filled = fill_order(&order);
+        portfolio.update_order(&event(order_filled));
+
+        let cache = portfolio.cache.borrow();
+        let account = cache.account(&order_filled.account_id).unwrap();
+        let (from, to) = (order_filled.ts_event, order_filled.ts_event);
+        assert_eq!(
+            account.turnover(from, to, Currency::USDT()).unwrap(),
+            Money::from("12342 USDT")
+        );
+        assert_eq!(
+            account.turnover(from, to, Currency::BTC()).unwrap(),
+            Money::from("0.561 BTC")
+        );
+    }
+
     // TODO: It should return an error
     #[rstest]
     fn test_exceed_free_balance_multi_currency_raises_account_balance_negative_exception(
@@ -2054,6 +2147,47 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_update_orders_open_betting_account(
+        portfolio: Portfolio,
+        betting_account_state: AccountState,
+        betting: BettingInstrument,
+    ) {
+        let account = AccountAny::Betting(BettingAccount::new(betting_account_state, true));
+        let instrument = InstrumentAny::Betting(betting);
+        let orders: Vec<OrderAny> = [OrderSide::Buy, OrderSide::Sell]
+            .into_iter()
+            .map(|side| {
+                let order = OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(instrument.id())
+                    .side(side)
+                    .quantity(Quantity::from("10.00"))
+                    .price(Price::from("3.00"))
+                    .build();
+                TestOrderStubs::make_accepted_order(&order)
+            })
+            .collect();
+
+        let (account, state) = portfolio
+            .inner
+            .borrow()
+            .accounts
+            .update_orders(
+                &account,
+                instrument,
+                orders.iter().collect(),
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        // The back liability is its stake of 10, the lay liability 10 * (3.00 - 1)
+        let balance = account.balances()[&Currency::GBP()];
+        assert_eq!(balance.locked, Money::from("30 GBP"));
+        assert_eq!(balance.free, Money::from("970 GBP"));
+        assert_eq!(state.account_type, AccountType::Betting);
+        assert_eq!(state.balances, vec![balance]);
+    }
+
     #[rstest]
     fn test_update_orders_open_margin_account(
         mut portfolio: Portfolio,
@@ -28,7 +28,7 @@ use nautilus_common::{
 use nautilus_core::uuid::UUID4;
 use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
 use nautilus_model::{
-    accounts::{Account, AccountAny},
+    accounts::{Account, AccountAny, BettingAccount},
     enums::{InstrumentClass, OrderSide, OrderStatus, TradingState},
     events::{OrderDenied, OrderEventAny, OrderModifyRejected},
     identifiers::InstrumentId,
@@ -322,15 +322,15 @@ impl RiskEngine {
         let order = &command.order;
         if let Some(position_id) = command.position_id {
             if order.is_reduce_only() {
-                let position_exists = {
+                let would_increase_exposure = {
                     let cache = self.cache.borrow();
                     cache
                         .position(&position_id)
-                        .map(|pos| (pos.side, pos.quantity))
+                        .map(|pos| order.would_increase_exposure(Some(pos)))
                 };
 
-                if let Some((pos_side, pos_quantity)) = position_exists {
-                    if !order.would_reduce_only(pos_side, pos_quantity) {
+                if let Some(would_increase_exposure) = would_increase_exposure {
+                    if would_increase_exposure {
                         self.deny_command(
                             TradingCommand::SubmitOrder(command),
                             &format!("Reduce only order would increase position {position_id}"),
@@ -617,6 +617,9 @@ impl RiskEngine {
         let cash_account = match account {
             AccountAny::Cash(cash_account) => cash_account,
             AccountAny::Margin(_) => return true, // TODO: Determine risk controls for margin
+            AccountAny::Betting(betting_account) => {
+                return self.check_orders_risk_betting(&instrument, &orders, betting_account);
+            }
         };
         let free = cash_account.balance_free(Some(instrument.quote_currency()));
         if self.config.debug {
@@ -851,6 +854,67 @@ impl RiskEngine {
         true // Passed
     }
 
+    fn check_orders_risk_betting(
+        &self,
+        instrument: &InstrumentAny,
+        orders: &[OrderAny],
+        mut account: BettingAccount,
+    ) -> bool {
+        let free = account.balance_free(Some(instrument.quote_currency()));
+        if self.config.debug {
+            log::debug!("Free balance: {:?}", free);
+        }
+
+        let mut cum_liability = Money::new(0.0, instrument.quote_currency());
+        for order in orders {
+            // The liability of a lay bet depends on its odds, so only priced orders can be checked
+            let price = if let Some(price) = order.price().or(order.trigger_price()) {
+                price
+            } else {
+                log::warn!(
+                    "Cannot check {} order risk: no price was set",
+                    order.order_type()
+                );
+                continue;
+            };
+
+            // The locked balance of a betting order is the stake of a back bet or the liability of a lay bet
+            let liability = match account.calculate_balance_locked(
+                instrument.clone(),
+                order.order_side(),
+                order.quantity(),
+                price,
+                None,
+            ) {
+                Ok(liability) => liability,
+                Err(e) => {
+                    self.deny_order(order.clone(), &e.to_string());
+                    return false; // Denied
+                }
+            };
+            cum_liability += liability;
+
+            if self.config.debug {
+                log::debug!("Cumulative liability: {:?}", cum_liability);
+            }
+
+            if let Some(free) = free {
+                if cum_liability > free {
+                    self.deny_order(
+                        order.clone(),
+                        &format!(
+                            "LIABILITY_EXCEEDS_FREE_BALANCE: free={free}, liability={cum_liability}"
+                        ),
+                    );
+                    return false; // Denied
+                }
+            }
+        }
+
+        // Finally
+        true // Passed
+    }
+
     fn check_price(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
         let price_val = price?;
 
@@ -1088,8 +1152,8 @@ mod tests {
         data::{stubs::quote_audusd, QuoteTick},
         enums::{AccountType, OrderSide, OrderType, TradingState},
         events::{
-            account::stubs::cash_account_state_million_usd, AccountState, OrderDenied,
-            OrderEventAny, OrderEventType,
+            account::stubs::{betting_account_state, cash_account_state_million_usd},
+            AccountState, OrderDenied, OrderEventAny, OrderEventType,
         },
         identifiers::{
             stubs::{
@@ -1100,8 +1164,8 @@ mod tests {
             TraderId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{audusd_sim, betting, crypto_perpetual_ethusdt, xbtusd_bitmex},
+            BettingInstrument, CryptoPerpetual, CurrencyPair, InstrumentAny,
         },
         orders::{OrderAny, OrderList, OrderTestBuilder},
         types::{AccountBalance, Money, Price, Quantity},
@@ -2668,6 +2732,92 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(OrderSide::Buy, "600.00", None)]
+    #[case(OrderSide::Sell, "400.00", None)]
+    #[case(
+        OrderSide::Sell,
+        "600.00",
+        Some("LIABILITY_EXCEEDS_FREE_BALANCE: free=1000.00 GBP, liability=1200.00 GBP")
+    )]
+    fn test_submit_order_when_betting_account_checks_liability(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        betting: BettingInstrument,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut betting_account_state: AccountState,
+        mut simple_cache: Cache,
+        #[case] side: OrderSide,
+        #[case] quantity: &str,
+        #[case] expected_denial: Option<&str>,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        let instrument = InstrumentAny::Betting(betting);
+        simple_cache.add_instrument(instrument.clone()).unwrap();
+
+        // Account must be issued by the instrument venue to be found for it
+        betting_account_state.account_id = AccountId::from("BETFAIR-001");
+        simple_cache
+            .add_account(AccountAny::from(betting_account_state))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(side)
+            .price(Price::from("3.00"))
+            .quantity(Quantity::from(quantity))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+
+        match expected_denial {
+            Some(reason) => {
+                assert_eq!(saved_process_messages.len(), 1);
+                assert_eq!(
+                    saved_process_messages.first().unwrap().event_type(),
+                    OrderEventType::Denied
+                );
+                assert_eq!(
+                    saved_process_messages.first().unwrap().message().unwrap(),
+                    Ustr::from(reason)
+                );
+            }
+            None => assert!(saved_process_messages.is_empty()),
+        }
+    }
+
     #[rstest]
     fn test_submit_order_list_buys_when_over_free_balance_then_denies(
         mut msgbus: MessageBus,
@@ -2667,6 +2667,8 @@ class OrderUpdated:
         account_id: AccountId | None = None,
         price: Price | None = None,
         trigger_price: Price | None = None,
+        limit_offset: Price | None = None,
+        trailing_offset: Price | None = None,
     ) -> None: ...
     @classmethod
     def from_dict(cls, values: dict[str, str]) -> OrderUpdated: ...
@@ -2945,6 +2947,9 @@ class HttpError(Exception):
 class HttpTimeoutError(Exception):
     ...
 
+class SocketSendTimeoutError(Exception):
+    ...
+
 class HttpClient:
     def __init__(
         self,
@@ -2952,7 +2957,14 @@ class HttpClient:
         header_keys: list[str] | None = None,
         keyed_quotas: list[tuple[str, Quota]] | None = None,
         default_quota: Quota | None = None,
+        max_inflight: int | None = None,
+        retry_config: HttpRetryConfig | None = None,
+        pool_config: HttpPoolConfig | None = None,
     ) -> None: ...
+    @property
+    def inflight_count(self) -> int: ...
+    def remaining(self, key: str) -> int | None: ...
+    def reset_after(self, key: str) -> float | None: ...
     async def request(
         self,
         method: HttpMethod,
@@ -2961,6 +2973,20 @@ class HttpClient:
         body: bytes | None = None,
         keys: list[str] | None = None,
         timeout_secs: int | None = None,
+        idempotent: bool | None = None,
+    ) -> HttpResponse: ...
+    async def request_templated(
+        self,
+        method: HttpMethod,
+        url: str,
+        template: str,
+        path_params: dict[str, str] | None = None,
+        query: dict[str, str] | None = None,
+        headers: dict[str, str] | None = None,
+        body: bytes | None = None,
+        keys: list[str] | None = None,
+        timeout_secs: int | None = None,
+        idempotent: bool | None = None,
     ) -> HttpResponse: ...
 
 class HttpMethod(Enum):
@@ -2969,6 +2995,35 @@ class HttpMethod(Enum):
     PUT = "PUT"
     DELETE = "DELETE"
     PATCH = "PATCH"
+    HEAD = "HEAD"
+
+class BackoffStrategy(Enum):
+    EXPONENTIAL = 0
+    DECORRELATED_JITTER = 1
+    FIXED = 2
+
+class BufferBudget:
+    def __init__(self, capacity: int) -> None: ...
+    @property
+    def capacity(self) -> int: ...
+    def available(self) -> int: ...
+
+class HttpRetryConfig:
+    def __init__(
+        self,
+        max_attempts: int = 3,
+        methods: list[HttpMethod] | None = None,
+        delay_initial_ms: int = 500,
+        delay_max_ms: int = 5_000,
+        backoff_strategy: BackoffStrategy = BackoffStrategy.EXPONENTIAL,
+    ) -> None: ...
+
+class HttpPoolConfig:
+    def __init__(
+        self,
+        max_idle_per_host: int | None = None,
+        idle_timeout_ms: int | None = None,
+    ) -> None: ...
 
 class HttpResponse:
     @property
@@ -2989,6 +3044,12 @@ class Quota:
 class WebSocketClientError(Exception):
     ...
 
+class WebSocketRetryableError(WebSocketClientError):
+    ...
+
+class WebSocketFatalError(WebSocketClientError):
+    ...
+
 class WebSocketConfig:
     def __init__(
         self,
@@ -2999,8 +3060,33 @@ class WebSocketConfig:
         heartbeat_msg: str | None = None,
         ping_handler: Callable[..., Any] | None = None,
         max_reconnection_tries: int | None = None,
+        sequence_json_pointer: str | None = None,
+        sequence_gap_handler: Callable[[int, int], Any] | None = None,
+        reconnect_on_sequence_gap: bool = False,
+        snapshot_predicate: Callable[[bytes], bool] | None = None,
+        heartbeat_pause_windows: list[tuple[int, int]] | None = None,
+        auth_flow: Callable[[bytes], bytes | bool] | None = None,
+        subscription_debounce_ms: int | None = None,
+        subscription_error_handler: Callable[[str, str], Any] | None = None,
+        ping_interval_ms: int | None = None,
+        max_message_size: int | None = None,
+        include_message_type: bool = False,
+        reconnect_backoff: BackoffStrategy | None = None,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        resubscribe_messages: list[bytes] | None = None,
     ) -> None: ...
 
+class WsMessageType(Enum):
+    Text = 0
+    Binary = 1
+
+class ConnectionState(Enum):
+    ACTIVE = 0
+    RECONNECT = 1
+    DISCONNECT = 2
+    CLOSED = 3
+
 class WebSocketClient:
     @classmethod
     def connect(
@@ -3011,9 +3097,19 @@ class WebSocketClient:
         post_disconnection: Callable[..., None] | None = None,
         keyed_quotas: list[tuple[str, Quota]] = [],
         default_quota: Quota | None = None,
+        on_state_change: Callable[[ConnectionState, ConnectionState, int], None] | None = None,
     ) -> Awaitable[WebSocketClient]: ...
     def disconnect(self) -> Awaitable[None]: ...
+    def close_with_code(self, code: int, reason: str | None = None) -> Awaitable[None]: ...
     def is_alive(self) -> bool: ...
+    def suspend_heartbeat(self) -> None: ...
+    def resume_heartbeat(self) -> None: ...
+    def attach_handler(self, handler: Callable[[bytes], Any]) -> None: ...
+    def subscribe(self, topic: str, message: bytes) -> Awaitable[None]: ...
+    def unsubscribe(self, topic: str, message: bytes) -> Awaitable[None]: ...
+    def set_resubscribe_messages(self, messages: list[bytes]) -> None: ...
+    def is_subscribed(self, topic: str) -> bool: ...
+    def last_close_frame(self) -> tuple[int, str] | None: ...
     def send(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
     def send_text(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
     def send_pong(self, data: bytes) -> Awaitable[None]: ...
@@ -3026,10 +3122,21 @@ class SocketClient:
         post_connection: Callable[..., None] | None = None,
         post_reconnection: Callable[..., None] | None = None,
         post_disconnection: Callable[..., None] | None = None,
+        on_reconnect_attempt: Callable[[int, int], None] | None = None,
     ) -> Awaitable[SocketClient]: ...
     def disconnect(self) -> Awaitable[None]: ...
     def is_alive(self) -> bool: ...
+    def buffered_bytes(self) -> int: ...
+    def reconnect_count(self) -> int: ...
+    def connected_since(self) -> int | None: ...
+    def current_reconnect_delay_ms(self) -> int | None: ...
+    def bytes_received(self) -> int: ...
+    def messages_received(self) -> int: ...
+    def last_message_ns(self) -> int | None: ...
     def send(self, data: bytes) -> Awaitable[None]: ...
+    def send_text(self, data: str) -> Awaitable[None]: ...
+    def send_with_timeout(self, data: bytes, timeout_ms: int) -> Awaitable[None]: ...
+    def flush(self) -> Awaitable[None]: ...
 
 class SocketConfig:
     def __init__(
@@ -3039,6 +3146,19 @@ class SocketConfig:
         suffix: bytes,
         handler: Callable[..., Any],
         heartbeat: tuple[int, list[int]] | None = None,
+        certs_dir: str | None = None,
+        cert_expiry_lead_secs: int | None = None,
+        buffer_budget: BufferBudget | None = None,
+        max_reconnect_attempts: int | None = None,
+        send_channel_capacity: int | None = None,
+        heartbeat_timeout_ms: int | None = None,
+        reconnect_backoff: BackoffStrategy | None = None,
+        nodelay: bool = True,
+        recv_buffer_size: int | None = None,
+        send_buffer_size: int | None = None,
+        auto_flush: bool = False,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
     ) -> None: ...
 
 ###################################################################################################